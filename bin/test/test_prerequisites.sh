@@ -61,6 +61,18 @@ echo -e "\n------------------ Starting ssl/server.py (Self-signed certificate +
 nohup python3 ssl/server.py 8003 ssl/server/cert.selfsigned.pem true > build/server-ssl-client-authent.log 2>&1 &
 check_listen_port "ssl/server.py" 8003 || cat_and_exit_err build/server-ssl-client-authent.log
 
+echo -e "\n------------------ Starting unix_socket/server.py (Unix domain socket)"
+python3 unix_socket/server.py /tmp/hurl-test.sock > build/server-unix-socket.log 2>&1 &
+for count in $(seq 30) ; do
+    if [ -S /tmp/hurl-test.sock ] ; then
+        echo "${color_green}$(date) - unix_socket/server.py listening${color_reset} on /tmp/hurl-test.sock"
+        break
+    fi
+    echo "$(date) - ${count} try - unix_socket/server.py not listening${color_reset} on /tmp/hurl-test.sock yet"
+    sleep 1
+done
+[ -S /tmp/hurl-test.sock ] || cat_and_exit_err build/server-unix-socket.log
+
 echo -e "\n------------------ Starting squid (proxy)"
 if [ -f /var/run/squid.pid ] ; then
   sudo squid -k shutdown || true
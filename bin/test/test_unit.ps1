@@ -6,3 +6,6 @@ write-host -foregroundcolor Cyan "----- unit tests  -----"
 # run test units
 cargo test --release --features strict --tests
 if ($LASTEXITCODE) { Throw }
+
+cargo test --release --package hurl_core --features serde
+if ($LASTEXITCODE) { Throw }
@@ -5,3 +5,4 @@ echo "----- unit tests  -----"
 PATH="$HOME"/.cargo/bin:$PATH
 export PATH
 cargo test --release
+cargo test --release --package hurl_core --features serde
@@ -33,6 +33,9 @@ def main():
         + get_files("tests_error_parser/*." + extension)
         + get_files("ssl/*." + extension)
     )
+    # Unix domain socket tests are only run on Unix
+    if platform.system() != "Windows":
+        script_files += get_files("unix_socket/*.sh")
     for f in sorted(script_files):
         test_script.test(f)
 
@@ -2,6 +2,7 @@
 # lint hurl file
 #
 import codecs
+import os
 import sys
 import subprocess
 
@@ -20,8 +21,12 @@ def test(hurl_file):
     cmd = ["hurlfmt", "--check", hurl_file]
     print(" ".join(cmd))
     result = subprocess.run(cmd, stdout=subprocess.PIPE, stderr=subprocess.PIPE)
-    if result.returncode != 1:
-        print(f"return code => expected: 1  actual {result.returncode}")
+    exit_file = hurl_file.replace(".hurl", ".exit")
+    expected = 1
+    if os.path.exists(exit_file):
+        expected = int(open(exit_file).read().strip())
+    if result.returncode != expected:
+        print(f"return code => expected: {expected}  actual {result.returncode}")
         sys.exit(1)
 
     err_file = hurl_file.replace(".hurl", ".err")
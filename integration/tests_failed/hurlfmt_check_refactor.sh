@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurlfmt --check --extract-json-bodies tests_ok/refactor.in
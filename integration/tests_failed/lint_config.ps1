@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurlfmt --check --config tests_error_lint/config.toml --check-format json tests_error_lint/config.hurl
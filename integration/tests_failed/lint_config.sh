@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurlfmt --check --config tests_error_lint/config.toml --check-format json tests_error_lint/config.hurl
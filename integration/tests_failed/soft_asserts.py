@@ -0,0 +1,14 @@
+# coding=utf-8
+from app import app
+from flask import request
+
+
+@app.route("/soft-asserts")
+def soft_asserts():
+    return '{ "id": 42, "name": "Bob" }'
+
+
+@app.route("/soft-asserts-check")
+def soft_asserts_check():
+    assert request.args.get("id") == "42"
+    return "Hello World!"
@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl --soft-asserts tests_failed/soft_asserts.hurl
@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl --soft-asserts --retry 3 --retry-interval 10 tests_failed/soft_asserts_retry.hurl
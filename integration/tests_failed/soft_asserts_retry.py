@@ -0,0 +1,23 @@
+# coding=utf-8
+from app import app
+
+count = 0
+
+
+@app.route("/soft-asserts-retry/reset")
+def soft_asserts_retry_reset():
+    global count
+    count = 0
+    return ""
+
+
+@app.route("/soft-asserts-retry")
+def soft_asserts_retry():
+    global count
+    count += 1
+    return "Bob"
+
+
+@app.route("/soft-asserts-retry/count")
+def soft_asserts_retry_count():
+    return str(count)
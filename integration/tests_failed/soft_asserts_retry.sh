@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl --soft-asserts --retry 3 --retry-interval 10 tests_failed/soft_asserts_retry.hurl
@@ -0,0 +1,14 @@
+from app import app
+from flask import request
+
+
+@app.route("/capture-default")
+def capture_default():
+    return '{ "id": 42 }'
+
+
+@app.route("/capture-default-check")
+def capture_default_check():
+    assert request.args.get("token") == "none"
+    assert request.args.get("id") == "42"
+    return ""
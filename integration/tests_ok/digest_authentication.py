@@ -0,0 +1,30 @@
+import hashlib
+from app import app
+from flask import request, make_response
+
+REALM = "hurl"
+NONCE = "dcd98b7102dd2f0e8b11d0f600bfb0c093"
+
+
+def md5(value):
+    return hashlib.md5(value.encode("utf-8")).hexdigest()
+
+
+@app.route("/digest-authentication")
+def digest_authentication():
+    auth = request.authorization
+    if auth is None or auth.type != "digest":
+        resp = make_response("", 401)
+        resp.headers[
+            "WWW-Authenticate"
+        ] = f'Digest realm="{REALM}", qop="auth", nonce="{NONCE}"'
+        return resp
+
+    assert auth.username == "bob"
+    assert auth.realm == REALM
+    assert auth.nonce == NONCE
+    ha1 = md5(f"bob:{REALM}:secret")
+    ha2 = md5(f"{request.method}:{auth.uri}")
+    expected = md5(f"{ha1}:{NONCE}:{auth.nc}:{auth.cnonce}:{auth.qop}:{ha2}")
+    assert auth.response == expected
+    return "You are authenticated"
@@ -0,0 +1,7 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+if (Test-Path build/result.har) {
+    Remove-Item build/result.har
+}
+hurl --user-agent hurl --report-har build/result.har tests_ok/test.1.hurl tests_ok/cookies.hurl --variable name=Bruce > $null
+hurlfmt --in har build/result.har
@@ -0,0 +1,5 @@
+#!/bin/bash
+set -Eeuo pipefail
+rm -f build/result.har
+hurl --user-agent hurl --report-har build/result.har tests_ok/test.1.hurl tests_ok/cookies.hurl --variable name=Bruce >/dev/null
+hurlfmt --in har build/result.har
@@ -0,0 +1,7 @@
+from flask import request
+from app import app
+
+
+@app.route("/http-version")
+def http_version_protocol():
+    return request.environ["SERVER_PROTOCOL"]
@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurlfmt --no-color --format-bodies --sort-json-keys tests_ok/hurlfmt_format_bodies.in
@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurlfmt --no-color --format-bodies --sort-json-keys tests_ok/hurlfmt_format_bodies.in
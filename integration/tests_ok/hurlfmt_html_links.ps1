@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurlfmt --out html --line-numbers --links tests_ok/hurlfmt_html_links.in
@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurlfmt --out html --line-numbers --links tests_ok/hurlfmt_html_links.in
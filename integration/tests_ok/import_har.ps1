@@ -0,0 +1,4 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl tests_ok/import_har.out > $null  # Validate expected file
+hurlfmt --in har tests_ok/import_har.in
@@ -0,0 +1,4 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl tests_ok/import_har.out >/dev/null  # Validate expected file
+hurlfmt --in har tests_ok/import_har.in
@@ -0,0 +1,4 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl tests_ok/import_http.out > $null  # Validate expected file
+hurlfmt --in http tests_ok/import_http.in
@@ -0,0 +1,24 @@
+from app import app
+from flask import request, Response
+
+
+@app.route("/import-http/users", methods=["GET"])
+def import_http_list_users():
+    assert request.args["page"] == "2"
+    assert request.args["size"] == "10"
+    assert request.headers["Accept"] == "application/json"
+    return Response('[{"id": 1, "name": "Bob"}]', mimetype="application/json")
+
+
+@app.route("/import-http/users", methods=["POST"])
+def import_http_create_user():
+    assert request.headers["Authorization"] == "Bearer secret"
+    assert request.json == {"name": "Bob", "age": 30}
+    return Response('{"id": 1, "name": "Bob"}', status=201, mimetype="application/json")
+
+
+@app.route("/import-http/users/1/note", methods=["PUT"])
+def import_http_put_note():
+    assert request.headers["Content-Type"] == "text/plain"
+    assert request.data == b"Hello World!"
+    return ""
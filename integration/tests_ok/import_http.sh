@@ -0,0 +1,4 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl tests_ok/import_http.out >/dev/null  # Validate expected file
+hurlfmt --in http tests_ok/import_http.in
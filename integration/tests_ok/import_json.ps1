@@ -0,0 +1,4 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl tests_ok/import_json.out > $null  # Validate expected file
+hurlfmt --in json tests_ok/import_json.in
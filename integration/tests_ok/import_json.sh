@@ -0,0 +1,4 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl tests_ok/import_json.out >/dev/null  # Validate expected file
+hurlfmt --in json tests_ok/import_json.in
@@ -0,0 +1,4 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl --variable petId=1 tests_ok/import_openapi.out > $null  # Validate expected file
+hurlfmt --in openapi tests_ok/import_openapi.in
@@ -0,0 +1,34 @@
+from app import app
+from flask import request, Response
+
+
+@app.route("/openapi/pets", methods=["GET"])
+def openapi_list_pets():
+    assert request.args["limit"] == "10"
+    return Response('[{"id": 1, "name": "Rex"}]', mimetype="application/json")
+
+
+@app.route("/openapi/pets", methods=["POST"])
+def openapi_create_pet():
+    assert request.headers["X-Request-Id"] == "3fa85f64-5717-4562-b3fc-2c963f66afa6"
+    assert request.json == {"id": 1, "name": "Rex", "tag": "string", "birth": "2023-01-01"}
+    return Response('{"id": 1, "name": "Rex"}', status=201, mimetype="application/json")
+
+
+@app.route("/openapi/pets/<pet_id>", methods=["GET"])
+def openapi_show_pet(pet_id):
+    assert pet_id == "1"
+    return Response('{"id": 1, "name": "Rex"}', mimetype="application/json")
+
+
+@app.route("/openapi/pets/<pet_id>", methods=["DELETE"])
+def openapi_delete_pet(pet_id):
+    assert pet_id == "1"
+    return Response(status=204)
+
+
+@app.route("/openapi/login", methods=["POST"])
+def openapi_login():
+    assert request.form["username"] == "bob"
+    assert request.form["password"] == "secret#1"
+    return Response("Welcome bob", mimetype="text/plain")
@@ -0,0 +1,4 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl --variable petId=1 tests_ok/import_openapi.out >/dev/null  # Validate expected file
+hurlfmt --in openapi tests_ok/import_openapi.in
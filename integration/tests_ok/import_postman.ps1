@@ -0,0 +1,7 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl --variables-file tests_ok/import_postman.env tests_ok/import_postman.out > $null  # Validate expected file
+hurlfmt --in postman --variables-file build/import_postman.env tests_ok/import_postman.in
+if (Compare-Object (Get-Content build/import_postman.env) (Get-Content tests_ok/import_postman.env)) {
+    exit 1
+}
@@ -0,0 +1,5 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl --variables-file tests_ok/import_postman.env tests_ok/import_postman.out >/dev/null  # Validate expected file
+hurlfmt --in postman --variables-file build/import_postman.env tests_ok/import_postman.in
+diff build/import_postman.env tests_ok/import_postman.env
@@ -0,0 +1,8 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+if (Test-Path build/refactor) { Remove-Item -Recurse build/refactor }
+New-Item -ItemType Directory -Force -Path build/refactor | Out-Null
+Copy-Item tests_ok/refactor.in build/refactor/users.hurl
+hurlfmt --rename-variable id=user_id --replace-url-prefix http://localhost:8000/old-api=http://localhost:8000/api --extract-json-bodies --in-place build/refactor/users.hurl
+Get-Content build/refactor/users.hurl, build/refactor/users_1_request.json
+hurlfmt --inline-json-bodies build/refactor/users.hurl
@@ -0,0 +1,9 @@
+#!/bin/bash
+set -Eeuo pipefail
+rm -rf build/refactor
+mkdir -p build/refactor
+cp tests_ok/refactor.in build/refactor/users.hurl
+hurlfmt --rename-variable id=user_id --replace-url-prefix http://localhost:8000/old-api=http://localhost:8000/api --extract-json-bodies --in-place build/refactor/users.hurl
+cat build/refactor/users.hurl build/refactor/users_1_request.json
+echo
+hurlfmt --inline-json-bodies build/refactor/users.hurl
@@ -0,0 +1,39 @@
+#!/usr/bin/env python
+# usage: ./server.py <socket_path>
+# Start a server listening on a Unix domain socket
+import flask
+import os
+import sys
+
+app1 = flask.Flask("Unix Socket Server")
+
+
+@app1.route("/hello")
+def hello():
+    return "Hello World!"
+
+
+@app1.route("/unix-socket")
+def unix_socket():
+    return "Hello from Unix socket!"
+
+
+def start_server(socket_path):
+    if os.path.exists(socket_path):
+        os.remove(socket_path)
+    app1.run(host="unix://" + socket_path)
+
+
+def main():
+    if len(sys.argv) < 2:
+        print("usage: ./server.py <socket_path>")
+        sys.exit(1)
+
+    socket_path = sys.argv[1]
+    print("Starting Unix Socket Server")
+    print("  socket: " + socket_path)
+    start_server(socket_path)
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl unix_socket/unix_socket.hurl --unix-socket /tmp/hurl-test.sock --verbose
@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl unix_socket/unix_socket.hurl --unix-socket /tmp/hurl-test.sock --verbose
@@ -17,6 +17,14 @@
  */
 use clap::{value_parser, ArgAction};
 
+pub fn aws_sigv4() -> clap::Arg {
+    clap::Arg::new("aws_sigv4")
+        .long("aws-sigv4")
+        .value_name("PROVIDER1[:PROVIDER2[:REGION[:SERVICE]]]")
+        .help("Use AWS V4 signature authentication in the transfer")
+        .num_args(1)
+}
+
 pub fn cacert_file() -> clap::Arg {
     clap::Arg::new("cacert_file")
         .long("cacert")
@@ -102,6 +110,14 @@ pub fn cookies_output_file() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn digest() -> clap::Arg {
+    clap::Arg::new("digest")
+        .long("digest")
+        .help("Use HTTP Digest authentication")
+        .conflicts_with_all(["negotiate", "ntlm"])
+        .action(ArgAction::SetTrue)
+}
+
 pub fn error_format() -> clap::Arg {
     clap::Arg::new("error_format")
         .long("error-format")
@@ -143,6 +159,42 @@ pub fn glob() -> clap::Arg {
         .number_of_values(1)
 }
 
+pub fn http10() -> clap::Arg {
+    clap::Arg::new("http10")
+        .short('0')
+        .long("http1.0")
+        .help("Tell Hurl to use HTTP version 1.0")
+        .action(ArgAction::SetTrue)
+}
+
+pub fn http11() -> clap::Arg {
+    clap::Arg::new("http11")
+        .long("http1.1")
+        .help("Tell Hurl to use HTTP version 1.1")
+        .action(ArgAction::SetTrue)
+}
+
+pub fn http2() -> clap::Arg {
+    clap::Arg::new("http2")
+        .long("http2")
+        .help("Tell Hurl to use HTTP version 2")
+        .action(ArgAction::SetTrue)
+}
+
+pub fn http2_prior_knowledge() -> clap::Arg {
+    clap::Arg::new("http2_prior_knowledge")
+        .long("http2-prior-knowledge")
+        .help("Tell Hurl to use HTTP version 2 without HTTP/1.1 Upgrade")
+        .action(ArgAction::SetTrue)
+}
+
+pub fn http3() -> clap::Arg {
+    clap::Arg::new("http3")
+        .long("http3")
+        .help("Tell Hurl to use HTTP version 3")
+        .action(ArgAction::SetTrue)
+}
+
 pub fn include() -> clap::Arg {
     clap::Arg::new("include")
         .short('i')
@@ -213,6 +265,14 @@ pub fn max_time() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn negotiate() -> clap::Arg {
+    clap::Arg::new("negotiate")
+        .long("negotiate")
+        .help("Use HTTP Negotiate (SPNEGO) authentication")
+        .conflicts_with_all(["digest", "ntlm"])
+        .action(ArgAction::SetTrue)
+}
+
 pub fn no_color() -> clap::Arg {
     clap::Arg::new("no_color")
         .long("no-color")
@@ -237,6 +297,14 @@ pub fn noproxy() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn ntlm() -> clap::Arg {
+    clap::Arg::new("ntlm")
+        .long("ntlm")
+        .help("Use HTTP NTLM authentication")
+        .conflicts_with_all(["digest", "negotiate"])
+        .action(ArgAction::SetTrue)
+}
+
 pub fn output() -> clap::Arg {
     clap::Arg::new("output")
         .short('o')
@@ -278,6 +346,14 @@ pub fn report_tap() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn report_har() -> clap::Arg {
+    clap::Arg::new("har")
+        .long("report-har")
+        .value_name("FILE")
+        .help("Write a HAR report to FILE")
+        .num_args(1)
+}
+
 pub fn report_html() -> clap::Arg {
     clap::Arg::new("report_html")
         .long("report-html")
@@ -317,6 +393,13 @@ pub fn retry_interval() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn soft_asserts() -> clap::Arg {
+    clap::Arg::new("soft_asserts")
+        .long("soft-asserts")
+        .help("Continue executing requests when an assert fails and report all failing asserts")
+        .action(ArgAction::SetTrue)
+}
+
 pub fn ssl_no_revoke() -> clap::Arg {
     clap::Arg::new("ssl_no_revoke")
         .long("ssl-no-revoke")
@@ -342,6 +425,14 @@ pub fn to_entry() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn unix_socket() -> clap::Arg {
+    clap::Arg::new("unix_socket")
+        .long("unix-socket")
+        .value_name("PATH")
+        .help("Connect through this Unix domain socket, instead of using the network")
+        .num_args(1)
+}
+
 pub fn user() -> clap::Arg {
     clap::Arg::new("user")
         .short('u')
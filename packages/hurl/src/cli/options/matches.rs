@@ -20,6 +20,7 @@ use super::OptionsError;
 use crate::cli::options::ErrorFormat;
 use crate::cli::OutputType;
 use clap::ArgMatches;
+use hurl::http::{AuthMethod, RequestedHttpVersion};
 use hurl::runner::Value;
 use hurl_core::ast::Retry;
 use std::collections::HashMap;
@@ -29,6 +30,23 @@ use std::path::{Path, PathBuf};
 use std::time::Duration;
 use std::{env, io};
 
+/// Returns the HTTP authentication method requested on the command line.
+pub fn auth(arg_matches: &ArgMatches) -> AuthMethod {
+    if has_flag(arg_matches, "digest") {
+        AuthMethod::Digest
+    } else if has_flag(arg_matches, "negotiate") {
+        AuthMethod::Negotiate
+    } else if has_flag(arg_matches, "ntlm") {
+        AuthMethod::Ntlm
+    } else {
+        AuthMethod::Basic
+    }
+}
+
+pub fn aws_sigv4(arg_matches: &ArgMatches) -> Option<String> {
+    get::<String>(arg_matches, "aws_sigv4")
+}
+
 pub fn cacert_file(arg_matches: &ArgMatches) -> Result<Option<String>, OptionsError> {
     match get_string(arg_matches, "cacert_file") {
         None => Ok(None),
@@ -136,6 +154,10 @@ pub fn follow_location(arg_matches: &ArgMatches) -> bool {
     has_flag(arg_matches, "follow_location")
 }
 
+pub fn har_file(arg_matches: &ArgMatches) -> Option<String> {
+    get::<String>(arg_matches, "har")
+}
+
 pub fn html_dir(arg_matches: &ArgMatches) -> Result<Option<PathBuf>, OptionsError> {
     if let Some(dir) = get::<String>(arg_matches, "report_html") {
         let path = Path::new(&dir);
@@ -193,6 +215,25 @@ pub fn input_files(arg_matches: &ArgMatches) -> Result<Vec<String>, OptionsError
     Ok(files)
 }
 
+/// Returns the HTTP version requested on the command line.
+///
+/// If more than one version flag is used, the most recent HTTP version wins.
+pub fn http_version(arg_matches: &ArgMatches) -> RequestedHttpVersion {
+    if has_flag(arg_matches, "http3") {
+        RequestedHttpVersion::Http3
+    } else if has_flag(arg_matches, "http2_prior_knowledge") {
+        RequestedHttpVersion::Http2PriorKnowledge
+    } else if has_flag(arg_matches, "http2") {
+        RequestedHttpVersion::Http2
+    } else if has_flag(arg_matches, "http11") {
+        RequestedHttpVersion::Http11
+    } else if has_flag(arg_matches, "http10") {
+        RequestedHttpVersion::Http10
+    } else {
+        RequestedHttpVersion::Default
+    }
+}
+
 pub fn insecure(arg_matches: &ArgMatches) -> bool {
     has_flag(arg_matches, "insecure")
 }
@@ -264,6 +305,10 @@ pub fn retry_interval(arg_matches: &ArgMatches) -> Duration {
     Duration::from_millis(value)
 }
 
+pub fn soft_asserts(arg_matches: &ArgMatches) -> bool {
+    has_flag(arg_matches, "soft_asserts")
+}
+
 pub fn ssl_no_revoke(arg_matches: &ArgMatches) -> bool {
     has_flag(arg_matches, "ssl_no_revoke")
 }
@@ -285,6 +330,10 @@ pub fn to_entry(arg_matches: &ArgMatches) -> Option<usize> {
     get::<u32>(arg_matches, "to_entry").map(|x| x as usize)
 }
 
+pub fn unix_socket(arg_matches: &ArgMatches) -> Option<String> {
+    get::<String>(arg_matches, "unix_socket")
+}
+
 pub fn user(arg_matches: &ArgMatches) -> Option<String> {
     get::<String>(arg_matches, "user")
 }
@@ -36,6 +36,8 @@ use crate::runner::{RunnerOptions, RunnerOptionsBuilder, Value};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Options {
+    pub auth: http::AuthMethod,
+    pub aws_sigv4: Option<String>,
     pub cacert_file: Option<String>,
     pub client_cert_file: Option<String>,
     pub client_key_file: Option<String>,
@@ -49,7 +51,9 @@ pub struct Options {
     pub error_format: ErrorFormat,
     pub file_root: Option<String>,
     pub follow_location: bool,
+    pub har_file: Option<String>,
     pub html_dir: Option<PathBuf>,
+    pub http_version: http::RequestedHttpVersion,
     pub ignore_asserts: bool,
     pub include: bool,
     pub input_files: Vec<String>,
@@ -66,11 +70,13 @@ pub struct Options {
     pub resolves: Vec<String>,
     pub retry: Retry,
     pub retry_interval: Duration,
+    pub soft_asserts: bool,
     pub ssl_no_revoke: bool,
     pub tap_file: Option<String>,
     pub test: bool,
     pub timeout: Duration,
     pub to_entry: Option<usize>,
+    pub unix_socket: Option<String>,
     pub user: Option<String>,
     pub user_agent: Option<String>,
     pub variables: HashMap<String, Value>,
@@ -124,6 +130,7 @@ pub fn parse() -> Result<Options, OptionsError> {
         .version(get_version())
         .disable_colored_help(true)
         .about("Run Hurl file(s) or standard input")
+        .arg(commands::aws_sigv4())
         .arg(commands::cacert_file())
         .arg(commands::client_cert_file())
         .arg(commands::client_key_file())
@@ -134,11 +141,17 @@ pub fn parse() -> Result<Options, OptionsError> {
         .arg(commands::continue_on_error())
         .arg(commands::cookies_input_file())
         .arg(commands::cookies_output_file())
+        .arg(commands::digest())
         .arg(commands::error_format())
         .arg(commands::fail_at_end())
         .arg(commands::file_root())
         .arg(commands::follow_location())
         .arg(commands::glob())
+        .arg(commands::http10())
+        .arg(commands::http11())
+        .arg(commands::http2())
+        .arg(commands::http2_prior_knowledge())
+        .arg(commands::http3())
         .arg(commands::ignore_asserts())
         .arg(commands::include())
         .arg(commands::input_files())
@@ -147,21 +160,26 @@ pub fn parse() -> Result<Options, OptionsError> {
         .arg(commands::json())
         .arg(commands::max_redirects())
         .arg(commands::max_time())
+        .arg(commands::negotiate())
         .arg(commands::no_color())
         .arg(commands::no_output())
         .arg(commands::noproxy())
+        .arg(commands::ntlm())
         .arg(commands::output())
         .arg(commands::path_as_is())
         .arg(commands::proxy())
+        .arg(commands::report_har())
         .arg(commands::report_html())
         .arg(commands::report_junit())
         .arg(commands::report_tap())
         .arg(commands::resolve())
         .arg(commands::retry())
         .arg(commands::retry_interval())
+        .arg(commands::soft_asserts())
         .arg(commands::ssl_no_revoke())
         .arg(commands::test())
         .arg(commands::to_entry())
+        .arg(commands::unix_socket())
         .arg(commands::user_agent())
         .arg(commands::user())
         .arg(commands::variable())
@@ -188,6 +206,8 @@ pub fn parse() -> Result<Options, OptionsError> {
 }
 
 fn parse_matches(arg_matches: &ArgMatches) -> Result<Options, OptionsError> {
+    let auth = matches::auth(arg_matches);
+    let aws_sigv4 = matches::aws_sigv4(arg_matches);
     let cacert_file = matches::cacert_file(arg_matches)?;
     let client_cert_file = matches::client_cert_file(arg_matches)?;
     let client_key_file = matches::client_key_file(arg_matches)?;
@@ -201,7 +221,9 @@ fn parse_matches(arg_matches: &ArgMatches) -> Result<Options, OptionsError> {
     let error_format = matches::error_format(arg_matches);
     let file_root = matches::file_root(arg_matches);
     let follow_location = matches::follow_location(arg_matches);
+    let har_file = matches::har_file(arg_matches);
     let html_dir = matches::html_dir(arg_matches)?;
+    let http_version = matches::http_version(arg_matches);
     let ignore_asserts = matches::ignore_asserts(arg_matches);
     let include = matches::include(arg_matches);
     let input_files = matches::input_files(arg_matches)?;
@@ -218,17 +240,21 @@ fn parse_matches(arg_matches: &ArgMatches) -> Result<Options, OptionsError> {
     let resolves = matches::resolves(arg_matches);
     let retry = matches::retry(arg_matches);
     let retry_interval = matches::retry_interval(arg_matches);
+    let soft_asserts = matches::soft_asserts(arg_matches);
     let ssl_no_revoke = matches::ssl_no_revoke(arg_matches);
     let tap_file = matches::tap_file(arg_matches);
     let test = matches::test(arg_matches);
     let timeout = matches::timeout(arg_matches);
     let to_entry = matches::to_entry(arg_matches);
+    let unix_socket = matches::unix_socket(arg_matches);
     let user = matches::user(arg_matches);
     let user_agent = matches::user_agent(arg_matches);
     let variables = matches::variables(arg_matches)?;
     let verbose = matches::verbose(arg_matches);
     let very_verbose = matches::very_verbose(arg_matches);
     Ok(Options {
+        auth,
+        aws_sigv4,
         cacert_file,
         client_cert_file,
         client_key_file,
@@ -242,7 +268,9 @@ fn parse_matches(arg_matches: &ArgMatches) -> Result<Options, OptionsError> {
         error_format,
         file_root,
         follow_location,
+        har_file,
         html_dir,
+        http_version,
         ignore_asserts,
         include,
         input_files,
@@ -259,11 +287,13 @@ fn parse_matches(arg_matches: &ArgMatches) -> Result<Options, OptionsError> {
         resolves,
         retry,
         retry_interval,
+        soft_asserts,
         ssl_no_revoke,
         tap_file,
         test,
         timeout,
         to_entry,
+        unix_socket,
         user,
         user_agent,
         variables,
@@ -281,11 +311,14 @@ pub enum OutputType {
 
 impl Options {
     pub fn to_runner_options(&self, filename: &str, current_dir: &Path) -> RunnerOptions {
+        let auth = self.auth;
+        let aws_sigv4 = self.aws_sigv4.clone();
         let cacert_file = self.cacert_file.clone();
         let client_cert_file = self.client_cert_file.clone();
         let client_key_file = self.client_key_file.clone();
         let connects_to = self.connects_to.clone();
         let follow_location = self.follow_location;
+        let http_version = self.http_version;
         let insecure = self.insecure;
         let max_redirect = self.max_redirect;
         let path_as_is = self.path_as_is;
@@ -321,13 +354,17 @@ impl Options {
             None
         };
         let to_entry = self.to_entry;
+        let unix_socket = self.unix_socket.clone();
         let resolves = self.resolves.clone();
         let retry = self.retry;
         let retry_interval = self.retry_interval;
         let ignore_asserts = self.ignore_asserts;
+        let soft_asserts = self.soft_asserts;
         let ssl_no_revoke = self.ssl_no_revoke;
 
         RunnerOptionsBuilder::new()
+            .auth(auth)
+            .aws_sigv4(aws_sigv4)
             .cacert_file(cacert_file)
             .client_cert_file(client_cert_file)
             .client_key_file(client_key_file)
@@ -338,6 +375,7 @@ impl Options {
             .context_dir(&context_dir)
             .cookie_input_file(cookie_input_file)
             .follow_location(follow_location)
+            .http_version(http_version)
             .ignore_asserts(ignore_asserts)
             .insecure(insecure)
             .max_redirect(max_redirect)
@@ -349,9 +387,11 @@ impl Options {
             .resolves(&resolves)
             .retry(retry)
             .retry_interval(retry_interval)
+            .soft_asserts(soft_asserts)
             .ssl_no_revoke(ssl_no_revoke)
             .timeout(timeout)
             .to_entry(to_entry)
+            .unix_socket(unix_socket)
             .user(user)
             .user_agent(user_agent)
             .build()
@@ -29,7 +29,7 @@ use url::Url;
 
 use crate::http::certificate::Certificate;
 use crate::http::core::*;
-use crate::http::options::ClientOptions;
+use crate::http::options::{AuthMethod, ClientOptions, RequestedHttpVersion};
 use crate::http::request::*;
 use crate::http::request_spec::*;
 use crate::http::response::*;
@@ -152,12 +152,17 @@ impl Client {
             self.handle.ssl_cert_type("PEM")?;
         }
         self.handle.path_as_is(options.path_as_is)?;
+        self.set_http_version(options.http_version)?;
+        self.set_auth(options)?;
         if let Some(proxy) = options.proxy.clone() {
             self.handle.proxy(proxy.as_str())?;
         }
         if let Some(s) = options.no_proxy.clone() {
             self.handle.noproxy(s.as_str())?;
         }
+        if let Some(unix_socket) = options.unix_socket.clone() {
+            self.handle.unix_socket(unix_socket.as_str())?;
+        }
         self.handle.timeout(options.timeout)?;
         self.handle.connect_timeout(options.connect_timeout)?;
 
@@ -437,10 +442,13 @@ impl Client {
             list.append(format!("User-Agent: {user_agent}").as_str())?;
         }
 
+        // Basic authentication is sent with an explicit header, other authentication methods
+        // are delegated to libcurl (see `set_auth`).
         if let Some(ref user) = options.user {
             let user = user.as_bytes();
             let authorization = general_purpose::STANDARD.encode(user);
-            if request.get_header_values("Authorization").is_empty() {
+            let basic = options.auth == AuthMethod::Basic && options.aws_sigv4.is_none();
+            if basic && request.get_header_values("Authorization").is_empty() {
                 list.append(format!("Authorization: Basic {authorization}").as_str())?;
             }
         }
@@ -512,6 +520,55 @@ impl Client {
         Ok(())
     }
 
+    /// Sets the credentials and authentication method delegated to libcurl.
+    ///
+    /// Basic authentication is not set here as it's sent through an explicit `Authorization`
+    /// header (see `set_headers`).
+    fn set_auth(&mut self, options: &ClientOptions) -> Result<(), HttpError> {
+        if options.auth == AuthMethod::Basic && options.aws_sigv4.is_none() {
+            return Ok(());
+        }
+        // SPNEGO can authenticate without any credentials, in this case
+        // libcurl must still be given an empty user and password.
+        let (username, password) = match &options.user {
+            Some(user) => match user.split_once(':') {
+                Some((username, password)) => (username, password),
+                None => (user.as_str(), ""),
+            },
+            None => ("", ""),
+        };
+        self.handle.username(username)?;
+        self.handle.password(password)?;
+
+        let mut auth = easy::Auth::new();
+        match options.auth {
+            AuthMethod::Basic => auth.basic(true),
+            AuthMethod::Digest => auth.digest(true),
+            AuthMethod::Negotiate => auth.gssnegotiate(true),
+            AuthMethod::Ntlm => auth.ntlm(true),
+        };
+        self.handle.http_auth(&auth)?;
+
+        if let Some(aws_sigv4) = &options.aws_sigv4 {
+            self.handle.aws_sigv4(aws_sigv4)?;
+        }
+        Ok(())
+    }
+
+    /// Sets the HTTP version used by libcurl for the next transfer.
+    fn set_http_version(&mut self, version: RequestedHttpVersion) -> Result<(), HttpError> {
+        let version = match version {
+            RequestedHttpVersion::Default => easy::HttpVersion::Any,
+            RequestedHttpVersion::Http10 => easy::HttpVersion::V10,
+            RequestedHttpVersion::Http11 => easy::HttpVersion::V11,
+            RequestedHttpVersion::Http2 => easy::HttpVersion::V2,
+            RequestedHttpVersion::Http2PriorKnowledge => easy::HttpVersion::V2PriorKnowledge,
+            RequestedHttpVersion::Http3 => easy::HttpVersion::V3,
+        };
+        self.handle.http_version(version)?;
+        Ok(())
+    }
+
     /// Sets SSL options
     fn set_ssl_options(&mut self, no_revoke: bool) -> Result<(), HttpError> {
         let mut ssl_opt = SslOpt::new();
@@ -540,6 +597,8 @@ impl Client {
             Ok(Version::Http11)
         } else if line.starts_with("HTTP/2") {
             Ok(Version::Http2)
+        } else if line.starts_with("HTTP/3") {
+            Ok(Version::Http3)
         } else {
             Err(HttpError::CouldNotParseResponse)
         }
@@ -22,6 +22,7 @@ pub use self::cookie::{CookieAttribute, ResponseCookie};
 pub(crate) use self::core::{Cookie, Param, RequestCookie};
 pub(crate) use self::error::HttpError;
 pub use self::header::Header;
+pub use self::options::{AuthMethod, RequestedHttpVersion};
 pub(crate) use self::options::{ClientOptions, Verbosity};
 pub use self::request::Request;
 pub(crate) use self::request_spec::{Body, FileParam, Method, MultipartParam, RequestSpec};
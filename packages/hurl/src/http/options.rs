@@ -16,10 +16,13 @@
  *
  */
 use hurl_core::ast::Retry;
+use std::fmt;
 use std::time::Duration;
 
 #[derive(Debug, Clone)]
 pub struct ClientOptions {
+    pub auth: AuthMethod,
+    pub aws_sigv4: Option<String>,
     pub cacert_file: Option<String>,
     pub client_cert_file: Option<String>,
     pub client_key_file: Option<String>,
@@ -28,6 +31,7 @@ pub struct ClientOptions {
     pub connects_to: Vec<String>,
     pub cookie_input_file: Option<String>,
     pub follow_location: bool,
+    pub http_version: RequestedHttpVersion,
     pub insecure: bool,
     pub max_redirect: Option<usize>,
     pub no_proxy: Option<String>,
@@ -37,6 +41,7 @@ pub struct ClientOptions {
     pub retry: Retry,
     pub ssl_no_revoke: bool,
     pub timeout: Duration,
+    pub unix_socket: Option<String>,
     pub user: Option<String>,
     pub user_agent: Option<String>,
     pub verbosity: Option<Verbosity>,
@@ -48,9 +53,61 @@ pub enum Verbosity {
     VeryVerbose,
 }
 
+/// HTTP authentication method used with the user credentials.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AuthMethod {
+    #[default]
+    Basic,
+    Digest,
+    Negotiate,
+    Ntlm,
+}
+
+impl fmt::Display for AuthMethod {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = match self {
+            AuthMethod::Basic => "basic",
+            AuthMethod::Digest => "digest",
+            AuthMethod::Negotiate => "negotiate",
+            AuthMethod::Ntlm => "ntlm",
+        };
+        write!(f, "{value}")
+    }
+}
+
+/// HTTP version requested by the user (CLI or `[Options]` section).
+///
+/// [`RequestedHttpVersion::Default`] lets libcurl choose the best version to use.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RequestedHttpVersion {
+    #[default]
+    Default,
+    Http10,
+    Http11,
+    Http2,
+    Http2PriorKnowledge,
+    Http3,
+}
+
+impl fmt::Display for RequestedHttpVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = match self {
+            RequestedHttpVersion::Default => "HTTP (default)",
+            RequestedHttpVersion::Http10 => "HTTP/1.0",
+            RequestedHttpVersion::Http11 => "HTTP/1.1",
+            RequestedHttpVersion::Http2 => "HTTP/2",
+            RequestedHttpVersion::Http2PriorKnowledge => "HTTP/2 (prior knowledge)",
+            RequestedHttpVersion::Http3 => "HTTP/3",
+        };
+        write!(f, "{value}")
+    }
+}
+
 impl Default for ClientOptions {
     fn default() -> Self {
         ClientOptions {
+            auth: AuthMethod::Basic,
+            aws_sigv4: None,
             cacert_file: None,
             client_cert_file: None,
             client_key_file: None,
@@ -59,6 +116,7 @@ impl Default for ClientOptions {
             connects_to: vec![],
             cookie_input_file: None,
             follow_location: false,
+            http_version: RequestedHttpVersion::Default,
             insecure: false,
             max_redirect: Some(50),
             no_proxy: None,
@@ -68,6 +126,7 @@ impl Default for ClientOptions {
             retry: Retry::None,
             ssl_no_revoke: false,
             timeout: Duration::from_secs(300),
+            unix_socket: None,
             user: None,
             user_agent: None,
             verbosity: None,
@@ -80,6 +139,10 @@ impl ClientOptions {
     pub fn curl_args(&self) -> Vec<String> {
         let mut arguments = vec![];
 
+        if let Some(ref aws_sigv4) = self.aws_sigv4 {
+            arguments.push("--aws-sigv4".to_string());
+            arguments.push(aws_sigv4.clone());
+        }
         if let Some(ref cacert_file) = self.cacert_file {
             arguments.push("--cacert".to_string());
             arguments.push(cacert_file.clone());
@@ -107,6 +170,19 @@ impl ClientOptions {
             arguments.push("--cookie".to_string());
             arguments.push(cookie_file.clone());
         }
+        if self.auth == AuthMethod::Digest {
+            arguments.push("--digest".to_string());
+        }
+        match self.http_version {
+            RequestedHttpVersion::Default => {}
+            RequestedHttpVersion::Http10 => arguments.push("--http1.0".to_string()),
+            RequestedHttpVersion::Http11 => arguments.push("--http1.1".to_string()),
+            RequestedHttpVersion::Http2 => arguments.push("--http2".to_string()),
+            RequestedHttpVersion::Http2PriorKnowledge => {
+                arguments.push("--http2-prior-knowledge".to_string())
+            }
+            RequestedHttpVersion::Http3 => arguments.push("--http3".to_string()),
+        }
         if self.insecure {
             arguments.push("--insecure".to_string());
         }
@@ -121,6 +197,11 @@ impl ClientOptions {
             arguments.push("--max-redirs".to_string());
             arguments.push(max_redirect.to_string());
         }
+        match self.auth {
+            AuthMethod::Negotiate => arguments.push("--negotiate".to_string()),
+            AuthMethod::Ntlm => arguments.push("--ntlm".to_string()),
+            AuthMethod::Basic | AuthMethod::Digest => {}
+        }
         if self.path_as_is {
             arguments.push("--path-as-is".to_string());
         }
@@ -136,6 +217,10 @@ impl ClientOptions {
             arguments.push("--timeout".to_string());
             arguments.push(self.timeout.as_secs().to_string());
         }
+        if let Some(ref unix_socket) = self.unix_socket {
+            arguments.push("--unix-socket".to_string());
+            arguments.push(format!("'{unix_socket}'"));
+        }
         if let Some(ref user) = self.user {
             arguments.push("--user".to_string());
             arguments.push(format!("'{user}'"));
@@ -158,11 +243,14 @@ mod tests {
 
         assert_eq!(
             ClientOptions {
+                auth: AuthMethod::Digest,
+                aws_sigv4: None,
                 cacert_file: None,
                 client_cert_file: None,
                 client_key_file: None,
                 connects_to: vec!["example.com:443:host-47.example.com:443".to_string()],
                 follow_location: true,
+                http_version: RequestedHttpVersion::Http2PriorKnowledge,
                 max_redirect: Some(10),
                 cookie_input_file: Some("cookie_file".to_string()),
                 path_as_is: true,
@@ -178,6 +266,7 @@ mod tests {
                 ssl_no_revoke: false,
                 timeout: Duration::from_secs(10),
                 connect_timeout: Duration::from_secs(20),
+                unix_socket: Some("/var/run/example.sock".to_string()),
                 user: Some("user:password".to_string()),
                 user_agent: Some("my-useragent".to_string()),
                 compressed: true,
@@ -191,6 +280,8 @@ mod tests {
                 "example.com:443:host-47.example.com:443".to_string(),
                 "--cookie".to_string(),
                 "cookie_file".to_string(),
+                "--digest".to_string(),
+                "--http2-prior-knowledge".to_string(),
                 "--insecure".to_string(),
                 "--location".to_string(),
                 "--max-redirs".to_string(),
@@ -204,6 +295,8 @@ mod tests {
                 "bar.com:443:127.0.0.1".to_string(),
                 "--timeout".to_string(),
                 "10".to_string(),
+                "--unix-socket".to_string(),
+                "'/var/run/example.sock'".to_string(),
                 "--user".to_string(),
                 "'user:password'".to_string(),
                 "--user-agent".to_string(),
@@ -211,4 +304,29 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn test_curl_args_auth() {
+        let options = ClientOptions {
+            auth: AuthMethod::Ntlm,
+            user: Some("user:password".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(options.curl_args(), ["--ntlm", "--user", "'user:password'"]);
+
+        let options = ClientOptions {
+            aws_sigv4: Some("aws:amz:eu-central-1:s3".to_string()),
+            user: Some("key:secret".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            options.curl_args(),
+            [
+                "--aws-sigv4",
+                "aws:amz:eu-central-1:s3",
+                "--user",
+                "'key:secret'"
+            ]
+        );
+    }
 }
@@ -53,6 +53,7 @@ pub enum Version {
     Http10,
     Http11,
     Http2,
+    Http3,
 }
 
 impl fmt::Display for Version {
@@ -61,6 +62,7 @@ impl fmt::Display for Version {
             Version::Http10 => "HTTP/1.0",
             Version::Http11 => "HTTP/1.1",
             Version::Http2 => "HTTP/2",
+            Version::Http3 => "HTTP/3",
         };
         write!(f, "{value}")
     }
@@ -24,7 +24,7 @@ use std::{env, process};
 
 use crate::cli::options::OptionsError;
 use colored::control;
-use hurl::report::{html, junit, tap};
+use hurl::report::{har, html, junit, tap};
 use hurl::runner::HurlResult;
 use hurl::util::logger::{BaseLogger, Logger, LoggerOptionsBuilder, Verbosity};
 use hurl::{output, runner};
@@ -151,6 +151,12 @@ fn main() {
         unwrap_or_exit(result, EXIT_ERROR_UNDEFINED, &base_logger);
     }
 
+    if let Some(filename) = opts.har_file {
+        base_logger.debug(format!("Writing HAR report to {filename}").as_str());
+        let result = create_har_report(&runs, &filename);
+        unwrap_or_exit(result, EXIT_ERROR_UNDEFINED, &base_logger);
+    }
+
     if let Some(dir) = opts.html_dir {
         base_logger.debug(format!("Writing HTML report to {}", dir.display()).as_str());
         let result = create_html_report(&runs, &dir);
@@ -235,6 +241,16 @@ fn create_tap_report(runs: &[HurlRun], filename: &str) -> Result<(), cli::CliErr
     Ok(())
 }
 
+/// Create a HAR report for this run.
+fn create_har_report(runs: &[HurlRun], filename: &str) -> Result<(), cli::CliError> {
+    let testcases: Vec<har::Testcase> = runs
+        .iter()
+        .map(|r| har::Testcase::from(&r.hurl_result, &r.filename))
+        .collect();
+    har::write_report(filename, &testcases)?;
+    Ok(())
+}
+
 /// Create an HTML report for this run.
 fn create_html_report(runs: &[HurlRun], dir_path: &Path) -> Result<(), cli::CliError> {
     // We ensure that the containing folder exists.
@@ -0,0 +1,342 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2023 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+//! HAR report
+//!
+//! Writes all the HTTP calls of Hurl runs as an [HTTP Archive 1.2](http://www.softwareishard.com/blog/har-12-spec/)
+//! document, that can be opened in browsers developer tools and performance tooling.
+//!
+//! Each Hurl file is described by a HAR page, and each call (including redirections and retries)
+//! by a HAR entry.
+mod testcase;
+
+use std::fs::File;
+use std::io::Write;
+
+use base64::engine::general_purpose;
+use base64::Engine;
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde_json::{json, Value};
+
+use crate::http::{Call, Header, Request, Response, Timings, Version};
+use crate::report::Error;
+pub use testcase::Testcase;
+
+/// Creates a HAR report from a list of `testcases`.
+///
+/// Contrary to JUnit and TAP reports, an existing HAR file is overwritten.
+pub fn write_report(filename: &str, testcases: &[Testcase]) -> Result<(), Error> {
+    let har = to_har(testcases);
+    let mut file = match File::create(filename) {
+        Ok(f) => f,
+        Err(e) => {
+            return Err(Error {
+                message: format!("Failed to produce HAR report: {e:?}"),
+            });
+        }
+    };
+    let s = serde_json::to_string_pretty(&har).unwrap();
+    match file.write_all(s.as_bytes()) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(Error {
+            message: format!("Failed to write HAR report: {e:?}"),
+        }),
+    }
+}
+
+/// Returns the HAR document of a list of `testcases`.
+fn to_har(testcases: &[Testcase]) -> Value {
+    let mut pages = vec![];
+    let mut entries = vec![];
+    for (i, testcase) in testcases.iter().enumerate() {
+        let page_id = format!("page_{}", i + 1);
+        let started = match testcase.calls.first() {
+            Some(call) => call.timings.begin_call,
+            None => continue,
+        };
+        pages.push(json!({
+            "startedDateTime": json_date(started),
+            "id": page_id,
+            "title": testcase.filename,
+            "pageTimings": {"onContentLoad": -1, "onLoad": -1},
+        }));
+        for call in &testcase.calls {
+            entries.push(call_to_json(call, &page_id));
+        }
+    }
+    json!({
+        "log": {
+            "version": "1.2",
+            "creator": {"name": "hurl", "version": env!("CARGO_PKG_VERSION")},
+            "pages": pages,
+            "entries": entries,
+        }
+    })
+}
+
+fn call_to_json(call: &Call, page_id: &str) -> Value {
+    let version = version_to_json(&call.response.version);
+    json!({
+        "pageref": page_id,
+        "startedDateTime": json_date(call.timings.begin_call),
+        "time": millis(call.timings.total),
+        "request": request_to_json(&call.request, version),
+        "response": response_to_json(&call.response),
+        "cache": {},
+        "timings": timings_to_json(&call.timings),
+    })
+}
+
+fn request_to_json(request: &Request, version: &str) -> Value {
+    let cookies = request
+        .cookies()
+        .iter()
+        .map(|c| json!({"name": c.name, "value": c.value}))
+        .collect::<Vec<_>>();
+    let query_string = request
+        .query_string_params()
+        .iter()
+        .map(|p| json!({"name": p.name, "value": p.value}))
+        .collect::<Vec<_>>();
+    let mut value = json!({
+        "method": request.method,
+        "url": request.url,
+        "httpVersion": version,
+        "cookies": cookies,
+        "headers": headers_to_json(&request.headers),
+        "queryString": query_string,
+        "headersSize": -1,
+        "bodySize": request.body.len(),
+    });
+    if !request.body.is_empty() {
+        let mime_type = request.content_type().unwrap_or_default();
+        let text = String::from_utf8_lossy(&request.body);
+        value["postData"] = json!({"mimeType": mime_type, "text": text});
+    }
+    value
+}
+
+fn response_to_json(response: &Response) -> Value {
+    let cookies = response
+        .cookies()
+        .iter()
+        .map(|c| {
+            let mut cookie = json!({"name": c.name, "value": c.value});
+            if let Some(path) = c.path() {
+                cookie["path"] = json!(path);
+            }
+            if let Some(domain) = c.domain() {
+                cookie["domain"] = json!(domain);
+            }
+            if let Some(expires) = c.expires() {
+                cookie["expires"] = json!(expires);
+            }
+            if c.has_httponly() {
+                cookie["httpOnly"] = json!(true);
+            }
+            if c.has_secure() {
+                cookie["secure"] = json!(true);
+            }
+            cookie
+        })
+        .collect::<Vec<_>>();
+    let mime_type = response.content_type().unwrap_or_default();
+    let body = response
+        .uncompress_body()
+        .unwrap_or_else(|_| response.body.clone());
+    let content = match std::str::from_utf8(&body) {
+        Ok(text) => json!({"size": body.len(), "mimeType": mime_type, "text": text}),
+        Err(_) => json!({
+            "size": body.len(),
+            "mimeType": mime_type,
+            "text": general_purpose::STANDARD.encode(&body),
+            "encoding": "base64",
+        }),
+    };
+    let redirect_url = response
+        .get_header_values("Location")
+        .first()
+        .cloned()
+        .unwrap_or_default();
+    json!({
+        "status": response.status,
+        "statusText": "",
+        "httpVersion": version_to_json(&response.version),
+        "cookies": cookies,
+        "headers": headers_to_json(&response.headers),
+        "content": content,
+        "redirectURL": redirect_url,
+        "headersSize": -1,
+        "bodySize": response.body.len(),
+    })
+}
+
+fn headers_to_json(headers: &[Header]) -> Vec<Value> {
+    headers
+        .iter()
+        .map(|h| json!({"name": h.name, "value": h.value}))
+        .collect()
+}
+
+fn version_to_json(version: &Version) -> &'static str {
+    match version {
+        Version::Http10 => "HTTP/1.0",
+        Version::Http11 => "HTTP/1.1",
+        Version::Http2 => "HTTP/2",
+        Version::Http3 => "HTTP/3",
+    }
+}
+
+/// Maps libcurl `timings` to HAR timings.
+///
+/// libcurl timings are cumulative from the start of the transfer whereas HAR timings are
+/// durations of each phase. HAR `connect` includes the TLS handshake, and phases that don't
+/// apply (like `ssl` for an HTTP request) are set to -1.
+fn timings_to_json(timings: &Timings) -> Value {
+    let connected = timings.app_connect.max(timings.connect);
+    let ssl = if timings.app_connect.is_zero() {
+        json!(-1)
+    } else {
+        json!(millis(timings.app_connect.saturating_sub(timings.connect)))
+    };
+    json!({
+        "blocked": -1,
+        "dns": millis(timings.name_lookup),
+        "connect": millis(connected.saturating_sub(timings.name_lookup)),
+        "ssl": ssl,
+        "send": millis(timings.pre_transfer.saturating_sub(connected)),
+        "wait": millis(timings.start_transfer.saturating_sub(timings.pre_transfer)),
+        "receive": millis(timings.total.saturating_sub(timings.start_transfer)),
+    })
+}
+
+/// Returns a duration in milliseconds, with a microsecond precision.
+fn millis(duration: std::time::Duration) -> f64 {
+    duration.as_micros() as f64 / 1000.0
+}
+
+fn json_date(date: DateTime<Utc>) -> String {
+    date.to_rfc3339_opts(SecondsFormat::Millis, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use chrono::TimeZone;
+
+    use super::*;
+
+    #[test]
+    fn test_timings() {
+        let timings = Timings {
+            begin_call: Utc.with_ymd_and_hms(2023, 8, 28, 9, 0, 0).unwrap(),
+            end_call: Utc.with_ymd_and_hms(2023, 8, 28, 9, 0, 1).unwrap(),
+            name_lookup: Duration::from_micros(1_000),
+            connect: Duration::from_micros(3_000),
+            app_connect: Duration::from_micros(10_000),
+            pre_transfer: Duration::from_micros(10_500),
+            start_transfer: Duration::from_micros(30_500),
+            total: Duration::from_micros(31_250),
+        };
+        assert_eq!(
+            timings_to_json(&timings),
+            json!({
+                "blocked": -1,
+                "dns": 1.0,
+                "connect": 9.0,
+                "ssl": 7.0,
+                "send": 0.5,
+                "wait": 20.0,
+                "receive": 0.75,
+            })
+        );
+
+        let timings = Timings {
+            app_connect: Duration::default(),
+            ..timings
+        };
+        assert_eq!(timings_to_json(&timings)["connect"], json!(2.0));
+        assert_eq!(timings_to_json(&timings)["ssl"], json!(-1));
+    }
+
+    #[test]
+    fn test_to_har() {
+        let call = Call {
+            request: Request {
+                url: "http://localhost:8000/hello?name=Bob".to_string(),
+                method: "GET".to_string(),
+                headers: vec![Header::new("Cookie", "user=Bob")],
+                body: vec![],
+            },
+            response: Response {
+                version: Version::Http11,
+                status: 301,
+                headers: vec![Header::new("Location", "http://localhost:8000/hi")],
+                body: vec![0xff],
+                ..Default::default()
+            },
+            timings: Timings {
+                begin_call: Utc.with_ymd_and_hms(2023, 8, 28, 9, 0, 0).unwrap(),
+                total: Duration::from_millis(12),
+                ..Default::default()
+            },
+        };
+        let testcases = vec![
+            Testcase {
+                filename: "tests/empty.hurl".to_string(),
+                calls: vec![],
+            },
+            Testcase {
+                filename: "tests/hello.hurl".to_string(),
+                calls: vec![call],
+            },
+        ];
+        let har = to_har(&testcases);
+        assert_eq!(
+            har["log"]["pages"],
+            json!([{
+                "startedDateTime": "2023-08-28T09:00:00.000Z",
+                "id": "page_2",
+                "title": "tests/hello.hurl",
+                "pageTimings": {"onContentLoad": -1, "onLoad": -1},
+            }])
+        );
+        let entry = &har["log"]["entries"][0];
+        assert_eq!(entry["pageref"], json!("page_2"));
+        assert_eq!(entry["time"], json!(12.0));
+        assert_eq!(entry["request"]["httpVersion"], json!("HTTP/1.1"));
+        assert_eq!(
+            entry["request"]["cookies"],
+            json!([{"name": "user", "value": "Bob"}])
+        );
+        assert_eq!(
+            entry["request"]["queryString"],
+            json!([{"name": "name", "value": "Bob"}])
+        );
+        assert!(entry["request"].get("postData").is_none());
+        assert_eq!(
+            entry["response"]["redirectURL"],
+            json!("http://localhost:8000/hi")
+        );
+        assert_eq!(
+            entry["response"]["content"],
+            json!({"size": 1, "mimeType": "", "text": "/w==", "encoding": "base64"})
+        );
+    }
+}
@@ -17,6 +17,7 @@
  */
 use crate::report::html::nav::Tab;
 use hurl_core::ast::HurlFile;
+use hurl_core::format::{HtmlOptions, HtmlTheme};
 use regex::{Captures, Regex};
 
 use crate::report::html::Testcase;
@@ -27,11 +28,15 @@ impl Testcase {
     pub fn get_source_html(&self, hurl_file: &HurlFile, content: &str) -> String {
         let nav = self.get_nav_html(content, Tab::Source);
         let nav_css = include_str!("resources/nav.css");
-        let source_div = hurl_core::format::format_html(hurl_file, false);
+        let options = HtmlOptions {
+            links: true,
+            ..HtmlOptions::default()
+        };
+        let source_div = hurl_core::format::format_html(hurl_file, &options);
         let source_div = underline_errors(&source_div, &self.errors);
         let lines_div = get_numbered_lines(content);
         let source_css = include_str!("resources/source.css");
-        let hurl_css = hurl_core::format::hurl_css();
+        let hurl_css = hurl_core::format::hurl_css(HtmlTheme::Light);
         format!(
             include_str!("resources/source.html"),
             filename = self.filename,
@@ -21,6 +21,7 @@
 
 use std::fmt;
 
+pub mod har;
 pub mod html;
 pub mod junit;
 pub mod tap;
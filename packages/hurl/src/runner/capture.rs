@@ -22,6 +22,7 @@ use hurl_core::ast::*;
 use crate::http;
 use crate::runner::core::{CaptureResult, Error, RunnerError};
 use crate::runner::filter::eval_filters;
+use crate::runner::options::eval_variable_value;
 use crate::runner::query::eval_query;
 use crate::runner::value::Value;
 
@@ -35,26 +36,23 @@ pub fn eval_capture(
     let name = &capture.name.value;
     let value = eval_query(&capture.query, variables, http_response)?;
     let value = match value {
-        None => {
+        None => None,
+        Some(value) => {
+            let filters = capture.filters.iter().map(|(_, f)| f.clone()).collect();
+            eval_filters(&filters, &value, variables, false)?
+        }
+    };
+    // A capture with no result falls back to its default value, if any.
+    let value = match (value, &capture.default) {
+        (Some(value), _) => value,
+        (None, Some(default)) => eval_variable_value(&default.value, variables)?,
+        (None, None) => {
             return Err(Error {
                 source_info: capture.query.source_info.clone(),
                 inner: RunnerError::NoQueryResult,
                 assert: false,
             });
         }
-        Some(value) => {
-            let filters = capture.filters.iter().map(|(_, f)| f.clone()).collect();
-            match eval_filters(&filters, &value, variables, false)? {
-                None => {
-                    return Err(Error {
-                        source_info: capture.query.source_info.clone(),
-                        inner: RunnerError::NoQueryResult,
-                        assert: false,
-                    })
-                }
-                Some(v) => v,
-            }
-        }
     };
 
     Ok(CaptureResult {
@@ -91,6 +89,7 @@ pub mod tests {
             // xpath count(//user)
             query: query::tests::xpath_count_user_query(),
             filters: vec![],
+            default: None,
             line_terminator0: LineTerminator {
                 space0: whitespace.clone(),
                 comment: None,
@@ -120,6 +119,7 @@ pub mod tests {
             // xpath count(//user)
             query: query::tests::jsonpath_duration(),
             filters: vec![],
+            default: None,
             line_terminator0: LineTerminator {
                 space0: whitespace.clone(),
                 comment: None,
@@ -149,6 +149,7 @@ pub mod tests {
             space2: whitespace.clone(),
 
             query: query::tests::xpath_invalid_query(),
+            default: None,
             line_terminator0: LineTerminator {
                 space0: whitespace.clone(),
                 comment: None,
@@ -198,6 +199,7 @@ pub mod tests {
                 },
             },
             filters: vec![],
+            default: None,
             line_terminator0: LineTerminator {
                 space0: whitespace.clone(),
                 comment: None,
@@ -206,6 +208,64 @@ pub mod tests {
         };
     }
 
+    #[test]
+    fn test_capture_default() {
+        let variables = HashMap::new();
+        let whitespace = Whitespace {
+            value: String::new(),
+            source_info: SourceInfo::new(0, 0, 0, 0),
+        };
+        let mut capture = Capture {
+            line_terminators: vec![],
+            space0: whitespace.clone(),
+            name: EncodedString {
+                quotes: false,
+                value: "name".to_string(),
+                encoded: "name".to_string(),
+                source_info: SourceInfo::new(0, 0, 0, 0),
+            },
+            space1: whitespace.clone(),
+            space2: whitespace.clone(),
+
+            // regex "Hello ([a-zA-Z]+)!"
+            query: query::tests::regex_name(),
+            filters: vec![],
+            default: None,
+            line_terminator0: LineTerminator {
+                space0: whitespace.clone(),
+                comment: None,
+                newline: whitespace.clone(),
+            },
+        };
+
+        let error = eval_capture(&capture, &variables, &http::json_http_response())
+            .err()
+            .unwrap();
+        assert_eq!(error.inner, RunnerError::NoQueryResult);
+
+        capture.default = Some(CaptureDefault {
+            space0: whitespace.clone(),
+            space1: whitespace,
+            value: VariableValue::Null,
+        });
+        assert_eq!(
+            eval_capture(&capture, &variables, &http::json_http_response()).unwrap(),
+            CaptureResult {
+                name: "name".to_string(),
+                value: Value::Null,
+            }
+        );
+
+        // The default value is not used when the query returns a result.
+        assert_eq!(
+            eval_capture(&capture, &variables, &http::hello_http_response()).unwrap(),
+            CaptureResult {
+                name: "name".to_string(),
+                value: Value::String("World".to_string()),
+            }
+        );
+    }
+
     #[test]
     fn test_capture() {
         let variables = HashMap::new();
@@ -123,6 +123,7 @@ pub fn run(
 
     // We proceed asserts and captures in this order:
     // 1. first, check implicit assert on status and version. If KO, test is failed
+    //    (unless we're in soft asserts mode)
     // 2. then, we compute captures, we might need them in asserts
     // 3. finally, run the remaining asserts
     let mut all_asserts = vec![];
@@ -131,7 +132,7 @@ pub fn run(
         if let Some(response_spec) = &entry.response {
             let mut asserts = eval_version_status_asserts(response_spec, http_response);
             let errors = asserts_to_errors(&asserts);
-            if !errors.is_empty() {
+            if !errors.is_empty() && !runner_options.soft_asserts {
                 logger.debug("");
                 return EntryResult {
                     entry_index,
@@ -219,11 +220,14 @@ fn asserts_to_errors(asserts: &[AssertResult]) -> Vec<Error> {
 impl ClientOptions {
     fn from(runner_options: &RunnerOptions, verbosity: Option<Verbosity>) -> Self {
         ClientOptions {
+            auth: runner_options.auth,
+            aws_sigv4: runner_options.aws_sigv4.clone(),
             cacert_file: runner_options.cacert_file.clone(),
             client_cert_file: runner_options.client_cert_file.clone(),
             client_key_file: runner_options.client_key_file.clone(),
             connects_to: runner_options.connects_to.clone(),
             follow_location: runner_options.follow_location,
+            http_version: runner_options.http_version,
             max_redirect: runner_options.max_redirect,
             cookie_input_file: runner_options.cookie_input_file.clone(),
             path_as_is: runner_options.path_as_is,
@@ -240,6 +244,7 @@ impl ClientOptions {
             ssl_no_revoke: runner_options.ssl_no_revoke,
             timeout: runner_options.timeout,
             connect_timeout: runner_options.connect_timeout,
+            unix_socket: runner_options.unix_socket.clone(),
             user: runner_options.user.clone(),
             user_agent: runner_options.user_agent.clone(),
             compressed: runner_options.compressed,
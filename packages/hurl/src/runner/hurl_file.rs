@@ -25,7 +25,7 @@ use hurl_core::error::Error;
 use hurl_core::parser;
 
 use crate::http;
-use crate::http::Call;
+use crate::http::{AuthMethod, Call, RequestedHttpVersion};
 use crate::runner::core::*;
 use crate::runner::runner_options::RunnerOptions;
 use crate::runner::{entry, options, Value};
@@ -77,15 +77,14 @@ pub fn run(
 ) -> Result<HurlResult, String> {
     let logger = Logger::from(logger_options);
 
-    // Try to parse the content
-    let hurl_file = parser::parse_hurl_file(content);
-    let hurl_file = match hurl_file {
-        Ok(h) => h,
-        Err(e) => {
-            logger.error_rich(content, &e);
-            return Err(e.description());
+    // Try to parse the content, reporting all the parsing errors
+    let (hurl_file, errors) = parser::parse_hurl_file_with_recovery(content);
+    if let Some(first) = errors.first() {
+        for e in &errors {
+            logger.error_rich(content, e);
         }
-    };
+        return Err(first.description());
+    }
 
     log_run_info(&hurl_file, runner_options, variables, &logger);
 
@@ -153,6 +152,12 @@ pub fn run(
 
         // Check if we need to retry.
         let has_error = !entry_result.errors.is_empty();
+        // In soft asserts mode, failing asserts neither retry the entry nor stop the execution.
+        let has_blocking_error = if runner_options.soft_asserts {
+            entry_result.errors.iter().any(|e| !e.assert)
+        } else {
+            has_error
+        };
         let (retry_opts, retry_interval) = match &options {
             Ok(options) => (options.retry, options.retry_interval),
             Err(_) => (runner_options.retry, runner_options.retry_interval),
@@ -173,7 +178,7 @@ pub fn run(
             logger.debug("");
         }
 
-        let retry = !matches!(retry_opts, Retry::None) && !retry_max_reached && has_error;
+        let retry = !matches!(retry_opts, Retry::None) && !retry_max_reached && has_blocking_error;
         if has_error {
             log_errors(&entry_result, content, retry, &logger);
         }
@@ -201,7 +206,7 @@ pub fn run(
                 break;
             }
         }
-        if !runner_options.continue_on_error && has_error {
+        if !runner_options.continue_on_error && has_blocking_error {
             break;
         }
 
@@ -213,12 +218,16 @@ pub fn run(
     let time_in_ms = start.elapsed().as_millis();
     let cookies = http_client.get_cookie_storage();
     let success = is_success(&entries);
-    Ok(HurlResult {
+    let result = HurlResult {
         entries,
         time_in_ms,
         success,
         cookies,
-    })
+    };
+    if runner_options.soft_asserts {
+        log_soft_asserts(&result, &logger);
+    }
+    Ok(result)
 }
 
 /// Returns `true` if all the entries ar successful, `false` otherwise.
@@ -315,6 +324,12 @@ fn log_run_info(
     logger: &Logger,
 ) {
     logger.debug_important("Options:");
+    if runner_options.auth != AuthMethod::Basic {
+        logger.debug(format!("    auth: {}", runner_options.auth).as_str());
+    }
+    if let Some(aws_sigv4) = &runner_options.aws_sigv4 {
+        logger.debug(format!("    aws sigv4: {aws_sigv4}").as_str());
+    }
     logger.debug(
         format!(
             "    continue on error: {}",
@@ -323,6 +338,9 @@ fn log_run_info(
         .as_str(),
     );
     logger.debug(format!("    follow redirect: {}", runner_options.follow_location).as_str());
+    if runner_options.http_version != RequestedHttpVersion::Default {
+        logger.debug(format!("    http version: {}", runner_options.http_version).as_str());
+    }
     logger.debug(format!("    insecure: {}", runner_options.insecure).as_str());
     if let Some(n) = runner_options.max_redirect {
         logger.debug(format!("    max redirect: {n}").as_str());
@@ -331,6 +349,12 @@ fn log_run_info(
         logger.debug(format!("    proxy: {proxy}").as_str());
     }
     logger.debug(format!("    retry: {}", runner_options.retry).as_str());
+    if runner_options.soft_asserts {
+        logger.debug("    soft asserts: true");
+    }
+    if let Some(unix_socket) = &runner_options.unix_socket {
+        logger.debug(format!("    unix socket: {unix_socket}").as_str());
+    }
     if !variables.is_empty() {
         logger.debug_important("Variables:");
         for (name, value) in variables.iter() {
@@ -368,6 +392,29 @@ fn log_errors(entry_result: &EntryResult, content: &str, retry: bool, logger: &L
         .for_each(|e| logger.error_rich(content, e));
 }
 
+/// Logs a summary of all the failing asserts of `result`, run in soft asserts mode.
+fn log_soft_asserts(result: &HurlResult, logger: &Logger) {
+    let errors = result
+        .errors()
+        .into_iter()
+        .filter(|e| e.assert)
+        .collect::<Vec<_>>();
+    if errors.is_empty() {
+        return;
+    }
+    let count = errors.len();
+    let plural = if count > 1 { "s" } else { "" };
+    logger.error(format!("{count} assert{plural} failed").as_str());
+    for error in errors {
+        let filename = &logger.filename;
+        let line = error.source_info.start.line;
+        let column = error.source_info.start.column;
+        let description = error.description();
+        logger.info(format!("  {filename}:{line}:{column} {description}").as_str());
+    }
+    logger.info("");
+}
+
 /// Creates a new logger for this entry.
 /// Verbosity can be overridden at entry level with an Options section so each
 /// entry has its own logger.
@@ -16,6 +16,7 @@
  *
  */
 
+use crate::http::{AuthMethod, RequestedHttpVersion};
 use crate::runner::template;
 use crate::runner::{Error, RunnerOptions, Value};
 use crate::util::logger::{Logger, Verbosity};
@@ -46,6 +47,7 @@ pub fn get_entry_options(
         if let SectionValue::Options(options) = &section.value {
             for option in options.iter() {
                 match &option.kind {
+                    OptionKind::AwsSigV4(value) => runner_options.aws_sigv4 = Some(value.clone()),
                     OptionKind::CaCertificate(filename) => {
                         runner_options.cacert_file = Some(filename.value.clone())
                     }
@@ -57,9 +59,43 @@ pub fn get_entry_options(
                     }
                     OptionKind::Compressed(value) => runner_options.compressed = *value,
                     OptionKind::ConnectTo(value) => runner_options.connects_to.push(value.clone()),
+                    OptionKind::Digest(value) => {
+                        set_auth(&mut runner_options, AuthMethod::Digest, *value)
+                    }
                     OptionKind::Insecure(value) => runner_options.insecure = *value,
                     OptionKind::FollowLocation(value) => runner_options.follow_location = *value,
+                    OptionKind::Http10(value) => {
+                        if *value {
+                            runner_options.http_version = RequestedHttpVersion::Http10
+                        }
+                    }
+                    OptionKind::Http11(value) => {
+                        if *value {
+                            runner_options.http_version = RequestedHttpVersion::Http11
+                        }
+                    }
+                    OptionKind::Http2(value) => {
+                        if *value {
+                            runner_options.http_version = RequestedHttpVersion::Http2
+                        }
+                    }
+                    OptionKind::Http2PriorKnowledge(value) => {
+                        if *value {
+                            runner_options.http_version = RequestedHttpVersion::Http2PriorKnowledge
+                        }
+                    }
+                    OptionKind::Http3(value) => {
+                        if *value {
+                            runner_options.http_version = RequestedHttpVersion::Http3
+                        }
+                    }
                     OptionKind::MaxRedirect(value) => runner_options.max_redirect = Some(*value),
+                    OptionKind::Negotiate(value) => {
+                        set_auth(&mut runner_options, AuthMethod::Negotiate, *value)
+                    }
+                    OptionKind::Ntlm(value) => {
+                        set_auth(&mut runner_options, AuthMethod::Ntlm, *value)
+                    }
                     OptionKind::PathAsIs(value) => runner_options.path_as_is = *value,
                     OptionKind::Proxy(value) => runner_options.proxy = Some(value.clone()),
                     OptionKind::Resolve(value) => runner_options.resolves.push(value.clone()),
@@ -67,6 +103,9 @@ pub fn get_entry_options(
                     OptionKind::RetryInterval(value) => {
                         runner_options.retry_interval = Duration::from_millis(*value)
                     }
+                    OptionKind::UnixSocket(filename) => {
+                        runner_options.unix_socket = Some(filename.value.clone())
+                    }
                     OptionKind::Variable(VariableDefinition { name, value, .. }) => {
                         let value = eval_variable_value(value, variables)?;
                         variables.insert(name.clone(), value);
@@ -83,6 +122,18 @@ pub fn get_entry_options(
     Ok(runner_options)
 }
 
+/// Enables (or disables) the authentication method `auth` for these `runner_options`.
+///
+/// Disabling a method that is not the current one has no effect, otherwise we fall back
+/// to basic authentication.
+fn set_auth(runner_options: &mut RunnerOptions, auth: AuthMethod, enabled: bool) {
+    if enabled {
+        runner_options.auth = auth;
+    } else if runner_options.auth == auth {
+        runner_options.auth = AuthMethod::Basic;
+    }
+}
+
 /// Logs an entry option.
 fn log_option(option: &EntryOption, logger: &Logger) {
     let name = option.kind.name();
@@ -129,9 +180,11 @@ pub fn get_entry_verbosity(entry: &Entry, verbosity: &Option<Verbosity>) -> Opti
     verbosity
 }
 
-fn eval_variable_value(
+/// Evaluates a `variable_value` with the `variables` map, returns a [`Value`] on success
+/// or an [`Error`].
+pub fn eval_variable_value(
     variable_value: &VariableValue,
-    variables: &mut HashMap<String, Value>,
+    variables: &HashMap<String, Value>,
 ) -> Result<Value, Error> {
     match variable_value {
         VariableValue::Null => Ok(Value::Null),
@@ -19,9 +19,12 @@ use std::time::Duration;
 
 use hurl_core::ast::{Entry, Retry};
 
+use crate::http::{AuthMethod, RequestedHttpVersion};
 use crate::util::path::ContextDir;
 
 pub struct RunnerOptionsBuilder {
+    auth: AuthMethod,
+    aws_sigv4: Option<String>,
     cacert_file: Option<String>,
     client_cert_file: Option<String>,
     client_key_file: Option<String>,
@@ -32,6 +35,7 @@ pub struct RunnerOptionsBuilder {
     continue_on_error: bool,
     cookie_input_file: Option<String>,
     follow_location: bool,
+    http_version: RequestedHttpVersion,
     ignore_asserts: bool,
     insecure: bool,
     max_redirect: Option<usize>,
@@ -43,9 +47,11 @@ pub struct RunnerOptionsBuilder {
     resolves: Vec<String>,
     retry: Retry,
     retry_interval: Duration,
+    soft_asserts: bool,
     ssl_no_revoke: bool,
     timeout: Duration,
     to_entry: Option<usize>,
+    unix_socket: Option<String>,
     user: Option<String>,
     user_agent: Option<String>,
 }
@@ -53,6 +59,8 @@ pub struct RunnerOptionsBuilder {
 impl Default for RunnerOptionsBuilder {
     fn default() -> Self {
         RunnerOptionsBuilder {
+            auth: AuthMethod::Basic,
+            aws_sigv4: None,
             cacert_file: None,
             client_cert_file: None,
             client_key_file: None,
@@ -63,6 +71,7 @@ impl Default for RunnerOptionsBuilder {
             continue_on_error: false,
             cookie_input_file: None,
             follow_location: false,
+            http_version: RequestedHttpVersion::Default,
             ignore_asserts: false,
             insecure: false,
             max_redirect: Some(50),
@@ -74,9 +83,11 @@ impl Default for RunnerOptionsBuilder {
             resolves: vec![],
             retry: Retry::None,
             retry_interval: Duration::from_millis(1000),
+            soft_asserts: false,
             ssl_no_revoke: false,
             timeout: Duration::from_secs(300),
             to_entry: None,
+            unix_socket: None,
             user: None,
             user_agent: None,
         }
@@ -89,6 +100,23 @@ impl RunnerOptionsBuilder {
         RunnerOptionsBuilder::default()
     }
 
+    /// Sets the HTTP authentication method used with the [`user`](Self::user) credentials.
+    ///
+    /// By default, Hurl uses basic authentication.
+    pub fn auth(&mut self, auth: AuthMethod) -> &mut Self {
+        self.auth = auth;
+        self
+    }
+
+    /// Uses AWS V4 signature authentication in the transfer.
+    ///
+    /// The `aws_sigv4` parameter has the form `provider1[:provider2[:region[:service]]]`,
+    /// credentials are given with [`user`](Self::user).
+    pub fn aws_sigv4(&mut self, aws_sigv4: Option<String>) -> &mut Self {
+        self.aws_sigv4 = aws_sigv4;
+        self
+    }
+
     /// Specifies the certificate file for peer verification.
     /// The file may contain multiple CA certificates and must be in PEM format.
     pub fn cacert_file(&mut self, cacert_file: Option<String>) -> &mut Self {
@@ -172,6 +200,14 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Sets the HTTP version to use (HTTP/1.0, HTTP/1.1, HTTP/2, HTTP/3).
+    ///
+    /// By default, libcurl chooses the best HTTP version.
+    pub fn http_version(&mut self, version: RequestedHttpVersion) -> &mut Self {
+        self.http_version = version;
+        self
+    }
+
     /// Ignores all asserts defined in the Hurl file.
     pub fn ignore_asserts(&mut self, ignore_asserts: bool) -> &mut Self {
         self.ignore_asserts = ignore_asserts;
@@ -248,6 +284,16 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Sets soft asserts mode.
+    ///
+    /// In this mode, a failing assert never stops the execution of the Hurl file: captures and
+    /// asserts of the entry are still evaluated and the next entries are run. Runtime errors
+    /// (HTTP errors, failing captures etc...) still stop the execution.
+    pub fn soft_asserts(&mut self, soft_asserts: bool) -> &mut Self {
+        self.soft_asserts = soft_asserts;
+        self
+    }
+
     pub fn ssl_no_revoke(&mut self, ssl_no_revoke: bool) -> &mut Self {
         self.ssl_no_revoke = ssl_no_revoke;
         self
@@ -267,6 +313,12 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Connects through this Unix domain socket, instead of using the network.
+    pub fn unix_socket(&mut self, unix_socket: Option<String>) -> &mut Self {
+        self.unix_socket = unix_socket;
+        self
+    }
+
     /// Adds basic Authentication header to each request.
     pub fn user(&mut self, user: Option<String>) -> &mut Self {
         self.user = user;
@@ -282,6 +334,8 @@ impl RunnerOptionsBuilder {
     /// Create an instance of [`RunnerOptions`].
     pub fn build(&self) -> RunnerOptions {
         RunnerOptions {
+            auth: self.auth,
+            aws_sigv4: self.aws_sigv4.clone(),
             cacert_file: self.cacert_file.clone(),
             client_cert_file: self.client_cert_file.clone(),
             client_key_file: self.client_key_file.clone(),
@@ -292,6 +346,7 @@ impl RunnerOptionsBuilder {
             continue_on_error: self.continue_on_error,
             cookie_input_file: self.cookie_input_file.clone(),
             follow_location: self.follow_location,
+            http_version: self.http_version,
             ignore_asserts: self.ignore_asserts,
             insecure: self.insecure,
             max_redirect: self.max_redirect,
@@ -303,9 +358,11 @@ impl RunnerOptionsBuilder {
             resolves: self.resolves.clone(),
             retry: self.retry,
             retry_interval: self.retry_interval,
+            soft_asserts: self.soft_asserts,
             ssl_no_revoke: self.ssl_no_revoke,
             timeout: self.timeout,
             to_entry: self.to_entry,
+            unix_socket: self.unix_socket.clone(),
             user: self.user.clone(),
             user_agent: self.user_agent.clone(),
         }
@@ -314,6 +371,8 @@ impl RunnerOptionsBuilder {
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct RunnerOptions {
+    pub(crate) auth: AuthMethod,
+    pub(crate) aws_sigv4: Option<String>,
     pub(crate) cacert_file: Option<String>,
     pub(crate) client_cert_file: Option<String>,
     pub(crate) client_key_file: Option<String>,
@@ -324,6 +383,7 @@ pub struct RunnerOptions {
     pub(crate) continue_on_error: bool,
     pub(crate) cookie_input_file: Option<String>,
     pub(crate) follow_location: bool,
+    pub(crate) http_version: RequestedHttpVersion,
     pub(crate) ignore_asserts: bool,
     pub(crate) insecure: bool,
     pub(crate) max_redirect: Option<usize>,
@@ -335,9 +395,11 @@ pub struct RunnerOptions {
     pub(crate) resolves: Vec<String>,
     pub(crate) retry: Retry,
     pub(crate) retry_interval: Duration,
+    pub(crate) soft_asserts: bool,
     pub(crate) ssl_no_revoke: bool,
     pub(crate) timeout: Duration,
     pub(crate) to_entry: Option<usize>,
+    pub(crate) unix_socket: Option<String>,
     pub(crate) user: Option<String>,
     pub(crate) user_agent: Option<String>,
 }
@@ -21,18 +21,21 @@ use crate::ast::json;
 /// Hurl AST
 ///
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HurlFile {
     pub entries: Vec<Entry>,
     pub line_terminators: Vec<LineTerminator>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Entry {
     pub request: Request,
     pub response: Option<Response>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Request {
     pub line_terminators: Vec<LineTerminator>,
     pub space0: Whitespace,
@@ -101,6 +104,7 @@ impl Request {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Response {
     pub line_terminators: Vec<LineTerminator>,
     pub version: Version,
@@ -137,15 +141,18 @@ impl Response {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Method(pub String);
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Version {
     pub value: VersionValue,
     pub source_info: SourceInfo,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum VersionValue {
     Version1,
     Version11,
@@ -155,12 +162,14 @@ pub enum VersionValue {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Status {
     pub value: StatusValue,
     pub source_info: SourceInfo,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum StatusValue {
     Any,
     Specific(u64),
@@ -169,6 +178,7 @@ pub enum StatusValue {
 pub type Header = KeyValue;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Body {
     pub line_terminators: Vec<LineTerminator>,
     pub space0: Whitespace,
@@ -181,6 +191,7 @@ pub struct Body {
 //
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Section {
     pub line_terminators: Vec<LineTerminator>,
     pub space0: Whitespace,
@@ -205,6 +216,7 @@ impl Section {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(clippy::large_enum_variant)]
 pub enum SectionValue {
     QueryParams(Vec<KeyValue>),
@@ -218,6 +230,7 @@ pub enum SectionValue {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Cookie {
     pub line_terminators: Vec<LineTerminator>,
     pub space0: Whitespace,
@@ -229,6 +242,7 @@ pub struct Cookie {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct KeyValue {
     pub line_terminators: Vec<LineTerminator>,
     pub space0: Whitespace,
@@ -240,12 +254,14 @@ pub struct KeyValue {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MultipartParam {
     Param(KeyValue),
     FileParam(FileParam),
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FileParam {
     pub line_terminators: Vec<LineTerminator>,
     pub space0: Whitespace,
@@ -257,6 +273,7 @@ pub struct FileParam {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FileValue {
     pub space0: Whitespace,
     pub filename: Filename,
@@ -266,6 +283,7 @@ pub struct FileValue {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Capture {
     pub line_terminators: Vec<LineTerminator>,
     pub space0: Whitespace,
@@ -274,10 +292,21 @@ pub struct Capture {
     pub space2: Whitespace,
     pub query: Query,
     pub filters: Vec<(Whitespace, Filter)>,
+    pub default: Option<CaptureDefault>,
     pub line_terminator0: LineTerminator,
 }
 
+/// Value used by a capture when its query or filters return no result.
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CaptureDefault {
+    pub space0: Whitespace,
+    pub space1: Whitespace,
+    pub value: VariableValue,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Assert {
     pub line_terminators: Vec<LineTerminator>,
     pub space0: Whitespace,
@@ -289,12 +318,14 @@ pub struct Assert {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Query {
     pub source_info: SourceInfo,
     pub value: QueryValue,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(clippy::large_enum_variant)]
 pub enum QueryValue {
     Status,
@@ -335,18 +366,21 @@ pub enum QueryValue {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RegexValue {
     Template(Template),
     Regex(Regex),
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CookiePath {
     pub name: Template,
     pub attribute: Option<CookieAttribute>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CookieAttribute {
     pub space0: Whitespace,
     pub name: CookieAttributeName,
@@ -354,6 +388,7 @@ pub struct CookieAttribute {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CookieAttributeName {
     Value(String),
     Expires(String),
@@ -381,6 +416,7 @@ impl CookieAttributeName {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CertificateAttributeName {
     Subject,
     Issuer,
@@ -390,6 +426,7 @@ pub enum CertificateAttributeName {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Predicate {
     pub not: bool,
     pub space0: Whitespace,
@@ -397,18 +434,21 @@ pub struct Predicate {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Not {
     pub value: bool,
     pub space0: Whitespace,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PredicateFunc {
     pub source_info: SourceInfo,
     pub value: PredicateFuncValue,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(clippy::large_enum_variant)]
 pub enum PredicateValue {
     String(Template),
@@ -424,6 +464,7 @@ pub enum PredicateValue {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(clippy::large_enum_variant)]
 pub enum PredicateFuncValue {
     Equal {
@@ -490,6 +531,7 @@ pub enum PredicateFuncValue {
 // Primitives
 //
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MultilineString {
     // FIXME: temporary type until we implement oneline as `foo` instead of ```foo```
     OneLineText(Template),
@@ -521,6 +563,7 @@ impl MultilineString {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Text {
     pub space: Whitespace,
     pub newline: Whitespace,
@@ -528,6 +571,7 @@ pub struct Text {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GraphQl {
     pub space: Whitespace,
     pub newline: Whitespace,
@@ -536,6 +580,7 @@ pub struct GraphQl {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GraphQlVariables {
     pub space: Whitespace,
     pub value: json::Value,
@@ -543,6 +588,7 @@ pub struct GraphQlVariables {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Base64 {
     pub space0: Whitespace,
     pub value: Vec<u8>,
@@ -551,6 +597,7 @@ pub struct Base64 {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct File {
     pub space0: Whitespace,
     pub filename: Filename,
@@ -558,6 +605,7 @@ pub struct File {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Template {
     pub delimiter: Option<char>,
     pub elements: Vec<TemplateElement>,
@@ -565,6 +613,7 @@ pub struct Template {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TemplateElement {
     // TODO: explain the difference between value and encoded
     String { value: String, encoded: String },
@@ -572,11 +621,13 @@ pub enum TemplateElement {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Comment {
     pub value: String,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EncodedString {
     pub value: String,
     pub encoded: String,
@@ -585,18 +636,21 @@ pub struct EncodedString {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Whitespace {
     pub value: String,
     pub source_info: SourceInfo,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Filename {
     pub value: String,
     pub source_info: SourceInfo,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Number {
     pub int: i64,
     pub decimal: u64,
@@ -607,6 +661,7 @@ pub struct Number {
 // 1.01 and 1.010
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Float {
     pub value: f64,
     pub encoded: String, // as defined in Hurl
@@ -620,6 +675,7 @@ impl PartialEq for Float {
 impl Eq for Float {}
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LineTerminator {
     pub space0: Whitespace,
     pub comment: Option<Comment>,
@@ -627,6 +683,7 @@ pub struct LineTerminator {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Bytes {
     Json(json::Value),
     Xml(String),
@@ -638,6 +695,7 @@ pub enum Bytes {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Hex {
     pub space0: Whitespace,
     pub value: Vec<u8>,
@@ -658,13 +716,33 @@ impl PartialEq for Regex {
 }
 impl Eq for Regex {}
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for Regex {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.inner.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Regex {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        match regex::Regex::new(&s) {
+            Ok(inner) => Ok(Regex { inner }),
+            Err(e) => Err(serde::de::Error::custom(e)),
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Pos {
     pub line: usize,
     pub column: usize,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SourceInfo {
     pub start: Pos,
     pub end: Pos,
@@ -691,6 +769,7 @@ impl SourceInfo {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Expr {
     pub space0: Whitespace,
     pub variable: Variable,
@@ -698,12 +777,14 @@ pub struct Expr {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Variable {
     pub name: String,
     pub source_info: SourceInfo,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EntryOption {
     pub line_terminators: Vec<LineTerminator>,
     pub space0: Whitespace,
@@ -714,20 +795,31 @@ pub struct EntryOption {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum OptionKind {
+    AwsSigV4(String),
     CaCertificate(Filename),
     ClientCert(Filename),
     ClientKey(Filename),
     ConnectTo(String),
     Compressed(bool),
+    Digest(bool),
     Insecure(bool),
     FollowLocation(bool),
+    Http10(bool),
+    Http11(bool),
+    Http2(bool),
+    Http2PriorKnowledge(bool),
+    Http3(bool),
     MaxRedirect(usize),
+    Negotiate(bool),
+    Ntlm(bool),
     PathAsIs(bool),
     Proxy(String),
     Resolve(String),
     Retry(Retry),
     RetryInterval(u64),
+    UnixSocket(Filename),
     Variable(VariableDefinition),
     Verbose(bool),
     VeryVerbose(bool),
@@ -736,19 +828,29 @@ pub enum OptionKind {
 impl OptionKind {
     pub fn name(&self) -> &'static str {
         match self {
+            OptionKind::AwsSigV4(_) => "aws-sigv4",
             OptionKind::CaCertificate(_) => "cacert",
             OptionKind::ClientCert(_) => "cert",
             OptionKind::ClientKey(_) => "key",
             OptionKind::Compressed(_) => "compressed",
             OptionKind::ConnectTo(_) => "connect-to",
+            OptionKind::Digest(_) => "digest",
             OptionKind::Insecure(_) => "insecure",
             OptionKind::FollowLocation(_) => "location",
+            OptionKind::Http10(_) => "http1.0",
+            OptionKind::Http11(_) => "http1.1",
+            OptionKind::Http2(_) => "http2",
+            OptionKind::Http2PriorKnowledge(_) => "http2-prior-knowledge",
+            OptionKind::Http3(_) => "http3",
             OptionKind::MaxRedirect(_) => "max-redirs",
+            OptionKind::Negotiate(_) => "negotiate",
+            OptionKind::Ntlm(_) => "ntlm",
             OptionKind::PathAsIs(_) => "path-as-is",
             OptionKind::Proxy(_) => "proxy",
             OptionKind::Resolve(_) => "resolve",
             OptionKind::Retry(_) => "retry",
             OptionKind::RetryInterval(_) => "retry-interval",
+            OptionKind::UnixSocket(_) => "unix-socket",
             OptionKind::Variable(_) => "variable",
             OptionKind::Verbose(_) => "verbose",
             OptionKind::VeryVerbose(_) => "very-verbose",
@@ -757,19 +859,29 @@ impl OptionKind {
 
     pub fn value_as_str(&self) -> String {
         match self {
+            OptionKind::AwsSigV4(value) => value.clone(),
             OptionKind::CaCertificate(filename) => filename.value.clone(),
             OptionKind::ClientCert(filename) => filename.value.clone(),
             OptionKind::ClientKey(filename) => filename.value.clone(),
             OptionKind::Compressed(value) => value.to_string(),
             OptionKind::ConnectTo(value) => value.clone(),
+            OptionKind::Digest(value) => value.to_string(),
             OptionKind::Insecure(value) => value.to_string(),
             OptionKind::FollowLocation(value) => value.to_string(),
+            OptionKind::Http10(value) => value.to_string(),
+            OptionKind::Http11(value) => value.to_string(),
+            OptionKind::Http2(value) => value.to_string(),
+            OptionKind::Http2PriorKnowledge(value) => value.to_string(),
+            OptionKind::Http3(value) => value.to_string(),
             OptionKind::MaxRedirect(value) => value.to_string(),
+            OptionKind::Negotiate(value) => value.to_string(),
+            OptionKind::Ntlm(value) => value.to_string(),
             OptionKind::PathAsIs(value) => value.to_string(),
             OptionKind::Proxy(value) => value.clone(),
             OptionKind::Resolve(value) => value.clone(),
             OptionKind::Retry(value) => value.to_string(),
             OptionKind::RetryInterval(value) => value.to_string(),
+            OptionKind::UnixSocket(filename) => filename.value.clone(),
             OptionKind::Variable(VariableDefinition { name, value, .. }) => {
                 format!("{name}={value}")
             }
@@ -780,6 +892,7 @@ impl OptionKind {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct VariableDefinition {
     pub name: String,
     pub space0: Whitespace,
@@ -788,6 +901,7 @@ pub struct VariableDefinition {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum VariableValue {
     Null,
     Bool(bool),
@@ -797,12 +911,14 @@ pub enum VariableValue {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Filter {
     pub source_info: SourceInfo,
     pub value: FilterValue,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FilterValue {
     Count,
     DaysAfterNow,
@@ -849,6 +965,7 @@ pub enum FilterValue {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Retry {
     None,
     Finite(usize),
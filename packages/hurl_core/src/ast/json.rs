@@ -27,6 +27,7 @@ use crate::ast::{Expr, TemplateElement};
 /// Strings have been replaced by Hurl template.
 ///
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Value {
     Expression(Expr),
     Number(String),
@@ -58,6 +59,7 @@ impl Value {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ListElement {
     pub space0: String,
     pub value: Value,
@@ -65,6 +67,7 @@ pub struct ListElement {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ObjectElement {
     pub space0: String,
     pub name: Template,
@@ -20,7 +20,50 @@ pub use self::core::*;
 pub use self::json::{
     ListElement as JsonListElement, ObjectElement as JsonObjectElement, Value as JsonValue,
 };
+pub use self::visit::Visitor;
+pub use self::visit_mut::VisitorMut;
+
+pub mod visit;
+pub mod visit_mut;
 
 mod core;
 mod display;
 mod json;
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use crate::parser;
+
+    use super::*;
+
+    #[test]
+    fn test_serde_round_trip() {
+        let content = r#"# Create a user
+POST http://localhost:8000/users
+Content-Type: application/json
+[Options]
+retry: 3
+variable: id=1
+{"id": {{id}}, "name": "Bob", "tags": [true, null, 1.5]}
+HTTP 201
+[Captures]
+location: header "Location" regex /\/users\/(\d+)/
+[Asserts]
+jsonpath "$.name" == "Bob"
+certificate "Subject" contains "CN=localhost"
+"#;
+        let hurl_file = parser::parse_hurl_file(content).unwrap();
+        let json = serde_json::to_string(&hurl_file).unwrap();
+        let deserialized: HurlFile = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, hurl_file);
+    }
+
+    #[test]
+    fn test_serde_regex() {
+        let regex = Regex {
+            inner: regex::Regex::new(r"\d+").unwrap(),
+        };
+        assert_eq!(serde_json::to_string(&regex).unwrap(), r#""\\d+""#);
+        assert!(serde_json::from_str::<Regex>(r#""(""#).is_err());
+    }
+}
@@ -0,0 +1,830 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2023 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+//! Read-only traversal of the Hurl AST.
+//!
+//! Each `visit_*` method of [`Visitor`] defaults to the corresponding `walk_*` function of this
+//! module, which visits the children of the node in source order. Implementors override the
+//! methods of the nodes they're interested in, and call the `walk_*` function to keep on
+//! descending into the node children.
+//!
+//! ```
+//! use hurl_core::ast::visit::Visitor;
+//! use hurl_core::ast::Variable;
+//! use hurl_core::parser;
+//!
+//! struct Variables(Vec<String>);
+//!
+//! impl Visitor for Variables {
+//!     fn visit_variable(&mut self, variable: &Variable) {
+//!         self.0.push(variable.name.clone());
+//!     }
+//! }
+//!
+//! let hurl_file = parser::parse_hurl_file("GET {{host}}/users/{{id}}\n").unwrap();
+//! let mut variables = Variables(vec![]);
+//! variables.visit_hurl_file(&hurl_file);
+//! assert_eq!(variables.0, vec!["host", "id"]);
+//! ```
+//!
+//! Only the AST nodes are visited, not the concrete syntax between them (section names, keywords,
+//! separators, JSON punctuation). Formatters that write the source back, like
+//! [`crate::format`] or the hurlfmt formatter, keep their own traversal.
+use crate::ast::*;
+
+pub trait Visitor: Sized {
+    fn visit_hurl_file(&mut self, hurl_file: &HurlFile) {
+        walk_hurl_file(self, hurl_file);
+    }
+
+    fn visit_entry(&mut self, entry: &Entry) {
+        walk_entry(self, entry);
+    }
+
+    fn visit_request(&mut self, request: &Request) {
+        walk_request(self, request);
+    }
+
+    fn visit_response(&mut self, response: &Response) {
+        walk_response(self, response);
+    }
+
+    fn visit_method(&mut self, _method: &Method) {}
+
+    fn visit_version(&mut self, _version: &Version) {}
+
+    fn visit_status(&mut self, _status: &Status) {}
+
+    fn visit_key_value(&mut self, key_value: &KeyValue) {
+        walk_key_value(self, key_value);
+    }
+
+    fn visit_section(&mut self, section: &Section) {
+        walk_section(self, section);
+    }
+
+    fn visit_section_value(&mut self, section_value: &SectionValue) {
+        walk_section_value(self, section_value);
+    }
+
+    fn visit_cookie(&mut self, cookie: &Cookie) {
+        walk_cookie(self, cookie);
+    }
+
+    fn visit_multipart_param(&mut self, multipart_param: &MultipartParam) {
+        walk_multipart_param(self, multipart_param);
+    }
+
+    fn visit_file_param(&mut self, file_param: &FileParam) {
+        walk_file_param(self, file_param);
+    }
+
+    fn visit_file_value(&mut self, file_value: &FileValue) {
+        walk_file_value(self, file_value);
+    }
+
+    fn visit_capture(&mut self, capture: &Capture) {
+        walk_capture(self, capture);
+    }
+
+    fn visit_capture_default(&mut self, default: &CaptureDefault) {
+        walk_capture_default(self, default);
+    }
+
+    fn visit_assert(&mut self, assert: &Assert) {
+        walk_assert(self, assert);
+    }
+
+    fn visit_query(&mut self, query: &Query) {
+        walk_query(self, query);
+    }
+
+    fn visit_query_value(&mut self, query_value: &QueryValue) {
+        walk_query_value(self, query_value);
+    }
+
+    fn visit_regex_value(&mut self, regex_value: &RegexValue) {
+        walk_regex_value(self, regex_value);
+    }
+
+    fn visit_cookie_path(&mut self, cookie_path: &CookiePath) {
+        walk_cookie_path(self, cookie_path);
+    }
+
+    fn visit_cookie_attribute(&mut self, cookie_attribute: &CookieAttribute) {
+        walk_cookie_attribute(self, cookie_attribute);
+    }
+
+    fn visit_predicate(&mut self, predicate: &Predicate) {
+        walk_predicate(self, predicate);
+    }
+
+    fn visit_predicate_func(&mut self, predicate_func: &PredicateFunc) {
+        walk_predicate_func(self, predicate_func);
+    }
+
+    fn visit_predicate_func_value(&mut self, predicate_func_value: &PredicateFuncValue) {
+        walk_predicate_func_value(self, predicate_func_value);
+    }
+
+    fn visit_predicate_value(&mut self, predicate_value: &PredicateValue) {
+        walk_predicate_value(self, predicate_value);
+    }
+
+    fn visit_body(&mut self, body: &Body) {
+        walk_body(self, body);
+    }
+
+    fn visit_bytes(&mut self, bytes: &Bytes) {
+        walk_bytes(self, bytes);
+    }
+
+    fn visit_multiline_string(&mut self, multiline_string: &MultilineString) {
+        walk_multiline_string(self, multiline_string);
+    }
+
+    fn visit_text(&mut self, text: &Text) {
+        walk_text(self, text);
+    }
+
+    fn visit_graphql(&mut self, graphql: &GraphQl) {
+        walk_graphql(self, graphql);
+    }
+
+    fn visit_graphql_variables(&mut self, variables: &GraphQlVariables) {
+        walk_graphql_variables(self, variables);
+    }
+
+    fn visit_base64(&mut self, base64: &Base64) {
+        walk_base64(self, base64);
+    }
+
+    fn visit_hex(&mut self, hex: &Hex) {
+        walk_hex(self, hex);
+    }
+
+    fn visit_file(&mut self, file: &File) {
+        walk_file(self, file);
+    }
+
+    fn visit_json_value(&mut self, value: &JsonValue) {
+        walk_json_value(self, value);
+    }
+
+    fn visit_json_list_element(&mut self, element: &JsonListElement) {
+        walk_json_list_element(self, element);
+    }
+
+    fn visit_json_object_element(&mut self, element: &JsonObjectElement) {
+        walk_json_object_element(self, element);
+    }
+
+    fn visit_entry_option(&mut self, entry_option: &EntryOption) {
+        walk_entry_option(self, entry_option);
+    }
+
+    fn visit_option_kind(&mut self, option_kind: &OptionKind) {
+        walk_option_kind(self, option_kind);
+    }
+
+    fn visit_variable_definition(&mut self, variable_definition: &VariableDefinition) {
+        walk_variable_definition(self, variable_definition);
+    }
+
+    fn visit_variable_value(&mut self, variable_value: &VariableValue) {
+        walk_variable_value(self, variable_value);
+    }
+
+    fn visit_filter(&mut self, filter: &Filter) {
+        walk_filter(self, filter);
+    }
+
+    fn visit_filter_value(&mut self, filter_value: &FilterValue) {
+        walk_filter_value(self, filter_value);
+    }
+
+    fn visit_template(&mut self, template: &Template) {
+        walk_template(self, template);
+    }
+
+    fn visit_template_element(&mut self, element: &TemplateElement) {
+        walk_template_element(self, element);
+    }
+
+    fn visit_expr(&mut self, expr: &Expr) {
+        walk_expr(self, expr);
+    }
+
+    fn visit_variable(&mut self, _variable: &Variable) {}
+
+    fn visit_regex(&mut self, _regex: &Regex) {}
+
+    fn visit_encoded_string(&mut self, _encoded_string: &EncodedString) {}
+
+    fn visit_filename(&mut self, _filename: &Filename) {}
+
+    fn visit_line_terminator(&mut self, line_terminator: &LineTerminator) {
+        walk_line_terminator(self, line_terminator);
+    }
+
+    fn visit_comment(&mut self, _comment: &Comment) {}
+
+    fn visit_whitespace(&mut self, _whitespace: &Whitespace) {}
+}
+
+pub fn walk_hurl_file<V: Visitor>(visitor: &mut V, hurl_file: &HurlFile) {
+    for entry in &hurl_file.entries {
+        visitor.visit_entry(entry);
+    }
+    for line_terminator in &hurl_file.line_terminators {
+        visitor.visit_line_terminator(line_terminator);
+    }
+}
+
+pub fn walk_entry<V: Visitor>(visitor: &mut V, entry: &Entry) {
+    visitor.visit_request(&entry.request);
+    if let Some(response) = &entry.response {
+        visitor.visit_response(response);
+    }
+}
+
+pub fn walk_request<V: Visitor>(visitor: &mut V, request: &Request) {
+    for line_terminator in &request.line_terminators {
+        visitor.visit_line_terminator(line_terminator);
+    }
+    visitor.visit_whitespace(&request.space0);
+    visitor.visit_method(&request.method);
+    visitor.visit_whitespace(&request.space1);
+    visitor.visit_template(&request.url);
+    visitor.visit_line_terminator(&request.line_terminator0);
+    for header in &request.headers {
+        visitor.visit_key_value(header);
+    }
+    for section in &request.sections {
+        visitor.visit_section(section);
+    }
+    if let Some(body) = &request.body {
+        visitor.visit_body(body);
+    }
+}
+
+pub fn walk_response<V: Visitor>(visitor: &mut V, response: &Response) {
+    for line_terminator in &response.line_terminators {
+        visitor.visit_line_terminator(line_terminator);
+    }
+    visitor.visit_version(&response.version);
+    visitor.visit_whitespace(&response.space0);
+    visitor.visit_status(&response.status);
+    visitor.visit_whitespace(&response.space1);
+    visitor.visit_line_terminator(&response.line_terminator0);
+    for header in &response.headers {
+        visitor.visit_key_value(header);
+    }
+    for section in &response.sections {
+        visitor.visit_section(section);
+    }
+    if let Some(body) = &response.body {
+        visitor.visit_body(body);
+    }
+}
+
+pub fn walk_key_value<V: Visitor>(visitor: &mut V, key_value: &KeyValue) {
+    for line_terminator in &key_value.line_terminators {
+        visitor.visit_line_terminator(line_terminator);
+    }
+    visitor.visit_whitespace(&key_value.space0);
+    visitor.visit_encoded_string(&key_value.key);
+    visitor.visit_whitespace(&key_value.space1);
+    visitor.visit_whitespace(&key_value.space2);
+    visitor.visit_template(&key_value.value);
+    visitor.visit_line_terminator(&key_value.line_terminator0);
+}
+
+pub fn walk_section<V: Visitor>(visitor: &mut V, section: &Section) {
+    for line_terminator in &section.line_terminators {
+        visitor.visit_line_terminator(line_terminator);
+    }
+    visitor.visit_whitespace(&section.space0);
+    visitor.visit_line_terminator(&section.line_terminator0);
+    visitor.visit_section_value(&section.value);
+}
+
+pub fn walk_section_value<V: Visitor>(visitor: &mut V, section_value: &SectionValue) {
+    match section_value {
+        SectionValue::QueryParams(params) | SectionValue::FormParams(params) => {
+            for param in params {
+                visitor.visit_key_value(param);
+            }
+        }
+        SectionValue::BasicAuth(param) => {
+            if let Some(param) = param {
+                visitor.visit_key_value(param);
+            }
+        }
+        SectionValue::MultipartFormData(params) => {
+            for param in params {
+                visitor.visit_multipart_param(param);
+            }
+        }
+        SectionValue::Cookies(cookies) => {
+            for cookie in cookies {
+                visitor.visit_cookie(cookie);
+            }
+        }
+        SectionValue::Captures(captures) => {
+            for capture in captures {
+                visitor.visit_capture(capture);
+            }
+        }
+        SectionValue::Asserts(asserts) => {
+            for assert in asserts {
+                visitor.visit_assert(assert);
+            }
+        }
+        SectionValue::Options(options) => {
+            for option in options {
+                visitor.visit_entry_option(option);
+            }
+        }
+    }
+}
+
+pub fn walk_cookie<V: Visitor>(visitor: &mut V, cookie: &Cookie) {
+    for line_terminator in &cookie.line_terminators {
+        visitor.visit_line_terminator(line_terminator);
+    }
+    visitor.visit_whitespace(&cookie.space0);
+    visitor.visit_encoded_string(&cookie.name);
+    visitor.visit_whitespace(&cookie.space1);
+    visitor.visit_whitespace(&cookie.space2);
+    visitor.visit_template(&cookie.value);
+    visitor.visit_line_terminator(&cookie.line_terminator0);
+}
+
+pub fn walk_multipart_param<V: Visitor>(visitor: &mut V, multipart_param: &MultipartParam) {
+    match multipart_param {
+        MultipartParam::Param(param) => visitor.visit_key_value(param),
+        MultipartParam::FileParam(param) => visitor.visit_file_param(param),
+    }
+}
+
+pub fn walk_file_param<V: Visitor>(visitor: &mut V, file_param: &FileParam) {
+    for line_terminator in &file_param.line_terminators {
+        visitor.visit_line_terminator(line_terminator);
+    }
+    visitor.visit_whitespace(&file_param.space0);
+    visitor.visit_encoded_string(&file_param.key);
+    visitor.visit_whitespace(&file_param.space1);
+    visitor.visit_whitespace(&file_param.space2);
+    visitor.visit_file_value(&file_param.value);
+    visitor.visit_line_terminator(&file_param.line_terminator0);
+}
+
+pub fn walk_file_value<V: Visitor>(visitor: &mut V, file_value: &FileValue) {
+    visitor.visit_whitespace(&file_value.space0);
+    visitor.visit_filename(&file_value.filename);
+    visitor.visit_whitespace(&file_value.space1);
+    visitor.visit_whitespace(&file_value.space2);
+}
+
+pub fn walk_capture<V: Visitor>(visitor: &mut V, capture: &Capture) {
+    for line_terminator in &capture.line_terminators {
+        visitor.visit_line_terminator(line_terminator);
+    }
+    visitor.visit_whitespace(&capture.space0);
+    visitor.visit_encoded_string(&capture.name);
+    visitor.visit_whitespace(&capture.space1);
+    visitor.visit_whitespace(&capture.space2);
+    visitor.visit_query(&capture.query);
+    for (space, filter) in &capture.filters {
+        visitor.visit_whitespace(space);
+        visitor.visit_filter(filter);
+    }
+    if let Some(default) = &capture.default {
+        visitor.visit_capture_default(default);
+    }
+    visitor.visit_line_terminator(&capture.line_terminator0);
+}
+
+pub fn walk_capture_default<V: Visitor>(visitor: &mut V, default: &CaptureDefault) {
+    visitor.visit_whitespace(&default.space0);
+    visitor.visit_whitespace(&default.space1);
+    visitor.visit_variable_value(&default.value);
+}
+
+pub fn walk_assert<V: Visitor>(visitor: &mut V, assert: &Assert) {
+    for line_terminator in &assert.line_terminators {
+        visitor.visit_line_terminator(line_terminator);
+    }
+    visitor.visit_whitespace(&assert.space0);
+    visitor.visit_query(&assert.query);
+    for (space, filter) in &assert.filters {
+        visitor.visit_whitespace(space);
+        visitor.visit_filter(filter);
+    }
+    visitor.visit_whitespace(&assert.space1);
+    visitor.visit_predicate(&assert.predicate);
+    visitor.visit_line_terminator(&assert.line_terminator0);
+}
+
+pub fn walk_query<V: Visitor>(visitor: &mut V, query: &Query) {
+    visitor.visit_query_value(&query.value);
+}
+
+pub fn walk_query_value<V: Visitor>(visitor: &mut V, query_value: &QueryValue) {
+    match query_value {
+        QueryValue::Header { space0, name } | QueryValue::Variable { space0, name } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_template(name);
+        }
+        QueryValue::Cookie { space0, expr } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_cookie_path(expr);
+        }
+        QueryValue::Xpath { space0, expr } | QueryValue::Jsonpath { space0, expr } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_template(expr);
+        }
+        QueryValue::Regex { space0, value } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_regex_value(value);
+        }
+        QueryValue::Certificate { space0, .. } => visitor.visit_whitespace(space0),
+        QueryValue::Status
+        | QueryValue::Url
+        | QueryValue::Body
+        | QueryValue::Duration
+        | QueryValue::Bytes
+        | QueryValue::Sha256
+        | QueryValue::Md5 => {}
+    }
+}
+
+pub fn walk_regex_value<V: Visitor>(visitor: &mut V, regex_value: &RegexValue) {
+    match regex_value {
+        RegexValue::Template(template) => visitor.visit_template(template),
+        RegexValue::Regex(regex) => visitor.visit_regex(regex),
+    }
+}
+
+pub fn walk_cookie_path<V: Visitor>(visitor: &mut V, cookie_path: &CookiePath) {
+    visitor.visit_template(&cookie_path.name);
+    if let Some(attribute) = &cookie_path.attribute {
+        visitor.visit_cookie_attribute(attribute);
+    }
+}
+
+pub fn walk_cookie_attribute<V: Visitor>(visitor: &mut V, cookie_attribute: &CookieAttribute) {
+    visitor.visit_whitespace(&cookie_attribute.space0);
+    visitor.visit_whitespace(&cookie_attribute.space1);
+}
+
+pub fn walk_predicate<V: Visitor>(visitor: &mut V, predicate: &Predicate) {
+    visitor.visit_whitespace(&predicate.space0);
+    visitor.visit_predicate_func(&predicate.predicate_func);
+}
+
+pub fn walk_predicate_func<V: Visitor>(visitor: &mut V, predicate_func: &PredicateFunc) {
+    visitor.visit_predicate_func_value(&predicate_func.value);
+}
+
+pub fn walk_predicate_func_value<V: Visitor>(
+    visitor: &mut V,
+    predicate_func_value: &PredicateFuncValue,
+) {
+    match predicate_func_value {
+        PredicateFuncValue::Equal { space0, value, .. }
+        | PredicateFuncValue::NotEqual { space0, value, .. }
+        | PredicateFuncValue::GreaterThan { space0, value, .. }
+        | PredicateFuncValue::GreaterThanOrEqual { space0, value, .. }
+        | PredicateFuncValue::LessThan { space0, value, .. }
+        | PredicateFuncValue::LessThanOrEqual { space0, value, .. }
+        | PredicateFuncValue::StartWith { space0, value }
+        | PredicateFuncValue::EndWith { space0, value }
+        | PredicateFuncValue::Contain { space0, value }
+        | PredicateFuncValue::Include { space0, value }
+        | PredicateFuncValue::Match { space0, value } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_predicate_value(value);
+        }
+        PredicateFuncValue::IsInteger
+        | PredicateFuncValue::IsFloat
+        | PredicateFuncValue::IsBoolean
+        | PredicateFuncValue::IsString
+        | PredicateFuncValue::IsCollection
+        | PredicateFuncValue::IsDate
+        | PredicateFuncValue::Exist
+        | PredicateFuncValue::IsEmpty => {}
+    }
+}
+
+pub fn walk_predicate_value<V: Visitor>(visitor: &mut V, predicate_value: &PredicateValue) {
+    match predicate_value {
+        PredicateValue::String(template) => visitor.visit_template(template),
+        PredicateValue::MultilineString(value) => visitor.visit_multiline_string(value),
+        PredicateValue::Hex(hex) => visitor.visit_hex(hex),
+        PredicateValue::Base64(base64) => visitor.visit_base64(base64),
+        PredicateValue::Expression(expr) => visitor.visit_expr(expr),
+        PredicateValue::Regex(regex) => visitor.visit_regex(regex),
+        PredicateValue::Integer(_)
+        | PredicateValue::Float(_)
+        | PredicateValue::Bool(_)
+        | PredicateValue::Null => {}
+    }
+}
+
+pub fn walk_body<V: Visitor>(visitor: &mut V, body: &Body) {
+    for line_terminator in &body.line_terminators {
+        visitor.visit_line_terminator(line_terminator);
+    }
+    visitor.visit_whitespace(&body.space0);
+    visitor.visit_bytes(&body.value);
+    visitor.visit_line_terminator(&body.line_terminator0);
+}
+
+pub fn walk_bytes<V: Visitor>(visitor: &mut V, bytes: &Bytes) {
+    match bytes {
+        Bytes::Json(value) => visitor.visit_json_value(value),
+        Bytes::Xml(_) => {}
+        Bytes::MultilineString(value) => visitor.visit_multiline_string(value),
+        Bytes::OnelineString(template) => visitor.visit_template(template),
+        Bytes::Base64(base64) => visitor.visit_base64(base64),
+        Bytes::File(file) => visitor.visit_file(file),
+        Bytes::Hex(hex) => visitor.visit_hex(hex),
+    }
+}
+
+pub fn walk_multiline_string<V: Visitor>(visitor: &mut V, multiline_string: &MultilineString) {
+    match multiline_string {
+        MultilineString::OneLineText(template) => visitor.visit_template(template),
+        MultilineString::Text(text) | MultilineString::Json(text) | MultilineString::Xml(text) => {
+            visitor.visit_text(text);
+        }
+        MultilineString::GraphQl(graphql) => visitor.visit_graphql(graphql),
+    }
+}
+
+pub fn walk_text<V: Visitor>(visitor: &mut V, text: &Text) {
+    visitor.visit_whitespace(&text.space);
+    visitor.visit_whitespace(&text.newline);
+    visitor.visit_template(&text.value);
+}
+
+pub fn walk_graphql<V: Visitor>(visitor: &mut V, graphql: &GraphQl) {
+    visitor.visit_whitespace(&graphql.space);
+    visitor.visit_whitespace(&graphql.newline);
+    visitor.visit_template(&graphql.value);
+    if let Some(variables) = &graphql.variables {
+        visitor.visit_graphql_variables(variables);
+    }
+}
+
+pub fn walk_graphql_variables<V: Visitor>(visitor: &mut V, variables: &GraphQlVariables) {
+    visitor.visit_whitespace(&variables.space);
+    visitor.visit_json_value(&variables.value);
+    visitor.visit_whitespace(&variables.whitespace);
+}
+
+pub fn walk_base64<V: Visitor>(visitor: &mut V, base64: &Base64) {
+    visitor.visit_whitespace(&base64.space0);
+    visitor.visit_whitespace(&base64.space1);
+}
+
+pub fn walk_hex<V: Visitor>(visitor: &mut V, hex: &Hex) {
+    visitor.visit_whitespace(&hex.space0);
+    visitor.visit_whitespace(&hex.space1);
+}
+
+pub fn walk_file<V: Visitor>(visitor: &mut V, file: &File) {
+    visitor.visit_whitespace(&file.space0);
+    visitor.visit_filename(&file.filename);
+    visitor.visit_whitespace(&file.space1);
+}
+
+pub fn walk_json_value<V: Visitor>(visitor: &mut V, value: &JsonValue) {
+    match value {
+        JsonValue::Expression(expr) => visitor.visit_expr(expr),
+        JsonValue::String(template) => visitor.visit_template(template),
+        JsonValue::List { elements, .. } => {
+            for element in elements {
+                visitor.visit_json_list_element(element);
+            }
+        }
+        JsonValue::Object { elements, .. } => {
+            for element in elements {
+                visitor.visit_json_object_element(element);
+            }
+        }
+        JsonValue::Number(_) | JsonValue::Boolean(_) | JsonValue::Null => {}
+    }
+}
+
+pub fn walk_json_list_element<V: Visitor>(visitor: &mut V, element: &JsonListElement) {
+    visitor.visit_json_value(&element.value);
+}
+
+pub fn walk_json_object_element<V: Visitor>(visitor: &mut V, element: &JsonObjectElement) {
+    visitor.visit_template(&element.name);
+    visitor.visit_json_value(&element.value);
+}
+
+pub fn walk_entry_option<V: Visitor>(visitor: &mut V, entry_option: &EntryOption) {
+    for line_terminator in &entry_option.line_terminators {
+        visitor.visit_line_terminator(line_terminator);
+    }
+    visitor.visit_whitespace(&entry_option.space0);
+    visitor.visit_whitespace(&entry_option.space1);
+    visitor.visit_whitespace(&entry_option.space2);
+    visitor.visit_option_kind(&entry_option.kind);
+    visitor.visit_line_terminator(&entry_option.line_terminator0);
+}
+
+pub fn walk_option_kind<V: Visitor>(visitor: &mut V, option_kind: &OptionKind) {
+    match option_kind {
+        OptionKind::CaCertificate(filename)
+        | OptionKind::ClientCert(filename)
+        | OptionKind::ClientKey(filename)
+        | OptionKind::UnixSocket(filename) => visitor.visit_filename(filename),
+        OptionKind::Variable(variable_definition) => {
+            visitor.visit_variable_definition(variable_definition);
+        }
+        _ => {}
+    }
+}
+
+pub fn walk_variable_definition<V: Visitor>(
+    visitor: &mut V,
+    variable_definition: &VariableDefinition,
+) {
+    visitor.visit_whitespace(&variable_definition.space0);
+    visitor.visit_whitespace(&variable_definition.space1);
+    visitor.visit_variable_value(&variable_definition.value);
+}
+
+pub fn walk_variable_value<V: Visitor>(visitor: &mut V, variable_value: &VariableValue) {
+    if let VariableValue::String(template) = variable_value {
+        visitor.visit_template(template);
+    }
+}
+
+pub fn walk_filter<V: Visitor>(visitor: &mut V, filter: &Filter) {
+    visitor.visit_filter_value(&filter.value);
+}
+
+pub fn walk_filter_value<V: Visitor>(visitor: &mut V, filter_value: &FilterValue) {
+    match filter_value {
+        FilterValue::Decode { space0, encoding } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_template(encoding);
+        }
+        FilterValue::Format { space0, fmt } | FilterValue::ToDate { space0, fmt } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_template(fmt);
+        }
+        FilterValue::Nth { space0, .. } => visitor.visit_whitespace(space0),
+        FilterValue::Regex { space0, value } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_regex_value(value);
+        }
+        FilterValue::Replace {
+            space0,
+            old_value,
+            space1,
+            new_value,
+        } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_regex_value(old_value);
+            visitor.visit_whitespace(space1);
+            visitor.visit_template(new_value);
+        }
+        FilterValue::Split { space0, sep } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_template(sep);
+        }
+        FilterValue::XPath { space0, expr } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_template(expr);
+        }
+        FilterValue::Count
+        | FilterValue::DaysAfterNow
+        | FilterValue::DaysBeforeNow
+        | FilterValue::HtmlEscape
+        | FilterValue::HtmlUnescape
+        | FilterValue::ToInt
+        | FilterValue::UrlDecode
+        | FilterValue::UrlEncode => {}
+    }
+}
+
+pub fn walk_template<V: Visitor>(visitor: &mut V, template: &Template) {
+    for element in &template.elements {
+        visitor.visit_template_element(element);
+    }
+}
+
+pub fn walk_template_element<V: Visitor>(visitor: &mut V, element: &TemplateElement) {
+    if let TemplateElement::Expression(expr) = element {
+        visitor.visit_expr(expr);
+    }
+}
+
+pub fn walk_expr<V: Visitor>(visitor: &mut V, expr: &Expr) {
+    visitor.visit_whitespace(&expr.space0);
+    visitor.visit_variable(&expr.variable);
+    visitor.visit_whitespace(&expr.space1);
+}
+
+pub fn walk_line_terminator<V: Visitor>(visitor: &mut V, line_terminator: &LineTerminator) {
+    visitor.visit_whitespace(&line_terminator.space0);
+    if let Some(comment) = &line_terminator.comment {
+        visitor.visit_comment(comment);
+    }
+    visitor.visit_whitespace(&line_terminator.newline);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser;
+
+    /// Collects the source position of every whitespace.
+    struct Whitespaces(Vec<(usize, usize)>);
+
+    impl Visitor for Whitespaces {
+        fn visit_whitespace(&mut self, whitespace: &Whitespace) {
+            if !whitespace.value.is_empty() {
+                let pos = &whitespace.source_info.start;
+                self.0.push((pos.line, pos.column));
+            }
+        }
+    }
+
+    #[test]
+    fn test_walk_in_source_order() {
+        let hurl_file = parser::parse_hurl_file(
+            r#"GET http://localhost # comment
+HTTP 200
+[Asserts]
+jsonpath "$.id" count == 2
+"#,
+        )
+        .unwrap();
+        let mut visitor = Whitespaces(vec![]);
+        visitor.visit_hurl_file(&hurl_file);
+        let mut sorted = visitor.0.clone();
+        sorted.sort();
+        assert_eq!(visitor.0, sorted);
+        assert_eq!(
+            visitor.0,
+            vec![
+                (1, 4),
+                (1, 21),
+                (1, 31),
+                (2, 5),
+                (2, 9),
+                (3, 10),
+                (4, 9),
+                (4, 16),
+                (4, 22),
+                (4, 25),
+                (4, 27),
+            ]
+        );
+    }
+
+    /// Stops at requests, without visiting their children.
+    struct Methods(Vec<String>);
+
+    impl Visitor for Methods {
+        fn visit_request(&mut self, request: &Request) {
+            self.0.push(request.method.0.clone());
+        }
+
+        fn visit_variable(&mut self, _variable: &Variable) {
+            panic!("request children must not be visited");
+        }
+    }
+
+    #[test]
+    fn test_override_stops_walk() {
+        let hurl_file = parser::parse_hurl_file("GET {{url}}\nHTTP 200\nPOST {{url}}\n").unwrap();
+        let mut visitor = Methods(vec![]);
+        visitor.visit_hurl_file(&hurl_file);
+        assert_eq!(visitor.0, vec!["GET", "POST"]);
+    }
+}
@@ -0,0 +1,799 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2023 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+//! Mutable traversal of the Hurl AST.
+//!
+//! [`VisitorMut`] is the mutable counterpart of [`Visitor`]: its
+//! `visit_*` methods default to the `walk_*` functions of this module, and nodes can be
+//! rewritten in place.
+//!
+//! ```
+//! use hurl_core::ast::visit_mut::VisitorMut;
+//! use hurl_core::ast::Variable;
+//! use hurl_core::parser;
+//!
+//! struct Rename;
+//!
+//! impl VisitorMut for Rename {
+//!     fn visit_variable(&mut self, variable: &mut Variable) {
+//!         if variable.name == "host" {
+//!             variable.name = "base_url".to_string();
+//!         }
+//!     }
+//! }
+//!
+//! let mut hurl_file = parser::parse_hurl_file("GET {{host}}/users\n").unwrap();
+//! Rename.visit_hurl_file(&mut hurl_file);
+//! assert_eq!(hurl_file.entries[0].request.url.to_string(), "{{base_url}}/users");
+//! ```
+use crate::ast::*;
+
+pub trait VisitorMut: Sized {
+    fn visit_hurl_file(&mut self, hurl_file: &mut HurlFile) {
+        walk_hurl_file(self, hurl_file);
+    }
+
+    fn visit_entry(&mut self, entry: &mut Entry) {
+        walk_entry(self, entry);
+    }
+
+    fn visit_request(&mut self, request: &mut Request) {
+        walk_request(self, request);
+    }
+
+    fn visit_response(&mut self, response: &mut Response) {
+        walk_response(self, response);
+    }
+
+    fn visit_method(&mut self, _method: &mut Method) {}
+
+    fn visit_version(&mut self, _version: &mut Version) {}
+
+    fn visit_status(&mut self, _status: &mut Status) {}
+
+    fn visit_key_value(&mut self, key_value: &mut KeyValue) {
+        walk_key_value(self, key_value);
+    }
+
+    fn visit_section(&mut self, section: &mut Section) {
+        walk_section(self, section);
+    }
+
+    fn visit_section_value(&mut self, section_value: &mut SectionValue) {
+        walk_section_value(self, section_value);
+    }
+
+    fn visit_cookie(&mut self, cookie: &mut Cookie) {
+        walk_cookie(self, cookie);
+    }
+
+    fn visit_multipart_param(&mut self, multipart_param: &mut MultipartParam) {
+        walk_multipart_param(self, multipart_param);
+    }
+
+    fn visit_file_param(&mut self, file_param: &mut FileParam) {
+        walk_file_param(self, file_param);
+    }
+
+    fn visit_file_value(&mut self, file_value: &mut FileValue) {
+        walk_file_value(self, file_value);
+    }
+
+    fn visit_capture(&mut self, capture: &mut Capture) {
+        walk_capture(self, capture);
+    }
+
+    fn visit_capture_default(&mut self, default: &mut CaptureDefault) {
+        walk_capture_default(self, default);
+    }
+
+    fn visit_assert(&mut self, assert: &mut Assert) {
+        walk_assert(self, assert);
+    }
+
+    fn visit_query(&mut self, query: &mut Query) {
+        walk_query(self, query);
+    }
+
+    fn visit_query_value(&mut self, query_value: &mut QueryValue) {
+        walk_query_value(self, query_value);
+    }
+
+    fn visit_regex_value(&mut self, regex_value: &mut RegexValue) {
+        walk_regex_value(self, regex_value);
+    }
+
+    fn visit_cookie_path(&mut self, cookie_path: &mut CookiePath) {
+        walk_cookie_path(self, cookie_path);
+    }
+
+    fn visit_cookie_attribute(&mut self, cookie_attribute: &mut CookieAttribute) {
+        walk_cookie_attribute(self, cookie_attribute);
+    }
+
+    fn visit_predicate(&mut self, predicate: &mut Predicate) {
+        walk_predicate(self, predicate);
+    }
+
+    fn visit_predicate_func(&mut self, predicate_func: &mut PredicateFunc) {
+        walk_predicate_func(self, predicate_func);
+    }
+
+    fn visit_predicate_func_value(&mut self, predicate_func_value: &mut PredicateFuncValue) {
+        walk_predicate_func_value(self, predicate_func_value);
+    }
+
+    fn visit_predicate_value(&mut self, predicate_value: &mut PredicateValue) {
+        walk_predicate_value(self, predicate_value);
+    }
+
+    fn visit_body(&mut self, body: &mut Body) {
+        walk_body(self, body);
+    }
+
+    fn visit_bytes(&mut self, bytes: &mut Bytes) {
+        walk_bytes(self, bytes);
+    }
+
+    fn visit_multiline_string(&mut self, multiline_string: &mut MultilineString) {
+        walk_multiline_string(self, multiline_string);
+    }
+
+    fn visit_text(&mut self, text: &mut Text) {
+        walk_text(self, text);
+    }
+
+    fn visit_graphql(&mut self, graphql: &mut GraphQl) {
+        walk_graphql(self, graphql);
+    }
+
+    fn visit_graphql_variables(&mut self, variables: &mut GraphQlVariables) {
+        walk_graphql_variables(self, variables);
+    }
+
+    fn visit_base64(&mut self, base64: &mut Base64) {
+        walk_base64(self, base64);
+    }
+
+    fn visit_hex(&mut self, hex: &mut Hex) {
+        walk_hex(self, hex);
+    }
+
+    fn visit_file(&mut self, file: &mut File) {
+        walk_file(self, file);
+    }
+
+    fn visit_json_value(&mut self, value: &mut JsonValue) {
+        walk_json_value(self, value);
+    }
+
+    fn visit_json_list_element(&mut self, element: &mut JsonListElement) {
+        walk_json_list_element(self, element);
+    }
+
+    fn visit_json_object_element(&mut self, element: &mut JsonObjectElement) {
+        walk_json_object_element(self, element);
+    }
+
+    fn visit_entry_option(&mut self, entry_option: &mut EntryOption) {
+        walk_entry_option(self, entry_option);
+    }
+
+    fn visit_option_kind(&mut self, option_kind: &mut OptionKind) {
+        walk_option_kind(self, option_kind);
+    }
+
+    fn visit_variable_definition(&mut self, variable_definition: &mut VariableDefinition) {
+        walk_variable_definition(self, variable_definition);
+    }
+
+    fn visit_variable_value(&mut self, variable_value: &mut VariableValue) {
+        walk_variable_value(self, variable_value);
+    }
+
+    fn visit_filter(&mut self, filter: &mut Filter) {
+        walk_filter(self, filter);
+    }
+
+    fn visit_filter_value(&mut self, filter_value: &mut FilterValue) {
+        walk_filter_value(self, filter_value);
+    }
+
+    fn visit_template(&mut self, template: &mut Template) {
+        walk_template(self, template);
+    }
+
+    fn visit_template_element(&mut self, element: &mut TemplateElement) {
+        walk_template_element(self, element);
+    }
+
+    fn visit_expr(&mut self, expr: &mut Expr) {
+        walk_expr(self, expr);
+    }
+
+    fn visit_variable(&mut self, _variable: &mut Variable) {}
+
+    fn visit_regex(&mut self, _regex: &mut Regex) {}
+
+    fn visit_encoded_string(&mut self, _encoded_string: &mut EncodedString) {}
+
+    fn visit_filename(&mut self, _filename: &mut Filename) {}
+
+    fn visit_line_terminator(&mut self, line_terminator: &mut LineTerminator) {
+        walk_line_terminator(self, line_terminator);
+    }
+
+    fn visit_comment(&mut self, _comment: &mut Comment) {}
+
+    fn visit_whitespace(&mut self, _whitespace: &mut Whitespace) {}
+}
+
+pub fn walk_hurl_file<V: VisitorMut>(visitor: &mut V, hurl_file: &mut HurlFile) {
+    for entry in &mut hurl_file.entries {
+        visitor.visit_entry(entry);
+    }
+    for line_terminator in &mut hurl_file.line_terminators {
+        visitor.visit_line_terminator(line_terminator);
+    }
+}
+
+pub fn walk_entry<V: VisitorMut>(visitor: &mut V, entry: &mut Entry) {
+    visitor.visit_request(&mut entry.request);
+    if let Some(response) = &mut entry.response {
+        visitor.visit_response(response);
+    }
+}
+
+pub fn walk_request<V: VisitorMut>(visitor: &mut V, request: &mut Request) {
+    for line_terminator in &mut request.line_terminators {
+        visitor.visit_line_terminator(line_terminator);
+    }
+    visitor.visit_whitespace(&mut request.space0);
+    visitor.visit_method(&mut request.method);
+    visitor.visit_whitespace(&mut request.space1);
+    visitor.visit_template(&mut request.url);
+    visitor.visit_line_terminator(&mut request.line_terminator0);
+    for header in &mut request.headers {
+        visitor.visit_key_value(header);
+    }
+    for section in &mut request.sections {
+        visitor.visit_section(section);
+    }
+    if let Some(body) = &mut request.body {
+        visitor.visit_body(body);
+    }
+}
+
+pub fn walk_response<V: VisitorMut>(visitor: &mut V, response: &mut Response) {
+    for line_terminator in &mut response.line_terminators {
+        visitor.visit_line_terminator(line_terminator);
+    }
+    visitor.visit_version(&mut response.version);
+    visitor.visit_whitespace(&mut response.space0);
+    visitor.visit_status(&mut response.status);
+    visitor.visit_whitespace(&mut response.space1);
+    visitor.visit_line_terminator(&mut response.line_terminator0);
+    for header in &mut response.headers {
+        visitor.visit_key_value(header);
+    }
+    for section in &mut response.sections {
+        visitor.visit_section(section);
+    }
+    if let Some(body) = &mut response.body {
+        visitor.visit_body(body);
+    }
+}
+
+pub fn walk_key_value<V: VisitorMut>(visitor: &mut V, key_value: &mut KeyValue) {
+    for line_terminator in &mut key_value.line_terminators {
+        visitor.visit_line_terminator(line_terminator);
+    }
+    visitor.visit_whitespace(&mut key_value.space0);
+    visitor.visit_encoded_string(&mut key_value.key);
+    visitor.visit_whitespace(&mut key_value.space1);
+    visitor.visit_whitespace(&mut key_value.space2);
+    visitor.visit_template(&mut key_value.value);
+    visitor.visit_line_terminator(&mut key_value.line_terminator0);
+}
+
+pub fn walk_section<V: VisitorMut>(visitor: &mut V, section: &mut Section) {
+    for line_terminator in &mut section.line_terminators {
+        visitor.visit_line_terminator(line_terminator);
+    }
+    visitor.visit_whitespace(&mut section.space0);
+    visitor.visit_line_terminator(&mut section.line_terminator0);
+    visitor.visit_section_value(&mut section.value);
+}
+
+pub fn walk_section_value<V: VisitorMut>(visitor: &mut V, section_value: &mut SectionValue) {
+    match section_value {
+        SectionValue::QueryParams(params) | SectionValue::FormParams(params) => {
+            for param in params {
+                visitor.visit_key_value(param);
+            }
+        }
+        SectionValue::BasicAuth(param) => {
+            if let Some(param) = param {
+                visitor.visit_key_value(param);
+            }
+        }
+        SectionValue::MultipartFormData(params) => {
+            for param in params {
+                visitor.visit_multipart_param(param);
+            }
+        }
+        SectionValue::Cookies(cookies) => {
+            for cookie in cookies {
+                visitor.visit_cookie(cookie);
+            }
+        }
+        SectionValue::Captures(captures) => {
+            for capture in captures {
+                visitor.visit_capture(capture);
+            }
+        }
+        SectionValue::Asserts(asserts) => {
+            for assert in asserts {
+                visitor.visit_assert(assert);
+            }
+        }
+        SectionValue::Options(options) => {
+            for option in options {
+                visitor.visit_entry_option(option);
+            }
+        }
+    }
+}
+
+pub fn walk_cookie<V: VisitorMut>(visitor: &mut V, cookie: &mut Cookie) {
+    for line_terminator in &mut cookie.line_terminators {
+        visitor.visit_line_terminator(line_terminator);
+    }
+    visitor.visit_whitespace(&mut cookie.space0);
+    visitor.visit_encoded_string(&mut cookie.name);
+    visitor.visit_whitespace(&mut cookie.space1);
+    visitor.visit_whitespace(&mut cookie.space2);
+    visitor.visit_template(&mut cookie.value);
+    visitor.visit_line_terminator(&mut cookie.line_terminator0);
+}
+
+pub fn walk_multipart_param<V: VisitorMut>(visitor: &mut V, multipart_param: &mut MultipartParam) {
+    match multipart_param {
+        MultipartParam::Param(param) => visitor.visit_key_value(param),
+        MultipartParam::FileParam(param) => visitor.visit_file_param(param),
+    }
+}
+
+pub fn walk_file_param<V: VisitorMut>(visitor: &mut V, file_param: &mut FileParam) {
+    for line_terminator in &mut file_param.line_terminators {
+        visitor.visit_line_terminator(line_terminator);
+    }
+    visitor.visit_whitespace(&mut file_param.space0);
+    visitor.visit_encoded_string(&mut file_param.key);
+    visitor.visit_whitespace(&mut file_param.space1);
+    visitor.visit_whitespace(&mut file_param.space2);
+    visitor.visit_file_value(&mut file_param.value);
+    visitor.visit_line_terminator(&mut file_param.line_terminator0);
+}
+
+pub fn walk_file_value<V: VisitorMut>(visitor: &mut V, file_value: &mut FileValue) {
+    visitor.visit_whitespace(&mut file_value.space0);
+    visitor.visit_filename(&mut file_value.filename);
+    visitor.visit_whitespace(&mut file_value.space1);
+    visitor.visit_whitespace(&mut file_value.space2);
+}
+
+pub fn walk_capture<V: VisitorMut>(visitor: &mut V, capture: &mut Capture) {
+    for line_terminator in &mut capture.line_terminators {
+        visitor.visit_line_terminator(line_terminator);
+    }
+    visitor.visit_whitespace(&mut capture.space0);
+    visitor.visit_encoded_string(&mut capture.name);
+    visitor.visit_whitespace(&mut capture.space1);
+    visitor.visit_whitespace(&mut capture.space2);
+    visitor.visit_query(&mut capture.query);
+    for (space, filter) in &mut capture.filters {
+        visitor.visit_whitespace(space);
+        visitor.visit_filter(filter);
+    }
+    if let Some(default) = &mut capture.default {
+        visitor.visit_capture_default(default);
+    }
+    visitor.visit_line_terminator(&mut capture.line_terminator0);
+}
+
+pub fn walk_capture_default<V: VisitorMut>(visitor: &mut V, default: &mut CaptureDefault) {
+    visitor.visit_whitespace(&mut default.space0);
+    visitor.visit_whitespace(&mut default.space1);
+    visitor.visit_variable_value(&mut default.value);
+}
+
+pub fn walk_assert<V: VisitorMut>(visitor: &mut V, assert: &mut Assert) {
+    for line_terminator in &mut assert.line_terminators {
+        visitor.visit_line_terminator(line_terminator);
+    }
+    visitor.visit_whitespace(&mut assert.space0);
+    visitor.visit_query(&mut assert.query);
+    for (space, filter) in &mut assert.filters {
+        visitor.visit_whitespace(space);
+        visitor.visit_filter(filter);
+    }
+    visitor.visit_whitespace(&mut assert.space1);
+    visitor.visit_predicate(&mut assert.predicate);
+    visitor.visit_line_terminator(&mut assert.line_terminator0);
+}
+
+pub fn walk_query<V: VisitorMut>(visitor: &mut V, query: &mut Query) {
+    visitor.visit_query_value(&mut query.value);
+}
+
+pub fn walk_query_value<V: VisitorMut>(visitor: &mut V, query_value: &mut QueryValue) {
+    match query_value {
+        QueryValue::Header { space0, name } | QueryValue::Variable { space0, name } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_template(name);
+        }
+        QueryValue::Cookie { space0, expr } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_cookie_path(expr);
+        }
+        QueryValue::Xpath { space0, expr } | QueryValue::Jsonpath { space0, expr } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_template(expr);
+        }
+        QueryValue::Regex { space0, value } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_regex_value(value);
+        }
+        QueryValue::Certificate { space0, .. } => visitor.visit_whitespace(space0),
+        QueryValue::Status
+        | QueryValue::Url
+        | QueryValue::Body
+        | QueryValue::Duration
+        | QueryValue::Bytes
+        | QueryValue::Sha256
+        | QueryValue::Md5 => {}
+    }
+}
+
+pub fn walk_regex_value<V: VisitorMut>(visitor: &mut V, regex_value: &mut RegexValue) {
+    match regex_value {
+        RegexValue::Template(template) => visitor.visit_template(template),
+        RegexValue::Regex(regex) => visitor.visit_regex(regex),
+    }
+}
+
+pub fn walk_cookie_path<V: VisitorMut>(visitor: &mut V, cookie_path: &mut CookiePath) {
+    visitor.visit_template(&mut cookie_path.name);
+    if let Some(attribute) = &mut cookie_path.attribute {
+        visitor.visit_cookie_attribute(attribute);
+    }
+}
+
+pub fn walk_cookie_attribute<V: VisitorMut>(
+    visitor: &mut V,
+    cookie_attribute: &mut CookieAttribute,
+) {
+    visitor.visit_whitespace(&mut cookie_attribute.space0);
+    visitor.visit_whitespace(&mut cookie_attribute.space1);
+}
+
+pub fn walk_predicate<V: VisitorMut>(visitor: &mut V, predicate: &mut Predicate) {
+    visitor.visit_whitespace(&mut predicate.space0);
+    visitor.visit_predicate_func(&mut predicate.predicate_func);
+}
+
+pub fn walk_predicate_func<V: VisitorMut>(visitor: &mut V, predicate_func: &mut PredicateFunc) {
+    visitor.visit_predicate_func_value(&mut predicate_func.value);
+}
+
+pub fn walk_predicate_func_value<V: VisitorMut>(
+    visitor: &mut V,
+    predicate_func_value: &mut PredicateFuncValue,
+) {
+    match predicate_func_value {
+        PredicateFuncValue::Equal { space0, value, .. }
+        | PredicateFuncValue::NotEqual { space0, value, .. }
+        | PredicateFuncValue::GreaterThan { space0, value, .. }
+        | PredicateFuncValue::GreaterThanOrEqual { space0, value, .. }
+        | PredicateFuncValue::LessThan { space0, value, .. }
+        | PredicateFuncValue::LessThanOrEqual { space0, value, .. }
+        | PredicateFuncValue::StartWith { space0, value }
+        | PredicateFuncValue::EndWith { space0, value }
+        | PredicateFuncValue::Contain { space0, value }
+        | PredicateFuncValue::Include { space0, value }
+        | PredicateFuncValue::Match { space0, value } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_predicate_value(value);
+        }
+        PredicateFuncValue::IsInteger
+        | PredicateFuncValue::IsFloat
+        | PredicateFuncValue::IsBoolean
+        | PredicateFuncValue::IsString
+        | PredicateFuncValue::IsCollection
+        | PredicateFuncValue::IsDate
+        | PredicateFuncValue::Exist
+        | PredicateFuncValue::IsEmpty => {}
+    }
+}
+
+pub fn walk_predicate_value<V: VisitorMut>(visitor: &mut V, predicate_value: &mut PredicateValue) {
+    match predicate_value {
+        PredicateValue::String(template) => visitor.visit_template(template),
+        PredicateValue::MultilineString(value) => visitor.visit_multiline_string(value),
+        PredicateValue::Hex(hex) => visitor.visit_hex(hex),
+        PredicateValue::Base64(base64) => visitor.visit_base64(base64),
+        PredicateValue::Expression(expr) => visitor.visit_expr(expr),
+        PredicateValue::Regex(regex) => visitor.visit_regex(regex),
+        PredicateValue::Integer(_)
+        | PredicateValue::Float(_)
+        | PredicateValue::Bool(_)
+        | PredicateValue::Null => {}
+    }
+}
+
+pub fn walk_body<V: VisitorMut>(visitor: &mut V, body: &mut Body) {
+    for line_terminator in &mut body.line_terminators {
+        visitor.visit_line_terminator(line_terminator);
+    }
+    visitor.visit_whitespace(&mut body.space0);
+    visitor.visit_bytes(&mut body.value);
+    visitor.visit_line_terminator(&mut body.line_terminator0);
+}
+
+pub fn walk_bytes<V: VisitorMut>(visitor: &mut V, bytes: &mut Bytes) {
+    match bytes {
+        Bytes::Json(value) => visitor.visit_json_value(value),
+        Bytes::Xml(_) => {}
+        Bytes::MultilineString(value) => visitor.visit_multiline_string(value),
+        Bytes::OnelineString(template) => visitor.visit_template(template),
+        Bytes::Base64(base64) => visitor.visit_base64(base64),
+        Bytes::File(file) => visitor.visit_file(file),
+        Bytes::Hex(hex) => visitor.visit_hex(hex),
+    }
+}
+
+pub fn walk_multiline_string<V: VisitorMut>(
+    visitor: &mut V,
+    multiline_string: &mut MultilineString,
+) {
+    match multiline_string {
+        MultilineString::OneLineText(template) => visitor.visit_template(template),
+        MultilineString::Text(text) | MultilineString::Json(text) | MultilineString::Xml(text) => {
+            visitor.visit_text(text);
+        }
+        MultilineString::GraphQl(graphql) => visitor.visit_graphql(graphql),
+    }
+}
+
+pub fn walk_text<V: VisitorMut>(visitor: &mut V, text: &mut Text) {
+    visitor.visit_whitespace(&mut text.space);
+    visitor.visit_whitespace(&mut text.newline);
+    visitor.visit_template(&mut text.value);
+}
+
+pub fn walk_graphql<V: VisitorMut>(visitor: &mut V, graphql: &mut GraphQl) {
+    visitor.visit_whitespace(&mut graphql.space);
+    visitor.visit_whitespace(&mut graphql.newline);
+    visitor.visit_template(&mut graphql.value);
+    if let Some(variables) = &mut graphql.variables {
+        visitor.visit_graphql_variables(variables);
+    }
+}
+
+pub fn walk_graphql_variables<V: VisitorMut>(visitor: &mut V, variables: &mut GraphQlVariables) {
+    visitor.visit_whitespace(&mut variables.space);
+    visitor.visit_json_value(&mut variables.value);
+    visitor.visit_whitespace(&mut variables.whitespace);
+}
+
+pub fn walk_base64<V: VisitorMut>(visitor: &mut V, base64: &mut Base64) {
+    visitor.visit_whitespace(&mut base64.space0);
+    visitor.visit_whitespace(&mut base64.space1);
+}
+
+pub fn walk_hex<V: VisitorMut>(visitor: &mut V, hex: &mut Hex) {
+    visitor.visit_whitespace(&mut hex.space0);
+    visitor.visit_whitespace(&mut hex.space1);
+}
+
+pub fn walk_file<V: VisitorMut>(visitor: &mut V, file: &mut File) {
+    visitor.visit_whitespace(&mut file.space0);
+    visitor.visit_filename(&mut file.filename);
+    visitor.visit_whitespace(&mut file.space1);
+}
+
+pub fn walk_json_value<V: VisitorMut>(visitor: &mut V, value: &mut JsonValue) {
+    match value {
+        JsonValue::Expression(expr) => visitor.visit_expr(expr),
+        JsonValue::String(template) => visitor.visit_template(template),
+        JsonValue::List { elements, .. } => {
+            for element in elements {
+                visitor.visit_json_list_element(element);
+            }
+        }
+        JsonValue::Object { elements, .. } => {
+            for element in elements {
+                visitor.visit_json_object_element(element);
+            }
+        }
+        JsonValue::Number(_) | JsonValue::Boolean(_) | JsonValue::Null => {}
+    }
+}
+
+pub fn walk_json_list_element<V: VisitorMut>(visitor: &mut V, element: &mut JsonListElement) {
+    visitor.visit_json_value(&mut element.value);
+}
+
+pub fn walk_json_object_element<V: VisitorMut>(visitor: &mut V, element: &mut JsonObjectElement) {
+    visitor.visit_template(&mut element.name);
+    visitor.visit_json_value(&mut element.value);
+}
+
+pub fn walk_entry_option<V: VisitorMut>(visitor: &mut V, entry_option: &mut EntryOption) {
+    for line_terminator in &mut entry_option.line_terminators {
+        visitor.visit_line_terminator(line_terminator);
+    }
+    visitor.visit_whitespace(&mut entry_option.space0);
+    visitor.visit_whitespace(&mut entry_option.space1);
+    visitor.visit_whitespace(&mut entry_option.space2);
+    visitor.visit_option_kind(&mut entry_option.kind);
+    visitor.visit_line_terminator(&mut entry_option.line_terminator0);
+}
+
+pub fn walk_option_kind<V: VisitorMut>(visitor: &mut V, option_kind: &mut OptionKind) {
+    match option_kind {
+        OptionKind::CaCertificate(filename)
+        | OptionKind::ClientCert(filename)
+        | OptionKind::ClientKey(filename)
+        | OptionKind::UnixSocket(filename) => visitor.visit_filename(filename),
+        OptionKind::Variable(variable_definition) => {
+            visitor.visit_variable_definition(variable_definition);
+        }
+        _ => {}
+    }
+}
+
+pub fn walk_variable_definition<V: VisitorMut>(
+    visitor: &mut V,
+    variable_definition: &mut VariableDefinition,
+) {
+    visitor.visit_whitespace(&mut variable_definition.space0);
+    visitor.visit_whitespace(&mut variable_definition.space1);
+    visitor.visit_variable_value(&mut variable_definition.value);
+}
+
+pub fn walk_variable_value<V: VisitorMut>(visitor: &mut V, variable_value: &mut VariableValue) {
+    if let VariableValue::String(template) = variable_value {
+        visitor.visit_template(template);
+    }
+}
+
+pub fn walk_filter<V: VisitorMut>(visitor: &mut V, filter: &mut Filter) {
+    visitor.visit_filter_value(&mut filter.value);
+}
+
+pub fn walk_filter_value<V: VisitorMut>(visitor: &mut V, filter_value: &mut FilterValue) {
+    match filter_value {
+        FilterValue::Decode { space0, encoding } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_template(encoding);
+        }
+        FilterValue::Format { space0, fmt } | FilterValue::ToDate { space0, fmt } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_template(fmt);
+        }
+        FilterValue::Nth { space0, .. } => visitor.visit_whitespace(space0),
+        FilterValue::Regex { space0, value } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_regex_value(value);
+        }
+        FilterValue::Replace {
+            space0,
+            old_value,
+            space1,
+            new_value,
+        } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_regex_value(old_value);
+            visitor.visit_whitespace(space1);
+            visitor.visit_template(new_value);
+        }
+        FilterValue::Split { space0, sep } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_template(sep);
+        }
+        FilterValue::XPath { space0, expr } => {
+            visitor.visit_whitespace(space0);
+            visitor.visit_template(expr);
+        }
+        FilterValue::Count
+        | FilterValue::DaysAfterNow
+        | FilterValue::DaysBeforeNow
+        | FilterValue::HtmlEscape
+        | FilterValue::HtmlUnescape
+        | FilterValue::ToInt
+        | FilterValue::UrlDecode
+        | FilterValue::UrlEncode => {}
+    }
+}
+
+pub fn walk_template<V: VisitorMut>(visitor: &mut V, template: &mut Template) {
+    for element in &mut template.elements {
+        visitor.visit_template_element(element);
+    }
+}
+
+pub fn walk_template_element<V: VisitorMut>(visitor: &mut V, element: &mut TemplateElement) {
+    if let TemplateElement::Expression(expr) = element {
+        visitor.visit_expr(expr);
+    }
+}
+
+pub fn walk_expr<V: VisitorMut>(visitor: &mut V, expr: &mut Expr) {
+    visitor.visit_whitespace(&mut expr.space0);
+    visitor.visit_variable(&mut expr.variable);
+    visitor.visit_whitespace(&mut expr.space1);
+}
+
+pub fn walk_line_terminator<V: VisitorMut>(visitor: &mut V, line_terminator: &mut LineTerminator) {
+    visitor.visit_whitespace(&mut line_terminator.space0);
+    if let Some(comment) = &mut line_terminator.comment {
+        visitor.visit_comment(comment);
+    }
+    visitor.visit_whitespace(&mut line_terminator.newline);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser;
+
+    /// Removes every comment.
+    struct Uncomment;
+
+    impl VisitorMut for Uncomment {
+        fn visit_line_terminator(&mut self, line_terminator: &mut LineTerminator) {
+            if line_terminator.comment.take().is_some() {
+                line_terminator.space0.value = String::new();
+            }
+        }
+    }
+
+    #[test]
+    fn test_rewrite() {
+        let mut hurl_file = parser::parse_hurl_file(
+            r#"# Users
+GET http://localhost/users # all users
+[QueryStringParams]
+page: 1 # first
+HTTP 200
+"#,
+        )
+        .unwrap();
+        Uncomment.visit_hurl_file(&mut hurl_file);
+
+        let request = &hurl_file.entries[0].request;
+        assert_eq!(request.line_terminators[0].comment, None);
+        assert_eq!(request.line_terminator0.comment, None);
+        assert_eq!(request.line_terminator0.space0.value, "");
+        let param = &request.querystring_params()[0];
+        assert_eq!(param.line_terminator0.comment, None);
+        assert_eq!(param.value.to_string(), "1");
+    }
+}
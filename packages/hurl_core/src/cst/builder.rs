@@ -0,0 +1,615 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2023 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+//! Builds a syntax tree from the AST of a Hurl file.
+//!
+//! Nodes are created while walking the AST: the range of a node is given by its source info, if
+//! any, extended to its children. Nodes without source info in the AST (method, comment, option
+//! and variable names, body value) get the range between their siblings. The tree is then
+//! normalized, so that children are contiguous: the bytes between children become
+//! [`SyntaxKind::Token`] nodes.
+use std::ops::Range;
+
+use crate::ast::visit::{self, Visitor};
+use crate::ast::*;
+use crate::cst::{SyntaxKind, SyntaxNode};
+
+/// Returns the syntax tree of the Hurl file `text`, parsed as `hurl_file`.
+pub fn build(text: &str, hurl_file: &HurlFile) -> SyntaxNode {
+    let mut builder = Builder {
+        offsets: Offsets::new(text),
+        stack: vec![],
+        root: None,
+    };
+    builder.visit_hurl_file(hurl_file);
+    let mut root = builder.root.expect("root node");
+    root.range = Some(0..text.len());
+    let mut root = normalize(root, 0..text.len());
+    if root.children.is_empty() && !text.is_empty() {
+        root.children.push(token(0..text.len()));
+    }
+    root
+}
+
+/// A node being built: its range is unknown until all its children are built.
+struct Node {
+    kind: SyntaxKind,
+    range: Option<Range<usize>>,
+    /// Byte length of a node without source info, placed before its next sibling when it has no
+    /// previous sibling.
+    len: usize,
+    children: Vec<Node>,
+}
+
+struct Builder {
+    offsets: Offsets,
+    stack: Vec<Node>,
+    root: Option<Node>,
+}
+
+impl Builder {
+    fn enter(&mut self, kind: SyntaxKind) {
+        self.stack.push(Node {
+            kind,
+            range: None,
+            len: 0,
+            children: vec![],
+        });
+    }
+
+    /// Ends the current node, with an optional explicit `source_info`.
+    fn exit(&mut self, source_info: Option<&SourceInfo>) {
+        let mut node = self.stack.pop().expect("node to exit");
+        infer_ranges(&mut node.children);
+        node.children
+            .sort_by_key(|n| n.range.as_ref().map(|r| r.start));
+        let explicit = source_info.map(|s| self.range(s));
+        node.range = node
+            .children
+            .iter()
+            .filter_map(|n| n.range.clone())
+            .chain(explicit)
+            .reduce(|a, b| a.start.min(b.start)..a.end.max(b.end));
+        match self.stack.last_mut() {
+            Some(parent) => parent.children.push(node),
+            None => self.root = Some(node),
+        }
+    }
+
+    fn leaf(&mut self, kind: SyntaxKind, source_info: &SourceInfo) {
+        self.enter(kind);
+        self.exit(Some(source_info));
+    }
+
+    /// Adds a leaf without source info, of byte length `len`.
+    fn inferred_leaf(&mut self, kind: SyntaxKind, len: usize) {
+        self.enter(kind);
+        self.stack.last_mut().unwrap().len = len;
+        self.exit(None);
+    }
+
+    fn range(&self, source_info: &SourceInfo) -> Range<usize> {
+        let start = self.offsets.offset(&source_info.start);
+        let end = self.offsets.offset(&source_info.end);
+        start..end.max(start)
+    }
+}
+
+/// Sets the range of the `nodes` without range from their siblings.
+fn infer_ranges(nodes: &mut [Node]) {
+    for i in 0..nodes.len() {
+        if nodes[i].range.is_some() || !nodes[i].children.is_empty() {
+            continue;
+        }
+        let prev = nodes[..i].iter().rev().find_map(|n| n.range.clone());
+        let next = nodes[i + 1..].iter().find_map(|n| n.range.clone());
+        nodes[i].range = match (prev, next) {
+            (Some(prev), Some(next)) => Some(prev.end..next.start.max(prev.end)),
+            (None, Some(next)) => Some(next.start.saturating_sub(nodes[i].len)..next.start),
+            (Some(prev), None) => Some(prev.end..prev.end + nodes[i].len),
+            (None, None) => None,
+        };
+    }
+}
+
+/// Converts a built `node` to a syntax node, clamped to `bounds`, with contiguous children.
+fn normalize(node: Node, bounds: Range<usize>) -> SyntaxNode {
+    let range = node.range.unwrap_or(bounds.start..bounds.start);
+    let start = range.start.clamp(bounds.start, bounds.end);
+    let end = range.end.clamp(start, bounds.end);
+    let mut children = vec![];
+    let mut cursor = start;
+    for child in node.children {
+        let Some(range) = child.range.clone() else {
+            continue;
+        };
+        let child_start = range.start.max(cursor);
+        let child_end = range.end.min(end);
+        if child_start >= child_end {
+            continue;
+        }
+        if child_start > cursor {
+            children.push(token(cursor..child_start));
+        }
+        children.push(normalize(child, child_start..child_end));
+        cursor = child_end;
+    }
+    if !children.is_empty() && cursor < end {
+        children.push(token(cursor..end));
+    }
+    SyntaxNode {
+        kind: node.kind,
+        range: start..end,
+        children,
+    }
+}
+
+fn token(range: Range<usize>) -> SyntaxNode {
+    SyntaxNode {
+        kind: SyntaxKind::Token,
+        range,
+        children: vec![],
+    }
+}
+
+impl Visitor for Builder {
+    fn visit_hurl_file(&mut self, hurl_file: &HurlFile) {
+        self.enter(SyntaxKind::HurlFile);
+        visit::walk_hurl_file(self, hurl_file);
+        self.exit(None);
+    }
+
+    fn visit_entry(&mut self, entry: &Entry) {
+        self.enter(SyntaxKind::Entry);
+        visit::walk_entry(self, entry);
+        self.exit(None);
+    }
+
+    fn visit_request(&mut self, request: &Request) {
+        self.enter(SyntaxKind::Request);
+        visit::walk_request(self, request);
+        self.exit(Some(&request.source_info));
+    }
+
+    fn visit_response(&mut self, response: &Response) {
+        self.enter(SyntaxKind::Response);
+        visit::walk_response(self, response);
+        self.exit(Some(&response.source_info));
+    }
+
+    fn visit_method(&mut self, method: &Method) {
+        self.inferred_leaf(SyntaxKind::Method, method.0.len());
+    }
+
+    fn visit_version(&mut self, version: &Version) {
+        self.leaf(SyntaxKind::Version, &version.source_info);
+    }
+
+    fn visit_status(&mut self, status: &Status) {
+        self.leaf(SyntaxKind::Status, &status.source_info);
+    }
+
+    fn visit_key_value(&mut self, key_value: &KeyValue) {
+        self.enter(SyntaxKind::KeyValue);
+        visit::walk_key_value(self, key_value);
+        self.exit(None);
+    }
+
+    fn visit_section(&mut self, section: &Section) {
+        self.enter(SyntaxKind::Section);
+        self.leaf(SyntaxKind::SectionName, &section.source_info);
+        visit::walk_section(self, section);
+        self.exit(None);
+    }
+
+    fn visit_cookie(&mut self, cookie: &Cookie) {
+        self.enter(SyntaxKind::Cookie);
+        visit::walk_cookie(self, cookie);
+        self.exit(None);
+    }
+
+    fn visit_multipart_param(&mut self, multipart_param: &MultipartParam) {
+        self.enter(SyntaxKind::MultipartParam);
+        visit::walk_multipart_param(self, multipart_param);
+        self.exit(None);
+    }
+
+    fn visit_file_param(&mut self, file_param: &FileParam) {
+        self.enter(SyntaxKind::FileParam);
+        visit::walk_file_param(self, file_param);
+        self.exit(None);
+    }
+
+    fn visit_capture(&mut self, capture: &Capture) {
+        self.enter(SyntaxKind::Capture);
+        visit::walk_capture(self, capture);
+        self.exit(None);
+    }
+
+    fn visit_assert(&mut self, assert: &Assert) {
+        self.enter(SyntaxKind::Assert);
+        visit::walk_assert(self, assert);
+        self.exit(None);
+    }
+
+    fn visit_query(&mut self, query: &Query) {
+        self.enter(SyntaxKind::Query);
+        visit::walk_query(self, query);
+        self.exit(Some(&query.source_info));
+    }
+
+    fn visit_predicate(&mut self, predicate: &Predicate) {
+        self.enter(SyntaxKind::Predicate);
+        visit::walk_predicate(self, predicate);
+        self.exit(None);
+    }
+
+    fn visit_predicate_func(&mut self, predicate_func: &PredicateFunc) {
+        self.enter(SyntaxKind::PredicateFunc);
+        visit::walk_predicate_func(self, predicate_func);
+        self.exit(Some(&predicate_func.source_info));
+    }
+
+    fn visit_body(&mut self, body: &Body) {
+        self.enter(SyntaxKind::Body);
+        visit::walk_body(self, body);
+        // The bytes have no source info: they span from the leading space to the line terminator.
+        let children = &mut self.stack.last_mut().unwrap().children;
+        if let Some(i) = children.iter().position(|n| n.kind == SyntaxKind::Bytes) {
+            let start = children[i - 1].range.as_ref().map(|r| r.end);
+            let end = children
+                .get(i + 1)
+                .and_then(|n| n.range.as_ref().map(|r| r.start));
+            if let (Some(start), Some(end)) = (start, end) {
+                children[i].range = Some(start..end.max(start));
+            }
+        }
+        self.exit(None);
+    }
+
+    fn visit_bytes(&mut self, bytes: &Bytes) {
+        self.enter(SyntaxKind::Bytes);
+        visit::walk_bytes(self, bytes);
+        self.exit(None);
+    }
+
+    fn visit_entry_option(&mut self, entry_option: &EntryOption) {
+        // The option name has no source info, and is visited between the two first spaces.
+        self.enter(SyntaxKind::EntryOption);
+        for line_terminator in &entry_option.line_terminators {
+            self.visit_line_terminator(line_terminator);
+        }
+        self.visit_whitespace(&entry_option.space0);
+        self.inferred_leaf(SyntaxKind::OptionName, entry_option.kind.name().len());
+        self.visit_whitespace(&entry_option.space1);
+        self.visit_whitespace(&entry_option.space2);
+        self.visit_option_kind(&entry_option.kind);
+        self.visit_line_terminator(&entry_option.line_terminator0);
+        self.exit(None);
+    }
+
+    fn visit_variable_definition(&mut self, variable_definition: &VariableDefinition) {
+        self.enter(SyntaxKind::VariableDefinition);
+        self.inferred_leaf(SyntaxKind::VariableName, variable_definition.name.len());
+        visit::walk_variable_definition(self, variable_definition);
+        self.exit(None);
+    }
+
+    fn visit_filter(&mut self, filter: &Filter) {
+        self.enter(SyntaxKind::Filter);
+        visit::walk_filter(self, filter);
+        self.exit(Some(&filter.source_info));
+    }
+
+    fn visit_template(&mut self, template: &Template) {
+        self.enter(SyntaxKind::Template);
+        visit::walk_template(self, template);
+        self.exit(Some(&template.source_info));
+    }
+
+    fn visit_expr(&mut self, expr: &Expr) {
+        self.enter(SyntaxKind::Expr);
+        visit::walk_expr(self, expr);
+        self.exit(None);
+    }
+
+    fn visit_variable(&mut self, variable: &Variable) {
+        self.leaf(SyntaxKind::Variable, &variable.source_info);
+    }
+
+    fn visit_encoded_string(&mut self, encoded_string: &EncodedString) {
+        self.leaf(SyntaxKind::EncodedString, &encoded_string.source_info);
+    }
+
+    fn visit_filename(&mut self, filename: &Filename) {
+        self.leaf(SyntaxKind::Filename, &filename.source_info);
+    }
+
+    fn visit_line_terminator(&mut self, line_terminator: &LineTerminator) {
+        self.enter(SyntaxKind::LineTerminator);
+        visit::walk_line_terminator(self, line_terminator);
+        self.exit(None);
+    }
+
+    fn visit_comment(&mut self, comment: &Comment) {
+        self.inferred_leaf(SyntaxKind::Comment, comment.value.len() + 1);
+    }
+
+    fn visit_whitespace(&mut self, whitespace: &Whitespace) {
+        self.leaf(SyntaxKind::Whitespace, &whitespace.source_info);
+    }
+}
+
+/// Converts parser positions to byte offsets.
+///
+/// Columns of the parser count chars, except combining characters.
+struct Offsets {
+    /// Byte offset of each column, for each line.
+    lines: Vec<Vec<usize>>,
+}
+
+impl Offsets {
+    fn new(text: &str) -> Offsets {
+        let mut lines = vec![vec![]];
+        for (i, c) in text.char_indices() {
+            let line = lines.last_mut().unwrap();
+            if !is_combining_character(c) {
+                line.push(i);
+            }
+            if c == '\n' {
+                lines.push(vec![]);
+            }
+        }
+        lines.last_mut().unwrap().push(text.len());
+        Offsets { lines }
+    }
+
+    fn offset(&self, pos: &Pos) -> usize {
+        let Some(line) = self.lines.get(pos.line.saturating_sub(1)) else {
+            return self
+                .lines
+                .last()
+                .and_then(|l| l.last())
+                .copied()
+                .unwrap_or(0);
+        };
+        match line.get(pos.column.saturating_sub(1)) {
+            Some(offset) => *offset,
+            None => line.last().copied().unwrap_or(0),
+        }
+    }
+}
+
+fn is_combining_character(c: char) -> bool {
+    c > '\u{0300}' && c < '\u{036F}'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser;
+
+    /// Builds the syntax tree of `text`, and checks that it's printed back byte for byte, with
+    /// contiguous children.
+    fn build_lossless(text: &str) -> SyntaxNode {
+        let hurl_file = parser::parse_hurl_file(text).unwrap();
+        let root = build(text, &hurl_file);
+        assert_eq!(root.range, 0..text.len());
+        check_ranges(&root);
+        let printed = root.tokens().map(|t| &text[t.range()]).collect::<String>();
+        assert_eq!(printed, text);
+        root
+    }
+
+    fn check_ranges(node: &SyntaxNode) {
+        let mut start = node.range.start;
+        for child in &node.children {
+            assert_eq!(child.range.start, start, "{child:?} in {:?}", node.kind);
+            assert!(child.range.start < child.range.end);
+            check_ranges(child);
+            start = child.range.end;
+        }
+        if !node.children.is_empty() {
+            assert_eq!(start, node.range.end);
+        }
+    }
+
+    fn texts<'a>(text: &'a str, root: &SyntaxNode, kind: SyntaxKind) -> Vec<&'a str> {
+        root.descendants()
+            .filter(|n| n.kind == kind)
+            .map(|n| &text[n.range()])
+            .collect()
+    }
+
+    #[test]
+    fn test_multibyte_chars() {
+        let text = "GET http://localhost/é/{{id}}\nX-Name: 日本語 😀 {{name}} # 注释 😀\nHTTP 200\n[Asserts]\nbody == \"é😀{{name}}\"\n";
+        let root = build_lossless(text);
+        assert_eq!(
+            texts(text, &root, SyntaxKind::Variable),
+            ["id", "name", "name"]
+        );
+        assert_eq!(texts(text, &root, SyntaxKind::Comment), ["# 注释 😀"]);
+        assert_eq!(
+            texts(text, &root, SyntaxKind::Template),
+            [
+                "http://localhost/é/{{id}}",
+                "日本語 😀 {{name}}",
+                "\"é😀{{name}}\""
+            ]
+        );
+    }
+
+    #[test]
+    fn test_combining_chars() {
+        // `e` followed by a combining acute accent, `n` by a combining tilde.
+        let text = "GET http://localhost/{{id}}\nX-Name: cafe\u{301} {{id}}\nHTTP 200\n[Asserts]\nbody == \"n\u{303}{{id}}\" # cafe\u{301}\n";
+        let root = build_lossless(text);
+        assert_eq!(texts(text, &root, SyntaxKind::Variable), ["id", "id", "id"]);
+        assert_eq!(texts(text, &root, SyntaxKind::Comment), ["# cafe\u{301}"]);
+        assert_eq!(
+            texts(text, &root, SyntaxKind::Template),
+            [
+                "http://localhost/{{id}}",
+                "cafe\u{301} {{id}}",
+                "\"n\u{303}{{id}}\""
+            ]
+        );
+    }
+
+    #[test]
+    fn test_crlf() {
+        let text = "# Users\r\nGET http://localhost/{{id}}\r\nAccept: */*\r\n\r\nHTTP 200\r\n[Asserts]\r\nstatus == 200\r\n";
+        let root = build_lossless(text);
+        assert_eq!(texts(text, &root, SyntaxKind::Comment), ["# Users"]);
+        assert_eq!(texts(text, &root, SyntaxKind::Variable), ["id"]);
+        assert_eq!(texts(text, &root, SyntaxKind::Status), ["200"]);
+        assert_eq!(texts(text, &root, SyntaxKind::SectionName), ["[Asserts]"]);
+    }
+
+    #[test]
+    fn test_comments_and_whitespaces() {
+        let text = "  # first  \n\nGET http://localhost   # url  \n\t# between\t\nHTTP 200   \n  [Asserts]  # section\nstatus == 200 \n\n# last";
+        let root = build_lossless(text);
+        assert_eq!(
+            texts(text, &root, SyntaxKind::Comment),
+            ["# first  ", "# url  ", "# between\t", "# section", "# last"]
+        );
+        assert_eq!(texts(text, &root, SyntaxKind::Method), ["GET"]);
+        assert_eq!(texts(text, &root, SyntaxKind::SectionName), ["[Asserts]"]);
+    }
+
+    #[test]
+    fn test_templates() {
+        let text = r#"POST {{url}}/{{path}}?q={{q}}
+X-Header: {{header}}
+[Options]
+variable: v={{option}}
+[QueryStringParams]
+param: {{param}}
+[FormParams]
+form: a{{form}}b
+[Cookies]
+cookie: {{cookie}}
+[BasicAuth]
+user: {{password}}
+{"key {{key}}": "{{string}}", "value": {{expression}}, "list": [{{item}}]}
+HTTP 200
+[Captures]
+capture: jsonpath "{{jsonpath}}" regex "{{regex}}"
+[Asserts]
+header "{{name}}" == "{{expected}}"
+jsonpath "$.a" == {{number}}
+jsonpath "$.b" contains "{{contains}}"
+```
+multiline {{multiline}}
+```
+
+POST http://localhost
+[MultipartFormData]
+field: {{field}}
+`oneline {{oneline}}`
+
+POST http://localhost
+```graphql
+query { user(id: "{{graphql}}") { name } }
+variables {"id": "{{graphql_variable}}"}
+```
+"#;
+        let root = build_lossless(text);
+        assert_eq!(
+            texts(text, &root, SyntaxKind::Variable),
+            [
+                "url",
+                "path",
+                "q",
+                "header",
+                "option",
+                "param",
+                "form",
+                "cookie",
+                "password",
+                "key",
+                "string",
+                "expression",
+                "item",
+                "jsonpath",
+                "regex",
+                "name",
+                "expected",
+                "number",
+                "contains",
+                "multiline",
+                "field",
+                "oneline",
+                "graphql",
+                "graphql_variable",
+            ]
+        );
+        assert_eq!(
+            texts(text, &root, SyntaxKind::Expr),
+            texts(text, &root, SyntaxKind::Variable)
+        );
+    }
+
+    #[test]
+    fn test_integration_files() {
+        let dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../../integration");
+        let mut count = 0;
+        for tests in ["tests_ok", "tests_failed"] {
+            for entry in std::fs::read_dir(dir.join(tests)).unwrap() {
+                let path = entry.unwrap().path();
+                if path.extension().and_then(|e| e.to_str()) != Some("hurl") {
+                    continue;
+                }
+                let Ok(text) = std::fs::read_to_string(&path) else {
+                    continue;
+                };
+                if parser::parse_hurl_file(&text).is_err() {
+                    continue;
+                }
+                let root = build_lossless(&text);
+                // Misplaced nodes would have the text of their neighbours.
+                for name in texts(&text, &root, SyntaxKind::Variable) {
+                    assert!(
+                        name.chars()
+                            .all(|c| c.is_alphanumeric() || c == '_' || c == '-'),
+                        "{}: variable <{name}>",
+                        path.display()
+                    );
+                }
+                for comment in texts(&text, &root, SyntaxKind::Comment) {
+                    assert!(
+                        comment.starts_with('#') && !comment.contains('\n'),
+                        "{}: comment <{comment}>",
+                        path.display()
+                    );
+                }
+                for method in texts(&text, &root, SyntaxKind::Method) {
+                    assert!(
+                        method.chars().all(|c| c.is_ascii_uppercase()),
+                        "{}: method <{method}>",
+                        path.display()
+                    );
+                }
+                count += 1;
+            }
+        }
+        assert!(count > 0);
+    }
+}
@@ -0,0 +1,120 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2023 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::ops::Range;
+
+use crate::cst::SyntaxNode;
+
+/// Records edits of the nodes of a syntax tree, and prints the edited text.
+///
+/// The text outside of the edited nodes is copied byte for byte. When two edits overlap, the
+/// first one in source order is applied and the other one is ignored.
+pub struct Editor<'a> {
+    text: &'a str,
+    edits: Vec<(Range<usize>, String)>,
+}
+
+impl<'a> Editor<'a> {
+    pub(crate) fn new(text: &'a str) -> Editor<'a> {
+        Editor {
+            text,
+            edits: vec![],
+        }
+    }
+
+    /// Replaces the text of `node` by `text`.
+    pub fn replace(&mut self, node: &SyntaxNode, text: &str) {
+        self.edits.push((node.range(), text.to_string()));
+    }
+
+    /// Inserts `text` before `node`.
+    pub fn insert_before(&mut self, node: &SyntaxNode, text: &str) {
+        let start = node.range().start;
+        self.edits.push((start..start, text.to_string()));
+    }
+
+    /// Inserts `text` after `node`.
+    pub fn insert_after(&mut self, node: &SyntaxNode, text: &str) {
+        let end = node.range().end;
+        self.edits.push((end..end, text.to_string()));
+    }
+
+    /// Deletes `node`.
+    pub fn delete(&mut self, node: &SyntaxNode) {
+        self.edits.push((node.range(), String::new()));
+    }
+
+    /// Returns true if no edit has been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.edits.is_empty()
+    }
+
+    /// Returns the edited text.
+    pub fn finish(mut self) -> String {
+        self.edits
+            .sort_by_key(|(range, _)| (range.start, range.end));
+        let mut output = String::with_capacity(self.text.len());
+        let mut cursor = 0;
+        for (range, text) in &self.edits {
+            if range.start < cursor {
+                continue;
+            }
+            output.push_str(&self.text[cursor..range.start]);
+            output.push_str(text);
+            cursor = range.end;
+        }
+        output.push_str(&self.text[cursor..]);
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cst::{Cst, SyntaxKind};
+
+    #[test]
+    fn test_edit() {
+        let text = "GET http://localhost   # comment\nx-id: {{id}}\nHTTP 200\n";
+        let cst = Cst::parse(text).unwrap();
+        let find = |kind| cst.root().descendants().find(|n| n.kind() == kind).unwrap();
+
+        let editor = cst.editor();
+        assert!(editor.is_empty());
+        assert_eq!(editor.finish(), text);
+
+        let mut editor = cst.editor();
+        editor.replace(find(SyntaxKind::Method), "POST");
+        editor.delete(find(SyntaxKind::Comment));
+        editor.insert_before(find(SyntaxKind::Variable), " ");
+        editor.insert_after(find(SyntaxKind::Variable), " ");
+        assert_eq!(
+            editor.finish(),
+            "POST http://localhost   \nx-id: {{ id }}\nHTTP 200\n"
+        );
+    }
+
+    #[test]
+    fn test_overlapping_edits() {
+        let text = "GET http://localhost/{{id}}\n";
+        let cst = Cst::parse(text).unwrap();
+        let find = |kind| cst.root().descendants().find(|n| n.kind() == kind).unwrap();
+        let mut editor = cst.editor();
+        editor.replace(find(SyntaxKind::Variable), "user_id");
+        editor.replace(find(SyntaxKind::Template), "http://localhost");
+        assert_eq!(editor.finish(), "GET http://localhost\n");
+    }
+}
@@ -0,0 +1,272 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2023 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+//! Lossless concrete syntax tree (CST) of a Hurl file.
+//!
+//! Every byte of the input belongs to exactly one token (a node without children): the text of
+//! a node is the slice of the input at its byte range, and the input can be printed back from
+//! the tree. Bytes that have no dedicated node in the AST (separators, quotes, escaped strings
+//! etc...) are grouped in [`SyntaxKind::Token`] nodes.
+//!
+//! Rewrites are done with an [`Editor`], that only touches the edited nodes and copies the rest of
+//! the input verbatim:
+//!
+//! ```
+//! use hurl_core::cst::{Cst, SyntaxKind};
+//!
+//! let cst = Cst::parse("GET {{host}}/users\n# Users list\nHTTP 200\n").unwrap();
+//! let mut editor = cst.editor();
+//! for node in cst.root().descendants() {
+//!     if node.kind() == SyntaxKind::Variable && cst.node_text(node) == "host" {
+//!         editor.replace(node, "server");
+//!     }
+//! }
+//! assert_eq!(editor.finish(), "GET {{server}}/users\n# Users list\nHTTP 200\n");
+//! ```
+use std::ops::Range;
+
+use crate::parser;
+
+pub use self::editor::Editor;
+
+mod builder;
+mod editor;
+
+/// Kind of a CST node.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SyntaxKind {
+    HurlFile,
+    Entry,
+    Request,
+    Response,
+    Method,
+    Version,
+    Status,
+    KeyValue,
+    Section,
+    /// Name of a section, with its brackets: `[Asserts]`.
+    SectionName,
+    Cookie,
+    MultipartParam,
+    FileParam,
+    Capture,
+    Assert,
+    Query,
+    Predicate,
+    PredicateFunc,
+    Body,
+    /// Value of a body: JSON, XML, multiline string, file etc...
+    Bytes,
+    Filter,
+    EntryOption,
+    OptionName,
+    VariableDefinition,
+    /// Name of a variable defined by a `variable` option.
+    VariableName,
+    Template,
+    Expr,
+    /// Variable used in an expression `{{name}}`.
+    Variable,
+    EncodedString,
+    Filename,
+    LineTerminator,
+    Comment,
+    Whitespace,
+    /// Any other text: punctuation, keywords, literals etc...
+    Token,
+}
+
+/// A node of the syntax tree, covering the byte `range` of the input.
+///
+/// Children of a node are contiguous and cover the whole range of their parent.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SyntaxNode {
+    kind: SyntaxKind,
+    range: Range<usize>,
+    children: Vec<SyntaxNode>,
+}
+
+impl SyntaxNode {
+    pub fn kind(&self) -> SyntaxKind {
+        self.kind
+    }
+
+    /// Returns the byte range of this node in the input.
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+
+    pub fn children(&self) -> &[SyntaxNode] {
+        &self.children
+    }
+
+    /// Returns true if this node is a token, i.e. has no children.
+    pub fn is_token(&self) -> bool {
+        self.children.is_empty()
+    }
+
+    /// Returns this node and all its descendants, in source order (pre-order traversal).
+    pub fn descendants(&self) -> impl Iterator<Item = &SyntaxNode> {
+        let mut stack = vec![self];
+        std::iter::from_fn(move || {
+            let node = stack.pop()?;
+            stack.extend(node.children.iter().rev());
+            Some(node)
+        })
+    }
+
+    /// Returns the tokens of this node, in source order.
+    pub fn tokens(&self) -> impl Iterator<Item = &SyntaxNode> {
+        self.descendants().filter(|node| node.is_token())
+    }
+}
+
+/// A Hurl file and its lossless syntax tree.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cst {
+    text: String,
+    root: SyntaxNode,
+}
+
+impl Cst {
+    /// Parses a Hurl file `text` into a syntax tree.
+    pub fn parse(text: &str) -> Result<Cst, parser::Error> {
+        let hurl_file = parser::parse_hurl_file(text)?;
+        let root = builder::build(text, &hurl_file);
+        Ok(Cst {
+            text: text.to_string(),
+            root,
+        })
+    }
+
+    /// Returns the text this tree has been parsed from.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the root node, of kind [`SyntaxKind::HurlFile`].
+    pub fn root(&self) -> &SyntaxNode {
+        &self.root
+    }
+
+    /// Returns the text of a `node` of this tree.
+    pub fn node_text(&self, node: &SyntaxNode) -> &str {
+        &self.text[node.range()]
+    }
+
+    /// Returns a new editor, to rewrite nodes of this tree.
+    pub fn editor(&self) -> Editor<'_> {
+        Editor::new(&self.text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEXT: &str = r#"# Get users
+GET {{host}}/users   # with a comment
+[Options]
+variable: id=1
+HTTP 200
+[Asserts]
+jsonpath "$[0].name" == "Bob" # Bob is first
+body contains "é😀"
+
+POST {{host}}/users/{{ id }}
+{
+  "name": "Bill",
+  "tags": ["a", "b"]
+}
+HTTP 201
+"#;
+
+    /// Checks that the children of each node are contiguous and cover their parent.
+    fn check_ranges(node: &SyntaxNode) {
+        if node.is_token() {
+            return;
+        }
+        let mut start = node.range.start;
+        for child in &node.children {
+            assert_eq!(child.range.start, start, "{child:?} in {:?}", node.kind);
+            assert!(child.range.start < child.range.end);
+            check_ranges(child);
+            start = child.range.end;
+        }
+        assert_eq!(start, node.range.end);
+    }
+
+    fn texts(cst: &Cst, kind: SyntaxKind) -> Vec<&str> {
+        cst.root()
+            .descendants()
+            .filter(|n| n.kind() == kind)
+            .map(|n| cst.node_text(n))
+            .collect()
+    }
+
+    #[test]
+    fn test_lossless() {
+        let cst = Cst::parse(TEXT).unwrap();
+        assert_eq!(cst.root().range(), 0..TEXT.len());
+        check_ranges(cst.root());
+        let printed = cst
+            .root()
+            .tokens()
+            .map(|t| cst.node_text(t))
+            .collect::<String>();
+        assert_eq!(printed, TEXT);
+    }
+
+    #[test]
+    fn test_nodes() {
+        let cst = Cst::parse(TEXT).unwrap();
+        assert_eq!(texts(&cst, SyntaxKind::Entry).len(), 2);
+        assert_eq!(texts(&cst, SyntaxKind::Method), vec!["GET", "POST"]);
+        assert_eq!(
+            texts(&cst, SyntaxKind::Comment),
+            vec!["# Get users", "# with a comment", "# Bob is first"]
+        );
+        assert_eq!(
+            texts(&cst, SyntaxKind::SectionName),
+            vec!["[Options]", "[Asserts]"]
+        );
+        assert_eq!(texts(&cst, SyntaxKind::OptionName), vec!["variable"]);
+        assert_eq!(texts(&cst, SyntaxKind::VariableName), vec!["id"]);
+        assert_eq!(
+            texts(&cst, SyntaxKind::Variable),
+            vec!["host", "host", "id"]
+        );
+        assert_eq!(texts(&cst, SyntaxKind::Status), vec!["200", "201"]);
+        assert_eq!(
+            texts(&cst, SyntaxKind::Bytes),
+            vec!["{\n  \"name\": \"Bill\",\n  \"tags\": [\"a\", \"b\"]\n}"]
+        );
+    }
+
+    #[test]
+    fn test_empty() {
+        let cst = Cst::parse("").unwrap();
+        assert_eq!(cst.root().range(), 0..0);
+        assert!(cst.root().is_token());
+
+        let text = "\n# Nothing to do\n";
+        let cst = Cst::parse(text).unwrap();
+        check_ranges(cst.root());
+        assert_eq!(texts(&cst, SyntaxKind::Comment), vec!["# Nothing to do"]);
+    }
+}
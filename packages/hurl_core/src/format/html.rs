@@ -16,17 +16,49 @@
  *
  */
 use crate::ast::*;
+use std::collections::HashMap;
 use std::fmt::Display;
 
+/// Options of the HTML rendering of a Hurl file.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct HtmlOptions {
+    /// If true, a complete HTML body with inline styling is returned. Otherwise, a `<pre>` HTML
+    /// tag is returned, without styling.
+    pub standalone: bool,
+    /// If true, lines are numbered, with anchors `#l<line>`.
+    pub line_numbers: bool,
+    /// If true, entries have anchors `#e<index>`, captures anchors `#e<index>-<name>`, and the
+    /// variables used in templates link to their last capture.
+    pub links: bool,
+    /// Theme of the inline styling, when `standalone` is true.
+    pub theme: HtmlTheme,
+}
+
+/// Color theme of the Hurl CSS.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum HtmlTheme {
+    #[default]
+    Light,
+    Dark,
+}
+
 /// Returns an HTML string of the Hurl file `hurl_file`.
-///
-/// If `standalone` is true, a complete HTML body with inline styling is returned.
-/// Otherwise, a `<pre>` HTML tag is returned, without styling.
-pub fn format(hurl_file: &HurlFile, standalone: bool) -> String {
+pub fn format(hurl_file: &HurlFile, options: &HtmlOptions) -> String {
     let mut fmt = HtmlFormatter::new();
-    let body = fmt.fmt_hurl_file(hurl_file);
-    if standalone {
-        let css = include_str!("hurl.css");
+    fmt.links = options.links;
+    let mut body = fmt.fmt_hurl_file(hurl_file).to_string();
+    if options.line_numbers {
+        let count = HtmlFormatter::text()
+            .fmt_hurl_file(hurl_file)
+            .lines()
+            .count();
+        body = format!(
+            "<div class=\"hurl-source\">{}{body}</div>",
+            numbered_lines(count)
+        );
+    }
+    if options.standalone {
+        let css = hurl_css(options.theme);
         format!(
             r#"<!DOCTYPE html>
 <html>
@@ -44,26 +76,69 @@ pub fn format(hurl_file: &HurlFile, standalone: bool) -> String {
 "#
         )
     } else {
-        body.to_string()
+        body
     }
 }
 
-pub fn hurl_css() -> String {
-    include_str!("hurl.css").to_string()
+/// Returns the CSS stylesheet of the Hurl HTML rendering, for this `theme`.
+pub fn hurl_css(theme: HtmlTheme) -> String {
+    let css = include_str!("hurl.css");
+    match theme {
+        HtmlTheme::Light => css.to_string(),
+        HtmlTheme::Dark => format!("{css}\n{}", include_str!("hurl_dark.css")),
+    }
+}
+
+/// Returns the numbers of `count` lines, with anchors.
+fn numbered_lines(count: usize) -> String {
+    let mut lines = "<pre class=\"line-numbers\"><code>".to_string();
+    for line in 1..=count {
+        lines.push_str(&format!("<a id=\"l{line}\" href=\"#l{line}\">{line}</a>\n"));
+    }
+    lines.push_str("</code></pre>");
+    lines
 }
 
 /// A HTML formatter for Hurl content.
-struct HtmlFormatter {
+pub(super) struct HtmlFormatter {
     buffer: String,
+    /// If false, the plain Hurl text is formatted, without HTML markup and escaping.
+    markup: bool,
+    /// If true, entries and captures have anchors, and variables link to their captures.
+    links: bool,
+    /// Index of the current entry, starting at 1.
+    entry_index: usize,
+    /// Anchors of the last capture of each variable.
+    captures: HashMap<String, String>,
 }
 
 impl HtmlFormatter {
     pub fn new() -> Self {
         HtmlFormatter {
             buffer: String::new(),
+            markup: true,
+            links: false,
+            entry_index: 0,
+            captures: HashMap::new(),
         }
     }
 
+    /// Returns a formatter of plain Hurl text, to render parts of a Hurl file in other formats.
+    pub(super) fn text() -> Self {
+        HtmlFormatter {
+            buffer: String::new(),
+            markup: false,
+            links: false,
+            entry_index: 0,
+            captures: HashMap::new(),
+        }
+    }
+
+    /// Returns the formatted text, and clears the buffer.
+    pub(super) fn take(&mut self) -> String {
+        std::mem::take(&mut self.buffer)
+    }
+
     pub fn fmt_hurl_file(&mut self, hurl_file: &HurlFile) -> &str {
         self.buffer.clear();
         self.fmt_pre_open("language-hurl");
@@ -74,26 +149,52 @@ impl HtmlFormatter {
     }
 
     fn fmt_pre_open(&mut self, class: &str) {
+        if !self.markup {
+            return;
+        }
         self.buffer.push_str("<pre><code class=\"");
         self.buffer.push_str(class);
         self.buffer.push_str("\">");
     }
 
     fn fmt_pre_close(&mut self) {
+        if !self.markup {
+            return;
+        }
         self.buffer.push_str("</code></pre>");
     }
 
     fn fmt_span_open(&mut self, class: &str) {
+        if !self.markup {
+            return;
+        }
         self.buffer.push_str("<span class=\"");
         self.buffer.push_str(class);
         self.buffer.push_str("\">");
     }
 
     fn fmt_span_close(&mut self) {
+        if !self.markup {
+            return;
+        }
         self.buffer.push_str("</span>");
     }
 
+    /// Opens a span with an anchor `id`, if links are enabled.
+    fn fmt_span_open_with_id(&mut self, class: &str, id: &str) {
+        if !self.markup || !self.links {
+            self.fmt_span_open(class);
+            return;
+        }
+        self.buffer
+            .push_str(&format!("<span class=\"{class}\" id=\"{id}\">"));
+    }
+
     fn fmt_span(&mut self, class: &str, value: &str) {
+        if !self.markup {
+            self.buffer.push_str(value);
+            return;
+        }
         self.buffer.push_str("<span class=\"");
         self.buffer.push_str(class);
         self.buffer.push_str("\">");
@@ -102,7 +203,9 @@ impl HtmlFormatter {
     }
 
     fn fmt_entry(&mut self, entry: &Entry) {
-        self.fmt_span_open("hurl-entry");
+        self.entry_index += 1;
+        let id = format!("e{}", self.entry_index);
+        self.fmt_span_open_with_id("hurl-entry", &id);
         self.fmt_request(&entry.request);
         if let Some(response) = &entry.response {
             self.fmt_response(response);
@@ -117,7 +220,7 @@ impl HtmlFormatter {
         self.fmt_space(&request.space0);
         self.fmt_method(&request.method);
         self.fmt_space(&request.space1);
-        let url = escape_xml(&request.url.to_encoded_string());
+        let url = self.template(&request.url);
         self.fmt_span("url", &url);
         self.fmt_span_close();
         self.fmt_lt(&request.line_terminator0);
@@ -212,26 +315,40 @@ impl HtmlFormatter {
         self.fmt_space(&option.space1);
         self.buffer.push(':');
         self.fmt_space(&option.space2);
-        match &option.kind {
+        self.fmt_option_kind(&option.kind);
+        self.fmt_span_close();
+        self.fmt_lt(&option.line_terminator0);
+    }
+
+    pub(super) fn fmt_option_kind(&mut self, kind: &OptionKind) {
+        match kind {
+            OptionKind::AwsSigV4(value) => self.fmt_string(value),
             OptionKind::CaCertificate(filename) => self.fmt_filename(filename),
             OptionKind::ClientCert(filename) => self.fmt_filename(filename),
             OptionKind::ClientKey(filename) => self.fmt_filename(filename),
             OptionKind::Compressed(value) => self.fmt_bool(*value),
             OptionKind::ConnectTo(value) => self.fmt_string(value),
+            OptionKind::Digest(value) => self.fmt_bool(*value),
             OptionKind::Insecure(value) => self.fmt_bool(*value),
             OptionKind::FollowLocation(value) => self.fmt_bool(*value),
+            OptionKind::Http10(value) => self.fmt_bool(*value),
+            OptionKind::Http11(value) => self.fmt_bool(*value),
+            OptionKind::Http2(value) => self.fmt_bool(*value),
+            OptionKind::Http2PriorKnowledge(value) => self.fmt_bool(*value),
+            OptionKind::Http3(value) => self.fmt_bool(*value),
             OptionKind::MaxRedirect(value) => self.fmt_number(value),
+            OptionKind::Negotiate(value) => self.fmt_bool(*value),
+            OptionKind::Ntlm(value) => self.fmt_bool(*value),
             OptionKind::PathAsIs(value) => self.fmt_bool(*value),
             OptionKind::Proxy(value) => self.fmt_string(value),
             OptionKind::Resolve(value) => self.fmt_string(value),
             OptionKind::Retry(value) => self.fmt_retry(value),
             OptionKind::RetryInterval(value) => self.fmt_number(value),
+            OptionKind::UnixSocket(filename) => self.fmt_filename(filename),
             OptionKind::Variable(value) => self.fmt_variable_definition(value),
             OptionKind::Verbose(value) => self.fmt_bool(*value),
             OptionKind::VeryVerbose(value) => self.fmt_bool(*value),
         };
-        self.fmt_span_close();
-        self.fmt_lt(&option.line_terminator0);
     }
 
     fn fmt_retry(&mut self, retry: &Retry) {
@@ -249,7 +366,7 @@ impl HtmlFormatter {
         self.fmt_variable_value(&option.value);
     }
 
-    fn fmt_variable_value(&mut self, option: &VariableValue) {
+    pub(super) fn fmt_variable_value(&mut self, option: &VariableValue) {
         match option {
             VariableValue::Null => self.fmt_span("null", "null"),
             VariableValue::Bool(v) => self.fmt_bool(*v),
@@ -279,7 +396,7 @@ impl HtmlFormatter {
         self.fmt_lt(&param.line_terminator0);
     }
 
-    fn fmt_file_value(&mut self, file_value: &FileValue) {
+    pub(super) fn fmt_file_value(&mut self, file_value: &FileValue) {
         self.buffer.push_str("file,");
         self.fmt_space(&file_value.space0);
         self.fmt_filename(&file_value.filename);
@@ -315,7 +432,10 @@ impl HtmlFormatter {
         self.fmt_lts(&capture.line_terminators);
         self.fmt_span_open("line");
         self.fmt_space(&capture.space0);
-        self.fmt_span("name", &capture.name.value);
+        let id = format!("e{}-{}", self.entry_index, capture.name.value);
+        self.fmt_span_open_with_id("name", &id);
+        self.buffer.push_str(&capture.name.value);
+        self.fmt_span_close();
         self.fmt_space(&capture.space1);
         self.buffer.push(':');
         self.fmt_space(&capture.space2);
@@ -324,11 +444,18 @@ impl HtmlFormatter {
             self.fmt_space(space);
             self.fmt_filter(filter);
         }
+        if let Some(default) = &capture.default {
+            self.fmt_space(&default.space0);
+            self.fmt_span("default", "default");
+            self.fmt_space(&default.space1);
+            self.fmt_variable_value(&default.value);
+        }
         self.fmt_span_close();
         self.fmt_lt(&capture.line_terminator0);
+        self.captures.insert(capture.name.value.clone(), id);
     }
 
-    fn fmt_query(&mut self, query: &Query) {
+    pub(super) fn fmt_query(&mut self, query: &Query) {
         self.fmt_query_value(&query.value);
     }
 
@@ -439,7 +566,7 @@ impl HtmlFormatter {
         self.fmt_lt(&assert.line_terminator0);
     }
 
-    fn fmt_predicate(&mut self, predicate: &Predicate) {
+    pub(super) fn fmt_predicate(&mut self, predicate: &Predicate) {
         if predicate.not {
             self.fmt_span("not", "not");
             self.fmt_space(&predicate.space0);
@@ -452,9 +579,12 @@ impl HtmlFormatter {
     }
 
     fn fmt_predicate_func_value(&mut self, value: &PredicateFuncValue) {
-        self.fmt_span_open("predicate-type");
-        self.buffer.push_str(&encode_html(value.name()));
-        self.fmt_span_close();
+        let name = if self.markup {
+            encode_html(value.name())
+        } else {
+            value.name()
+        };
+        self.fmt_span("predicate-type", &name);
 
         match value {
             PredicateFuncValue::Equal { space0, value, .. } => {
@@ -597,7 +727,7 @@ impl HtmlFormatter {
         let lang = multiline_string.lang();
         if as_body {
             let body = format!("```{lang}\n{multiline_string}```");
-            let body = format_multilines(&body);
+            let body = self.multilines(&body);
             self.fmt_span("multiline", &body);
         } else {
             let head = format!("```{lang}");
@@ -606,11 +736,13 @@ impl HtmlFormatter {
             self.fmt_span_close();
             self.buffer.push('\n');
             let tail = format!("{multiline_string}```");
-            let tail = format_multilines(&tail);
+            let tail = self.multilines(&tail);
             self.fmt_span("multiline", &tail);
             // As we have added a span close, we must remove one to have the right number
             // of span. The current span line will add a closing span.
-            pop_str(&mut self.buffer, "</span>")
+            if self.markup {
+                pop_str(&mut self.buffer, "</span>")
+            }
         }
     }
 
@@ -621,7 +753,7 @@ impl HtmlFormatter {
         self.fmt_lt(&body.line_terminator0);
     }
 
-    fn fmt_bytes(&mut self, bytes: &Bytes) {
+    pub(super) fn fmt_bytes(&mut self, bytes: &Bytes) {
         match bytes {
             Bytes::Base64(value) => {
                 self.fmt_span_open("line");
@@ -662,16 +794,16 @@ impl HtmlFormatter {
     }
 
     fn fmt_xml(&mut self, value: &str) {
-        let xml = format_multilines(value);
+        let xml = self.multilines(value);
         self.fmt_span("xml", &xml);
     }
 
     fn fmt_json_value(&mut self, json_value: &JsonValue) {
-        let json = format_multilines(&json_value.encoded());
+        let json = self.multilines(&json_value.encoded());
         self.fmt_span("json", &json);
     }
 
-    fn fmt_space(&mut self, space: &Whitespace) {
+    pub(super) fn fmt_space(&mut self, space: &Whitespace) {
         let Whitespace { value, .. } = space;
         if !value.is_empty() {
             self.buffer.push_str(value);
@@ -687,7 +819,7 @@ impl HtmlFormatter {
     }
 
     fn fmt_comment(&mut self, comment: &Comment) {
-        let comment = format!("#{}", escape_xml(&comment.value));
+        let comment = format!("#{}", self.escape(&comment.value));
         self.fmt_span("comment", &comment);
     }
 
@@ -721,17 +853,52 @@ impl HtmlFormatter {
         self.fmt_span("regex", &regex);
     }
 
-    fn fmt_template(&mut self, template: &Template) {
-        let s = template.to_encoded_string();
-        self.fmt_string(&escape_xml(&s));
+    pub(super) fn fmt_template(&mut self, template: &Template) {
+        let s = self.template(template);
+        self.fmt_string(&s);
+    }
+
+    /// Returns the escaped text of `template`, where variables link to their captures if links
+    /// are enabled.
+    fn template(&self, template: &Template) -> String {
+        if !self.markup || !self.links {
+            return self.escape(&template.to_encoded_string());
+        }
+        let mut s = String::new();
+        if let Some(d) = template.delimiter {
+            s.push(d);
+        }
+        for element in template.elements.iter() {
+            match element {
+                TemplateElement::String { encoded, .. } => s.push_str(&escape_xml(encoded)),
+                TemplateElement::Expression(expr) => {
+                    s.push_str(&format!("{{{{{}}}}}", self.variable(expr)));
+                }
+            }
+        }
+        if let Some(d) = template.delimiter {
+            s.push(d);
+        }
+        s
     }
 
     fn fmt_expr(&mut self, expr: &Expr) {
-        let expr = format!("{{{{{}}}}}", &expr.to_string());
+        let expr = format!("{{{{{}}}}}", self.variable(expr));
         self.fmt_span("expr", &expr);
     }
 
-    fn fmt_filter(&mut self, filter: &Filter) {
+    /// Returns the variable of `expr`, linked to its capture if links are enabled.
+    fn variable(&self, expr: &Expr) -> String {
+        let name = &expr.variable.name;
+        match self.captures.get(name) {
+            Some(id) if self.markup && self.links => {
+                format!("<a class=\"variable\" href=\"#{id}\">{name}</a>")
+            }
+            _ => name.to_string(),
+        }
+    }
+
+    pub(super) fn fmt_filter(&mut self, filter: &Filter) {
         self.fmt_filter_value(&filter.value);
     }
 
@@ -795,10 +962,26 @@ impl HtmlFormatter {
         };
     }
 
+    fn escape(&self, s: &str) -> String {
+        if self.markup {
+            escape_xml(s)
+        } else {
+            s.to_string()
+        }
+    }
+
+    fn multilines(&self, s: &str) -> String {
+        if self.markup {
+            format_multilines(s)
+        } else {
+            s.to_string()
+        }
+    }
+
     fn fmt_lts(&mut self, line_terminators: &[LineTerminator]) {
         for line_terminator in line_terminators {
             self.fmt_span_open("line");
-            if line_terminator.newline.value.is_empty() {
+            if self.markup && line_terminator.newline.value.is_empty() {
                 self.buffer.push_str("<br>");
             }
             self.fmt_span_close();
@@ -1038,6 +1221,44 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_links() {
+        let content = "GET http://localhost/{{id}}\nHTTP 200\n[Captures]\nid: header \"Id\"\n\nGET http://localhost/{{id}}\n";
+        let hurl_file = crate::parser::parse_hurl_file(content).unwrap();
+        let options = HtmlOptions {
+            links: true,
+            ..HtmlOptions::default()
+        };
+        assert_eq!(
+            format(&hurl_file, &options),
+            "<pre><code class=\"language-hurl\">\
+                <span class=\"hurl-entry\" id=\"e1\"><span class=\"request\"><span class=\"line\"><span class=\"method\">GET</span> <span class=\"url\">http://localhost/{{id}}</span></span>\n</span>\
+                <span class=\"response\"><span class=\"line\"><span class=\"version\">HTTP</span> <span class=\"number\">200</span></span>\n\
+                <span class=\"line\"><span class=\"section-header\">[Captures]</span></span>\n\
+                <span class=\"line\"><span class=\"name\" id=\"e1-id\">id</span>: <span class=\"query-type\">header</span> <span class=\"string\">\"Id\"</span></span>\n</span></span>\
+                <span class=\"hurl-entry\" id=\"e2\"><span class=\"request\"><span class=\"line\"></span>\n\
+                <span class=\"line\"><span class=\"method\">GET</span> <span class=\"url\">http://localhost/{{<a class=\"variable\" href=\"#e1-id\">id</a>}}</span></span>\n</span></span>\
+            </code></pre>"
+        );
+    }
+
+    #[test]
+    fn test_line_numbers() {
+        let hurl_file = crate::parser::parse_hurl_file("GET http://localhost\nHTTP 200\n").unwrap();
+        let options = HtmlOptions {
+            line_numbers: true,
+            ..HtmlOptions::default()
+        };
+        let html = format(&hurl_file, &options);
+        assert!(html.starts_with(
+            "<div class=\"hurl-source\"><pre class=\"line-numbers\"><code>\
+                <a id=\"l1\" href=\"#l1\">1</a>\n\
+                <a id=\"l2\" href=\"#l2\">2</a>\n\
+            </code></pre><pre><code class=\"language-hurl\">"
+        ));
+        assert!(html.ends_with("</code></pre></div>"));
+    }
+
     #[test]
     fn test_xml_escape() {
         assert_eq!(escape_xml("hello"), "hello");
@@ -0,0 +1,352 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2023 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use crate::ast::*;
+use crate::format::html::HtmlFormatter;
+
+/// Returns a Markdown documentation of the Hurl file `hurl_file`.
+///
+/// Each entry is rendered as a section, titled by its method and URL, and described by the
+/// comments preceding the request. Hurl values (templates, queries, predicates etc...) are
+/// written as in the Hurl file, formatted by the HTML formatter without markup.
+pub fn format(hurl_file: &HurlFile) -> String {
+    let mut fmt = MarkdownFormatter::new();
+    hurl_file.entries.iter().for_each(|e| fmt.fmt_entry(e));
+    let mut markdown = fmt.blocks.join("\n\n");
+    markdown.push('\n');
+    markdown
+}
+
+/// A Markdown formatter for Hurl content, as a list of blocks (headings, paragraphs, tables
+/// etc...) separated by blank lines.
+struct MarkdownFormatter {
+    blocks: Vec<String>,
+}
+
+impl MarkdownFormatter {
+    fn new() -> Self {
+        MarkdownFormatter { blocks: vec![] }
+    }
+
+    fn fmt_entry(&mut self, entry: &Entry) {
+        self.fmt_request(&entry.request);
+        if let Some(response) = &entry.response {
+            self.fmt_response(response);
+        }
+    }
+
+    fn fmt_request(&mut self, request: &Request) {
+        let url = text(|fmt| fmt.fmt_template(&request.url));
+        self.blocks
+            .push(format!("## {} {}", request.method, code_span(&url)));
+        self.fmt_description(&request.line_terminators);
+
+        let headers = kv_rows(&request.headers);
+        self.fmt_table("Headers", ["Name", "Value"], headers);
+        let params = kv_rows(&request.querystring_params());
+        self.fmt_table("Query parameters", ["Name", "Value"], params);
+        let params = kv_rows(&request.form_params());
+        self.fmt_table("Form parameters", ["Name", "Value"], params);
+        let params = request
+            .multipart_form_data()
+            .iter()
+            .map(|param| match param {
+                MultipartParam::Param(kv) => kv_row(kv),
+                MultipartParam::FileParam(param) => [
+                    code_span(&param.key.encoded),
+                    code_span(&text(|fmt| fmt.fmt_file_value(&param.value))),
+                ],
+            })
+            .collect();
+        self.fmt_table("Multipart form data", ["Name", "Value"], params);
+        let cookies = request
+            .cookies()
+            .iter()
+            .map(|cookie| {
+                [
+                    code_span(&cookie.name.value),
+                    code_span(&text(|fmt| fmt.fmt_template(&cookie.value))),
+                ]
+            })
+            .collect();
+        self.fmt_table("Cookies", ["Name", "Value"], cookies);
+        if let Some(basic_auth) = request.basic_auth() {
+            // The password is not documented.
+            self.blocks.push(format!(
+                "Basic authentication user: {}",
+                code_span(&basic_auth.key.encoded)
+            ));
+        }
+        let options = request
+            .options()
+            .iter()
+            .map(|option| {
+                [
+                    code_span(option.kind.name()),
+                    code_span(&text(|fmt| fmt.fmt_option_kind(&option.kind))),
+                ]
+            })
+            .collect();
+        self.fmt_table("Options", ["Name", "Value"], options);
+        if let Some(body) = &request.body {
+            self.blocks.push("Request body:".to_string());
+            self.fmt_bytes(&body.value);
+        }
+    }
+
+    fn fmt_response(&mut self, response: &Response) {
+        self.blocks.push("### Response".to_string());
+        self.fmt_description(&response.line_terminators);
+        let status = format!("{} {}", response.version.value, response.status.value);
+        self.blocks.push(format!("Status: {}", code_span(&status)));
+
+        let headers = kv_rows(&response.headers);
+        self.fmt_table("Headers", ["Name", "Value"], headers);
+        let captures = response
+            .captures()
+            .iter()
+            .map(|capture| {
+                let query = text(|fmt| {
+                    fmt.fmt_query(&capture.query);
+                    for (space, filter) in capture.filters.iter() {
+                        fmt.fmt_space(space);
+                        fmt.fmt_filter(filter);
+                    }
+                });
+                [code_span(&capture.name.value), code_span(&query)]
+            })
+            .collect();
+        self.fmt_table("Captures", ["Name", "Query"], captures);
+        let asserts = response
+            .asserts()
+            .iter()
+            .map(|assert| {
+                let value = text(|fmt| {
+                    fmt.fmt_query(&assert.query);
+                    for (space, filter) in assert.filters.iter() {
+                        fmt.fmt_space(space);
+                        fmt.fmt_filter(filter);
+                    }
+                    fmt.fmt_space(&assert.space1);
+                    fmt.fmt_predicate(&assert.predicate);
+                });
+                let mut comments = comments(&assert.line_terminators);
+                comments.extend(assert.line_terminator0.comment.as_ref().map(comment_text));
+                let value = code_span(&value.replace('\n', " "));
+                if comments.is_empty() {
+                    format!("- {value}")
+                } else {
+                    format!("- {value}: {}", comments.join(" "))
+                }
+            })
+            .collect::<Vec<_>>();
+        if !asserts.is_empty() {
+            self.blocks.push("Asserts:".to_string());
+            self.blocks.push(asserts.join("\n"));
+        }
+        if let Some(body) = &response.body {
+            self.blocks.push("Response body:".to_string());
+            self.fmt_bytes(&body.value);
+        }
+    }
+
+    /// Adds the comments of `line_terminators` as a paragraph.
+    fn fmt_description(&mut self, line_terminators: &[LineTerminator]) {
+        let comments = comments(line_terminators);
+        if !comments.is_empty() {
+            self.blocks.push(comments.join("\n"));
+        }
+    }
+
+    /// Adds a table titled `title`, if it has some `rows`.
+    fn fmt_table(&mut self, title: &str, header: [&str; 2], rows: Vec<[String; 2]>) {
+        if rows.is_empty() {
+            return;
+        }
+        self.blocks.push(format!("{title}:"));
+        let mut table = format!("| {} | {} |\n| --- | --- |", header[0], header[1]);
+        for [name, value] in rows {
+            let row = format!(
+                "\n| {} | {} |",
+                name.replace('|', "\\|"),
+                value.replace('|', "\\|")
+            );
+            table.push_str(&row);
+        }
+        self.blocks.push(table);
+    }
+
+    /// Adds a body as a fenced code block, with the language of its content.
+    fn fmt_bytes(&mut self, bytes: &Bytes) {
+        let (lang, content) = match bytes {
+            Bytes::Json(value) => ("json", value.encoded()),
+            Bytes::Xml(value) => ("xml", value.clone()),
+            Bytes::MultilineString(value @ MultilineString::OneLineText(_)) => {
+                ("", value.to_string())
+            }
+            Bytes::MultilineString(value) => {
+                // The content of a multiline string ends with a newline.
+                let content = value.to_string();
+                let content = content.strip_suffix('\n').unwrap_or(&content).to_string();
+                (value.lang(), content)
+            }
+            _ => ("", text(|fmt| fmt.fmt_bytes(bytes))),
+        };
+        self.blocks.push(code_block(lang, &content));
+    }
+}
+
+/// Returns the Hurl text written by `f` with a text formatter.
+fn text<F>(f: F) -> String
+where
+    F: FnOnce(&mut HtmlFormatter),
+{
+    let mut fmt = HtmlFormatter::text();
+    f(&mut fmt);
+    fmt.take()
+}
+
+fn kv_row(kv: &KeyValue) -> [String; 2] {
+    [
+        code_span(&kv.key.encoded),
+        code_span(&text(|fmt| fmt.fmt_template(&kv.value))),
+    ]
+}
+
+fn kv_rows(kvs: &[KeyValue]) -> Vec<[String; 2]> {
+    kvs.iter().map(kv_row).collect()
+}
+
+/// Returns the comments of `line_terminators`, without their leading `#`.
+fn comments(line_terminators: &[LineTerminator]) -> Vec<String> {
+    line_terminators
+        .iter()
+        .filter_map(|lt| lt.comment.as_ref())
+        .map(comment_text)
+        .filter(|c| !c.is_empty())
+        .collect()
+}
+
+fn comment_text(comment: &Comment) -> String {
+    let value = comment.value.strip_prefix(' ').unwrap_or(&comment.value);
+    value.trim_end().to_string()
+}
+
+/// Returns `s` as an inline code, delimited by enough backticks.
+fn code_span(s: &str) -> String {
+    let fence = "`".repeat(longest_backtick_run(s) + 1);
+    if s.starts_with('`') || s.ends_with('`') {
+        format!("{fence} {s} {fence}")
+    } else {
+        format!("{fence}{s}{fence}")
+    }
+}
+
+/// Returns `content` as a fenced code block, delimited by enough backticks.
+fn code_block(lang: &str, content: &str) -> String {
+    let fence = "`".repeat(longest_backtick_run(content).max(2) + 1);
+    format!("{fence}{lang}\n{content}\n{fence}")
+}
+
+fn longest_backtick_run(s: &str) -> usize {
+    s.split(|c| c != '`').map(str::len).max().unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser;
+
+    #[test]
+    fn test_format() {
+        let content = r#"# Create a user.
+# The id is captured.
+POST {{host}}/users?debug=true
+Content-Type: application/json
+[Options]
+variable: name=Bob
+{"name": "{{name}}"}
+HTTP 201
+[Captures]
+id: jsonpath "$.id" toInt
+[Asserts]
+# The name is kept.
+jsonpath "$.name" == "{{name}}"
+header "Location" matches /users\/\d+/ # Location of the user
+"#;
+        let hurl_file = parser::parse_hurl_file(content).unwrap();
+        assert_eq!(
+            format(&hurl_file),
+            r#"## POST `{{host}}/users?debug=true`
+
+Create a user.
+The id is captured.
+
+Headers:
+
+| Name | Value |
+| --- | --- |
+| `Content-Type` | `application/json` |
+
+Options:
+
+| Name | Value |
+| --- | --- |
+| `variable` | `name=Bob` |
+
+Request body:
+
+```json
+{"name": "{{name}}"}
+```
+
+### Response
+
+Status: `HTTP 201`
+
+Captures:
+
+| Name | Query |
+| --- | --- |
+| `id` | `jsonpath "$.id" toInt` |
+
+Asserts:
+
+- `jsonpath "$.name" == "{{name}}"`: The name is kept.
+- `header "Location" matches /users\/\d+/`: Location of the user
+"#
+        );
+    }
+
+    #[test]
+    fn test_body() {
+        let content = "POST http://localhost\n```graphql\n{ me }\n```\nHTTP 200\n```\nHello\n```\n\nGET http://localhost\nHTTP 200\nfile,data.bin;\n";
+        let hurl_file = parser::parse_hurl_file(content).unwrap();
+        assert_eq!(
+            format(&hurl_file),
+            "## POST `http://localhost`\n\nRequest body:\n\n```graphql\n{ me }\n```\n\n### Response\n\nStatus: `HTTP 200`\n\nResponse body:\n\n```\nHello\n```\n\n## GET `http://localhost`\n\n### Response\n\nStatus: `HTTP 200`\n\nResponse body:\n\n```\nfile,data.bin;\n```\n"
+        );
+    }
+
+    #[test]
+    fn test_code() {
+        assert_eq!(code_span("a|b"), "`a|b`");
+        assert_eq!(code_span("a`b"), "``a`b``");
+        assert_eq!(code_span("`a`"), "`` `a` ``");
+        assert_eq!(code_block("", "a ```b```"), "````\na ```b```\n````");
+    }
+}
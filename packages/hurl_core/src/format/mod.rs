@@ -17,6 +17,8 @@
  */
 
 pub use self::html::format as format_html;
-pub use self::html::hurl_css;
+pub use self::html::{hurl_css, HtmlOptions, HtmlTheme};
+pub use self::markdown::format as format_markdown;
 
 mod html;
+mod markdown;
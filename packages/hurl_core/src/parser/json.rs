@@ -142,7 +142,24 @@ fn escape_char(reader: &mut Reader) -> ParseResult<'static, char> {
 }
 
 fn unicode(reader: &mut Reader) -> ParseResult<'static, char> {
-    let v = hex_value(reader)?;
+    let mut v = hex_value(reader)?;
+    // Characters outside the Basic Multilingual Plane are escaped as a UTF-16 surrogate pair.
+    // Unpaired surrogates are replaced by U+FFFD, as they're not valid chars.
+    if (0xD800..0xDC00).contains(&v) {
+        let save = reader.state.clone();
+        v = match try_literal("\\u", reader) {
+            Ok(_) => match hex_value(reader)? {
+                low @ 0xDC00..=0xDFFF => 0x10000 + ((v - 0xD800) << 10) + (low - 0xDC00),
+                _ => {
+                    reader.state = save;
+                    0xFFFD
+                }
+            },
+            Err(_) => 0xFFFD,
+        };
+    } else if (0xDC00..0xE000).contains(&v) {
+        v = 0xFFFD;
+    }
     let c = match std::char::from_u32(v) {
         None => {
             return Err(error::Error {
@@ -161,7 +178,7 @@ fn hex_value(reader: &mut Reader) -> ParseResult<'static, u32> {
     let digit2 = nonrecover(hex_digit, reader)?;
     let digit3 = nonrecover(hex_digit, reader)?;
     let digit4 = nonrecover(hex_digit, reader)?;
-    let value = digit1 * (16 ^ 3) + digit2 * (16 ^ 2) + digit3 * 16 + digit4;
+    let value = digit1 * 0x1000 + digit2 * 0x100 + digit3 * 0x10 + digit4;
     Ok(value)
 }
 
@@ -595,6 +612,22 @@ mod tests {
         let mut reader = Reader::new("000a");
         assert_eq!(unicode(&mut reader).unwrap(), '\n');
         assert_eq!(reader.state.cursor, 4);
+
+        let mut reader = Reader::new("00e9");
+        assert_eq!(unicode(&mut reader).unwrap(), 'é');
+        assert_eq!(reader.state.cursor, 4);
+
+        let mut reader = Reader::new("D834\\uDD1E");
+        assert_eq!(unicode(&mut reader).unwrap(), '𝄞');
+        assert_eq!(reader.state.cursor, 10);
+
+        let mut reader = Reader::new("D834\\u0041");
+        assert_eq!(unicode(&mut reader).unwrap(), '\u{FFFD}');
+        assert_eq!(reader.state.cursor, 4);
+
+        let mut reader = Reader::new("DD1E");
+        assert_eq!(unicode(&mut reader).unwrap(), '\u{FFFD}');
+        assert_eq!(reader.state.cursor, 4);
     }
 
     #[test]
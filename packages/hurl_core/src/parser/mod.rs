@@ -23,6 +23,13 @@ pub fn parse_hurl_file(s: &str) -> ParseResult<'static, HurlFile> {
     parsers::hurl_file(&mut reader)
 }
 
+/// Parses a Hurl file without stopping at the first error: returns the entries that have been
+/// parsed, partially for the invalid ones, and all the errors (empty if the file is valid).
+pub fn parse_hurl_file_with_recovery(s: &str) -> (HurlFile, Vec<Error>) {
+    let mut reader = Reader::new(s);
+    recovery::hurl_file(&mut reader)
+}
+
 pub use self::error::{Error, ParseError};
 pub use self::json::{
     boolean_value as parse_json_boolean, null_value as parse_json_null,
@@ -48,6 +55,7 @@ mod predicate_value;
 mod primitives;
 mod query;
 mod reader;
+mod recovery;
 mod sections;
 mod string;
 mod template;
@@ -36,7 +36,7 @@ pub fn hurl_file(reader: &mut Reader) -> ParseResult<'static, HurlFile> {
     })
 }
 
-fn entry(reader: &mut Reader) -> ParseResult<'static, Entry> {
+pub fn entry(reader: &mut Reader) -> ParseResult<'static, Entry> {
     let req = request(reader)?;
     let resp = optional(response, reader)?;
     Ok(Entry {
@@ -92,7 +92,7 @@ fn request(reader: &mut Reader) -> ParseResult<'static, Request> {
     })
 }
 
-fn response(reader: &mut Reader) -> ParseResult<'static, Response> {
+pub fn response(reader: &mut Reader) -> ParseResult<'static, Response> {
     let start = reader.state.clone();
     let line_terminators = optional_line_terminators(reader)?;
     let space0 = zero_or_more_spaces(reader)?;
@@ -216,7 +216,7 @@ fn status(reader: &mut Reader) -> ParseResult<'static, Status> {
     })
 }
 
-fn body(reader: &mut Reader) -> ParseResult<'static, Body> {
+pub fn body(reader: &mut Reader) -> ParseResult<'static, Body> {
     //  let start = reader.state.clone();
     let line_terminators = optional_line_terminators(reader)?;
     let space0 = zero_or_more_spaces(reader)?;
@@ -0,0 +1,372 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2023 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+//! Parsing of a Hurl file that doesn't stop at the first error.
+//!
+//! When an entry can't be parsed, the entry is parsed again up to the line of the error, to keep
+//! its valid part. The parser then resynchronizes on the next section, response or entry line,
+//! and goes on parsing the following sections of this entry, and the following entries.
+//!
+//! The first error reported is always the one returned by [`crate::parser::parse_hurl_file`].
+use crate::ast::{Entry, HurlFile, LineTerminator, Section};
+use crate::parser::combinators::optional;
+use crate::parser::error::{Error, ParseError};
+use crate::parser::parsers::{body, entry, response};
+use crate::parser::primitives::{eof, optional_line_terminators};
+use crate::parser::reader::{Reader, ReaderState};
+use crate::parser::sections::{request_section, response_section};
+
+/// Parses a Hurl file from this `reader`, returning the entries that have been parsed (fully or
+/// partially), and all the errors.
+pub fn hurl_file(reader: &mut Reader) -> (HurlFile, Vec<Error>) {
+    let mut entries = vec![];
+    let mut errors = vec![];
+    let line_terminators = loop {
+        let start = reader.state.clone();
+        let error = match entry(reader) {
+            Ok(entry) => {
+                entries.push(entry);
+                continue;
+            }
+            Err(e) if !e.recoverable => e,
+            Err(_) => {
+                // No more entries: only line terminators are expected, until the end of file.
+                reader.state = start.clone();
+                match end_of_file(reader) {
+                    Ok(line_terminators) => break line_terminators,
+                    Err(e) => e,
+                }
+            }
+        };
+        reader.state = start.clone();
+        let entry = partial_entry(reader, &start, error.pos.line);
+        errors.push(error.clone());
+        match entry {
+            None => skip_to(reader, error.pos.line, is_entry_start),
+            Some(mut entry) => {
+                continue_entry(reader, &mut entry, error.pos.line, &mut errors);
+                entries.push(entry);
+            }
+        }
+        if reader.is_eof() {
+            break vec![];
+        }
+    };
+    let hurl_file = HurlFile {
+        entries,
+        line_terminators,
+    };
+    (hurl_file, errors)
+}
+
+fn end_of_file(reader: &mut Reader) -> Result<Vec<LineTerminator>, Error> {
+    let line_terminators = optional_line_terminators(reader)?;
+    eof(reader)?;
+    Ok(line_terminators)
+}
+
+/// Parses the entry starting at `start`, ignoring the lines from `line`.
+fn partial_entry(reader: &Reader, start: &ReaderState, line: usize) -> Option<Entry> {
+    let mut end = reader.clone();
+    end.state = start.clone();
+    while !end.is_eof() && end.state.pos.line < line {
+        end.read();
+    }
+    let mut truncated = Reader {
+        buffer: reader.buffer[..end.state.cursor].to_vec(),
+        state: start.clone(),
+    };
+    entry(&mut truncated).ok()
+}
+
+/// Parses the sections, response and body of an `entry` following an error at `line`, until the
+/// next entry.
+fn continue_entry(reader: &mut Reader, entry: &mut Entry, line: usize, errors: &mut Vec<Error>) {
+    let mut line = line;
+    loop {
+        skip_to(reader, line, is_boundary);
+        let current = current_line(reader);
+        if reader.is_eof() || is_entry_start(&current) {
+            return;
+        }
+        let start = reader.state.clone();
+        let result = if is_response_start(&current) {
+            if entry.response.is_some() {
+                return;
+            }
+            response(reader).map(|response| entry.response = Some(response))
+        } else {
+            section(reader, entry)
+        };
+        if let Err(error) = result {
+            line = error.pos.line;
+            errors.push(error);
+            reader.state = start;
+            continue;
+        }
+
+        // A body can follow the sections.
+        let target = match &mut entry.response {
+            Some(response) => &mut response.body,
+            None => &mut entry.request.body,
+        };
+        if target.is_none() && !at_boundary(reader) {
+            if let Ok(Some(body)) = optional(body, reader) {
+                *target = Some(body);
+            }
+        }
+        if !at_boundary(reader) {
+            match unexpected(reader) {
+                Some(error) => {
+                    line = error.pos.line;
+                    errors.push(error);
+                    continue;
+                }
+                None => return,
+            }
+        }
+        // Resumes on the current line.
+        line = reader.state.pos.line - 1;
+    }
+}
+
+fn at_boundary(reader: &Reader) -> bool {
+    reader.is_eof() || is_boundary(&current_line(reader))
+}
+
+/// Returns the error reported by the parser at the current position of the `reader`, as if a new
+/// entry was starting there, or `None` if an entry starts there indeed.
+fn unexpected(reader: &mut Reader) -> Option<Error> {
+    let start = reader.state.clone();
+    let error = match entry(reader) {
+        Ok(_) => None,
+        Err(e) if !e.recoverable => Some(e),
+        Err(_) => {
+            reader.state = start.clone();
+            end_of_file(reader).err()
+        }
+    };
+    reader.state = start;
+    error
+}
+
+/// Parses a section, and adds it to the request or the response of this `entry`.
+fn section(reader: &mut Reader, entry: &mut Entry) -> Result<(), Error> {
+    match &mut entry.response {
+        Some(response) => {
+            let section = response_section(reader)?;
+            response.sections.push(section);
+        }
+        None => {
+            let section = request_section(reader)?;
+            check_duplicate(&entry.request.sections, &section)?;
+            entry.request.sections.push(section);
+        }
+    }
+    Ok(())
+}
+
+fn check_duplicate(sections: &[Section], section: &Section) -> Result<(), Error> {
+    if sections.iter().any(|s| s.name() == section.name()) {
+        return Err(Error {
+            pos: section.source_info.start.clone(),
+            recoverable: false,
+            inner: ParseError::DuplicateSection,
+        });
+    }
+    Ok(())
+}
+
+/// Moves the `reader` to the start of the first line after `line` accepted by `predicate`, or to
+/// the end of the file.
+fn skip_to(reader: &mut Reader, line: usize, predicate: fn(&str) -> bool) {
+    let line = line.max(reader.state.pos.line - 1);
+    while !reader.is_eof() && reader.state.pos.line <= line {
+        reader.read();
+    }
+    while !reader.is_eof() && !predicate(&current_line(reader)) {
+        while let Some(c) = reader.read() {
+            if c == '\n' {
+                break;
+            }
+        }
+    }
+}
+
+fn current_line(reader: &Reader) -> String {
+    reader.buffer[reader.state.cursor..]
+        .iter()
+        .take_while(|c| **c != '\n')
+        .collect()
+}
+
+/// Returns true if this `line` starts a request: an uppercase method followed by spaces.
+fn is_entry_start(line: &str) -> bool {
+    let line = line.trim_start();
+    let method = line
+        .chars()
+        .take_while(|c| c.is_ascii_alphabetic())
+        .collect::<String>();
+    !method.is_empty()
+        && method != "HTTP"
+        && method.to_uppercase() == method
+        && line[method.len()..].starts_with([' ', '\t'])
+}
+
+fn is_response_start(line: &str) -> bool {
+    let line = line.trim_start();
+    line.starts_with("HTTP ") || line.starts_with("HTTP/")
+}
+
+fn is_section_start(line: &str) -> bool {
+    let Some(name) = line.trim_start().strip_prefix('[') else {
+        return false;
+    };
+    let len = name
+        .chars()
+        .take_while(|c| c.is_ascii_alphanumeric())
+        .count();
+    len > 0 && name[len..].starts_with(']')
+}
+
+fn is_boundary(line: &str) -> bool {
+    is_entry_start(line) || is_response_start(line) || is_section_start(line)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::Pos;
+    use crate::parser::parse_hurl_file;
+
+    fn parse(s: &str) -> (HurlFile, Vec<Error>) {
+        let mut reader = Reader::new(s);
+        hurl_file(&mut reader)
+    }
+
+    #[test]
+    fn test_valid_file() {
+        let s = "GET http://localhost\nHTTP 200\n[Asserts]\nstatus == 200\n\n# end\n";
+        let (hurl_file, errors) = parse(s);
+        assert!(errors.is_empty());
+        assert_eq!(hurl_file, parse_hurl_file(s).unwrap());
+    }
+
+    #[test]
+    fn test_entries() {
+        let s = r#"GET http://localhost/1
+HTTP 200
+
+GET http://localhost/2
+x-header
+HTTP 200
+
+GET http://localhost/3
+HTTP 200
+[Asserts]
+status === 200
+
+GET http://localhost/4
+HTTP 200
+"#;
+        let (hurl_file, errors) = parse(s);
+        assert_eq!(errors[0], parse_hurl_file(s).unwrap_err());
+        assert_eq!(
+            errors.iter().map(|e| e.pos.clone()).collect::<Vec<_>>(),
+            vec![
+                Pos { line: 5, column: 1 },
+                Pos {
+                    line: 11,
+                    column: 10
+                }
+            ]
+        );
+        assert_eq!(
+            hurl_file
+                .entries
+                .iter()
+                .map(|e| e.request.url.to_string())
+                .collect::<Vec<_>>(),
+            vec![
+                "http://localhost/1",
+                "http://localhost/2",
+                "http://localhost/3",
+                "http://localhost/4"
+            ]
+        );
+        // The valid part of an entry is kept.
+        let entry = &hurl_file.entries[2];
+        assert_eq!(entry.response.as_ref().unwrap().sections.len(), 1);
+    }
+
+    #[test]
+    fn test_sections() {
+        let s = r#"GET http://localhost
+[Foo]
+a: 1
+[QueryStringParams]
+b: 2
+[QueryStringParams]
+HTTP 200
+[Captures]
+id jsonpath "$.id"
+[Asserts]
+status == 200
+[Bar]
+"#;
+        let (hurl_file, errors) = parse(s);
+        assert_eq!(
+            errors.iter().map(|e| e.inner.clone()).collect::<Vec<_>>(),
+            vec![
+                ParseError::RequestSectionName {
+                    name: "Foo".to_string()
+                },
+                ParseError::DuplicateSection,
+                ParseError::Method {
+                    name: "id".to_string()
+                },
+                ParseError::ResponseSectionName {
+                    name: "Bar".to_string()
+                },
+            ]
+        );
+        let entry = &hurl_file.entries[0];
+        assert_eq!(entry.request.sections.len(), 1);
+        let response = entry.response.as_ref().unwrap();
+        assert_eq!(
+            response
+                .sections
+                .iter()
+                .map(|s| s.name())
+                .collect::<Vec<_>>(),
+            vec!["Captures", "Asserts"]
+        );
+    }
+
+    #[test]
+    fn test_garbage() {
+        let s = "foo\nbar\nGET http://localhost\nHTTP 200\nbaz\n";
+        let (hurl_file, errors) = parse(s);
+        assert_eq!(errors[0], parse_hurl_file(s).unwrap_err());
+        assert_eq!(
+            errors.iter().map(|e| e.pos.clone()).collect::<Vec<_>>(),
+            vec![Pos { line: 1, column: 1 }, Pos { line: 5, column: 1 }]
+        );
+        assert_eq!(hurl_file.entries.len(), 1);
+    }
+}
@@ -0,0 +1,219 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2023 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use lsp_types::{
+    CompletionItem, CompletionItemKind, CompletionTextEdit, Documentation, MarkupContent,
+    MarkupKind, Position, Range, TextEdit,
+};
+
+use crate::document::{self, Context};
+use crate::keywords::{self, Keyword, Kind};
+
+/// Returns the completions of the Hurl document `text` at `position`.
+pub fn completions(text: &str, position: Position) -> Vec<CompletionItem> {
+    let prefix = document::line_prefix(text, position);
+
+    // Variables, in an unclosed `{{`.
+    if let Some(start) = prefix.rfind("{{") {
+        if !prefix[start..].contains("}}") {
+            return variables(text);
+        }
+    }
+
+    // Section names, on a line starting with `[`.
+    let trimmed = prefix.trim_start();
+    if trimmed.starts_with('[') && !trimmed.contains(']') {
+        let start = Position::new(position.line, position.character - utf16_len(trimmed));
+        let previous = match position.line {
+            0 => Context::None,
+            line => document::contexts(text)
+                .get(line as usize - 1)
+                .cloned()
+                .unwrap_or(Context::None),
+        };
+        let kind = match previous {
+            Context::Response | Context::ResponseSection(_) => Kind::ResponseSection,
+            _ => Kind::RequestSection,
+        };
+        return keywords::keywords(kind)
+            .map(|k| section(k, Range::new(start, position)))
+            .collect();
+    }
+
+    let context = document::contexts(text)
+        .get(position.line as usize)
+        .cloned()
+        .unwrap_or(Context::None);
+    match context {
+        Context::ResponseSection(name) if name == "Asserts" => {
+            if completed_tokens(trimmed) == 0 {
+                items(&[Kind::Query])
+            } else {
+                items(&[Kind::Predicate, Kind::Filter])
+            }
+        }
+        Context::ResponseSection(name) if name == "Captures" => match trimmed.split_once(':') {
+            None => vec![],
+            Some((_, value)) if completed_tokens(value.trim_start()) == 0 => items(&[Kind::Query]),
+            Some(_) => items(&[Kind::Filter]),
+        },
+        _ => vec![],
+    }
+}
+
+/// Returns the number of tokens of `prefix` before the token being typed.
+fn completed_tokens(prefix: &str) -> usize {
+    let count = prefix.split_whitespace().count();
+    if prefix.is_empty() || prefix.ends_with(char::is_whitespace) {
+        count
+    } else {
+        count - 1
+    }
+}
+
+fn variables(text: &str) -> Vec<CompletionItem> {
+    let mut names = document::definitions(text)
+        .into_iter()
+        .map(|d| d.name)
+        .collect::<Vec<_>>();
+    names.sort();
+    names.dedup();
+    names
+        .into_iter()
+        .map(|name| CompletionItem {
+            label: name,
+            kind: Some(CompletionItemKind::VARIABLE),
+            ..CompletionItem::default()
+        })
+        .collect()
+}
+
+fn items(kinds: &[Kind]) -> Vec<CompletionItem> {
+    keywords::KEYWORDS
+        .iter()
+        .filter(|k| kinds.contains(&k.kind))
+        .map(|k| CompletionItem {
+            label: k.name.to_string(),
+            kind: Some(match k.kind {
+                Kind::Predicate => CompletionItemKind::OPERATOR,
+                Kind::Filter => CompletionItemKind::FUNCTION,
+                _ => CompletionItemKind::KEYWORD,
+            }),
+            documentation: Some(documentation(k)),
+            ..CompletionItem::default()
+        })
+        .collect()
+}
+
+fn section(keyword: &Keyword, range: Range) -> CompletionItem {
+    let new_text = format!("[{}]", keyword.name);
+    CompletionItem {
+        label: keyword.name.to_string(),
+        kind: Some(CompletionItemKind::MODULE),
+        documentation: Some(documentation(keyword)),
+        filter_text: Some(format!("[{}", keyword.name)),
+        text_edit: Some(CompletionTextEdit::Edit(TextEdit { range, new_text })),
+        ..CompletionItem::default()
+    }
+}
+
+fn documentation(keyword: &Keyword) -> Documentation {
+    Documentation::MarkupContent(MarkupContent {
+        kind: MarkupKind::Markdown,
+        value: keyword.doc.to_string(),
+    })
+}
+
+fn utf16_len(s: &str) -> u32 {
+    s.chars().map(char::len_utf16).sum::<usize>() as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn labels(text: &str, line: u32, character: u32) -> Vec<String> {
+        completions(text, Position::new(line, character))
+            .into_iter()
+            .map(|c| c.label)
+            .collect()
+    }
+
+    #[test]
+    fn test_sections() {
+        let text = "GET http://localhost\n[Opt\nHTTP 200\n[\n";
+        assert_eq!(
+            labels(text, 1, 4),
+            vec![
+                "QueryStringParams",
+                "FormParams",
+                "MultipartFormData",
+                "Cookies",
+                "BasicAuth",
+                "Options"
+            ]
+        );
+        assert_eq!(labels(text, 3, 1), vec!["Captures", "Asserts"]);
+
+        let completion = &completions(text, Position::new(1, 4))[0];
+        assert_eq!(
+            completion.text_edit,
+            Some(CompletionTextEdit::Edit(TextEdit {
+                range: Range::new(Position::new(1, 0), Position::new(1, 4)),
+                new_text: "[QueryStringParams]".to_string(),
+            }))
+        );
+    }
+
+    #[test]
+    fn test_variables() {
+        let text = r#"GET http://localhost
+[Options]
+variable: host=localhost
+HTTP 200
+[Captures]
+token: header "token"
+
+GET http://{{
+"#;
+        assert_eq!(labels(text, 7, 13), vec!["host", "token"]);
+        assert!(labels(text, 7, 11).is_empty());
+    }
+
+    #[test]
+    fn test_asserts_and_captures() {
+        let text = r#"GET http://localhost
+HTTP 200
+[Captures]
+id: jsonpath "$.id"
+[Asserts]
+jsonpath "$.id"
+"#;
+        let queries = labels(text, 3, 4);
+        assert!(queries.contains(&"jsonpath".to_string()));
+        assert!(!queries.contains(&"count".to_string()));
+
+        let filters = labels(text, 3, 20);
+        assert!(filters.contains(&"count".to_string()));
+        assert!(!filters.contains(&"==".to_string()));
+
+        assert!(labels(text, 5, 0).contains(&"status".to_string()));
+        let predicates = labels(text, 5, 16);
+        assert!(predicates.contains(&"==".to_string()));
+        assert!(predicates.contains(&"toInt".to_string()));
+    }
+}
@@ -0,0 +1,71 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2023 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use lsp_types::{Position, Range};
+
+use crate::document;
+
+/// Returns the range of the definition of the variable of the Hurl document `text` at
+/// `position`: the last capture (or `variable` option) before `position`, or the first one
+/// after it.
+pub fn definition(text: &str, position: Position) -> Option<Range> {
+    let (name, _) = document::word_at(text, position, document::is_variable_char)?;
+    let definitions = document::definitions(text)
+        .into_iter()
+        .filter(|d| d.name == name)
+        .collect::<Vec<_>>();
+    definitions
+        .iter()
+        .rev()
+        .find(|d| d.range.start <= position)
+        .or(definitions.first())
+        .map(|d| d.range)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_definition() {
+        let text = r#"GET http://localhost/{{id}}
+HTTP 200
+[Captures]
+id: jsonpath "$.id"
+
+GET http://localhost/{{id}}
+HTTP 200
+[Captures]
+id: jsonpath "$.next"
+[Asserts]
+variable "id" exists
+"#;
+        assert_eq!(
+            definition(text, Position::new(0, 24)),
+            Some(Range::new(Position::new(3, 0), Position::new(3, 2)))
+        );
+        assert_eq!(
+            definition(text, Position::new(5, 24)),
+            Some(Range::new(Position::new(3, 0), Position::new(3, 2)))
+        );
+        assert_eq!(
+            definition(text, Position::new(10, 11)),
+            Some(Range::new(Position::new(8, 0), Position::new(8, 2)))
+        );
+        assert_eq!(definition(text, Position::new(5, 10)), None);
+    }
+}
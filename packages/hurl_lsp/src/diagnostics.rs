@@ -0,0 +1,108 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2023 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::error::Error;
+use hurl_core::parser;
+use hurlfmt::linter::{self, Config, Severity};
+use lsp_types::{Diagnostic, DiagnosticSeverity, NumberOrString};
+
+use crate::document;
+
+const SOURCE: &str = "hurl";
+
+/// Returns the diagnostics of a Hurl document `text`: its parsing errors, or its linter warnings
+/// if it's valid.
+pub fn diagnostics(text: &str, config: &Config) -> Vec<Diagnostic> {
+    match parser::parse_hurl_file_with_recovery(text) {
+        (_, errors) if !errors.is_empty() => errors
+            .iter()
+            .map(|error| Diagnostic {
+                range: document::to_range(text, &error.source_info()),
+                severity: Some(DiagnosticSeverity::ERROR),
+                source: Some(SOURCE.to_string()),
+                message: message(error),
+                ..Diagnostic::default()
+            })
+            .collect(),
+        (hurl_file, _) => linter::check_hurl_file_with_config(&hurl_file, config)
+            .into_iter()
+            .map(|error| {
+                let severity = match config.severity(&error.inner) {
+                    Severity::Error => DiagnosticSeverity::ERROR,
+                    _ => DiagnosticSeverity::WARNING,
+                };
+                Diagnostic {
+                    range: document::to_range(text, &error.source_info()),
+                    severity: Some(severity),
+                    code: Some(NumberOrString::String(error.inner.rule().to_string())),
+                    source: Some(SOURCE.to_string()),
+                    message: message(&error),
+                    ..Diagnostic::default()
+                }
+            })
+            .collect(),
+    }
+}
+
+fn message(error: &impl Error) -> String {
+    format!("{}: {}", error.description(), error.fixme())
+}
+
+#[cfg(test)]
+mod tests {
+    use lsp_types::{Position, Range};
+
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let diagnostics = diagnostics(
+            "GET http://localhost\nHTTP 200\n[Foo]\n\nGET http://localhost\nfoo\n",
+            &Config::default(),
+        );
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(
+            diagnostics[0].range,
+            Range::new(Position::new(2, 1), Position::new(2, 1))
+        );
+        assert_eq!(diagnostics[0].severity, Some(DiagnosticSeverity::ERROR));
+        assert_eq!(diagnostics[0].code, None);
+    }
+
+    #[test]
+    fn test_linter_warnings() {
+        let config = Config::parse("[rules]\nno-assert = \"error\"\n").unwrap();
+        let diagnostics = diagnostics("GET http://localhost/{{id}}\n", &config);
+        let codes = diagnostics
+            .iter()
+            .map(|d| (d.code.clone().unwrap(), d.severity.unwrap()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            codes,
+            vec![
+                (
+                    NumberOrString::String("no-assert".to_string()),
+                    DiagnosticSeverity::ERROR
+                ),
+                (
+                    NumberOrString::String("undefined-variable".to_string()),
+                    DiagnosticSeverity::WARNING
+                ),
+            ]
+        );
+    }
+}
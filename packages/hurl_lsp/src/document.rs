@@ -0,0 +1,295 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2023 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+//! Text level analysis of a Hurl document.
+//!
+//! Completions and go-to-definition are requested while the document is being edited, and is
+//! often not parsable: the document is analysed line by line, independently of the parser.
+use hurl_core::ast::{Pos, SourceInfo};
+use lsp_types::{Position, Range};
+
+const METHODS: [&str; 9] = [
+    "GET", "HEAD", "POST", "PUT", "DELETE", "CONNECT", "OPTIONS", "TRACE", "PATCH",
+];
+
+/// The part of an entry a line belongs to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Context {
+    /// Before the first entry.
+    None,
+    /// Request line, headers or body.
+    Request,
+    /// Request section, with its name.
+    RequestSection(String),
+    /// Response line, headers or body.
+    Response,
+    /// Response section, with its name.
+    ResponseSection(String),
+}
+
+/// Returns the context of each line of `text`.
+pub fn contexts(text: &str) -> Vec<Context> {
+    let mut context = Context::None;
+    let mut contexts = vec![];
+    for line in text.lines() {
+        let trimmed = line.trim();
+        if is_request_line(trimmed) {
+            context = Context::Request;
+        } else if trimmed.starts_with("HTTP") {
+            context = Context::Response;
+        } else if let Some(name) = section_name(trimmed) {
+            context = match context {
+                Context::Response | Context::ResponseSection(_) => {
+                    Context::ResponseSection(name.to_string())
+                }
+                _ => Context::RequestSection(name.to_string()),
+            }
+        }
+        contexts.push(context.clone());
+    }
+    contexts
+}
+
+fn is_request_line(line: &str) -> bool {
+    match line.split_once(' ') {
+        Some((method, _)) => METHODS.contains(&method),
+        None => false,
+    }
+}
+
+fn section_name(line: &str) -> Option<&str> {
+    line.strip_prefix('[')?.strip_suffix(']')
+}
+
+/// A variable definition: a capture or a `variable` option.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Definition {
+    pub name: String,
+    pub range: Range,
+}
+
+/// Returns the variables defined in `text`, in source order.
+pub fn definitions(text: &str) -> Vec<Definition> {
+    let mut definitions = vec![];
+    for ((index, line), context) in text.lines().enumerate().zip(contexts(text)) {
+        let trimmed = line.trim_start();
+        let indent = line.len() - trimmed.len();
+        let (name, start) = match context {
+            Context::ResponseSection(section) if section == "Captures" => {
+                match trimmed.split_once(':') {
+                    Some((name, _)) => (name.trim_end(), indent),
+                    None => continue,
+                }
+            }
+            Context::RequestSection(section) if section == "Options" => {
+                let Some(value) = trimmed.strip_prefix("variable") else {
+                    continue;
+                };
+                let Some(value) = value.trim_start().strip_prefix(':') else {
+                    continue;
+                };
+                let value = value.trim_start();
+                match value.split_once('=') {
+                    Some((name, _)) => (name.trim_end(), line.len() - value.len()),
+                    None => continue,
+                }
+            }
+            _ => continue,
+        };
+        if name.is_empty() || !name.chars().all(is_variable_char) {
+            continue;
+        }
+        let start = utf16_len(&line[..start]);
+        definitions.push(Definition {
+            name: name.to_string(),
+            range: Range::new(
+                Position::new(index as u32, start),
+                Position::new(index as u32, start + utf16_len(name)),
+            ),
+        });
+    }
+    definitions
+}
+
+pub fn is_variable_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '-'
+}
+
+/// Returns the text of the line at `position` before the cursor.
+pub fn line_prefix(text: &str, position: Position) -> &str {
+    let line = text.lines().nth(position.line as usize).unwrap_or("");
+    &line[..byte_offset(line, position.character)]
+}
+
+/// Returns the word at `position`, made of chars accepted by `is_word_char`, with its range.
+pub fn word_at(
+    text: &str,
+    position: Position,
+    is_word_char: impl Fn(char) -> bool,
+) -> Option<(String, Range)> {
+    let line = text.lines().nth(position.line as usize)?;
+    let offset = byte_offset(line, position.character);
+    let start = line[..offset]
+        .char_indices()
+        .rev()
+        .take_while(|(_, c)| is_word_char(*c))
+        .last()
+        .map_or(offset, |(i, _)| i);
+    let end = line[offset..]
+        .char_indices()
+        .find(|(_, c)| !is_word_char(*c))
+        .map_or(line.len(), |(i, _)| offset + i);
+    if start == end {
+        return None;
+    }
+    let range = Range::new(
+        Position::new(position.line, utf16_len(&line[..start])),
+        Position::new(position.line, utf16_len(&line[..end])),
+    );
+    Some((line[start..end].to_string(), range))
+}
+
+/// Returns the range covering the whole `text`.
+pub fn full_range(text: &str) -> Range {
+    let lines = text.split('\n').collect::<Vec<_>>();
+    let last = lines.last().unwrap_or(&"");
+    Range::new(
+        Position::new(0, 0),
+        Position::new((lines.len() - 1) as u32, utf16_len(last)),
+    )
+}
+
+/// Converts a Hurl `source_info` (lines and columns starting at 1, columns in chars) to an LSP
+/// range (lines and characters starting at 0, characters in UTF-16 code units).
+pub fn to_range(text: &str, source_info: &SourceInfo) -> Range {
+    Range::new(
+        to_position(text, &source_info.start),
+        to_position(text, &source_info.end),
+    )
+}
+
+fn to_position(text: &str, pos: &Pos) -> Position {
+    let line = pos.line.saturating_sub(1);
+    let character = match text.lines().nth(line) {
+        Some(s) => s
+            .chars()
+            .take(pos.column.saturating_sub(1))
+            .map(char::len_utf16)
+            .sum(),
+        None => 0,
+    };
+    Position::new(line as u32, character as u32)
+}
+
+/// Returns the byte offset in `line` of the UTF-16 `character` offset.
+fn byte_offset(line: &str, character: u32) -> usize {
+    let mut utf16 = 0;
+    for (i, c) in line.char_indices() {
+        if utf16 >= character as usize {
+            return i;
+        }
+        utf16 += c.len_utf16();
+    }
+    line.len()
+}
+
+fn utf16_len(s: &str) -> u32 {
+    s.chars().map(char::len_utf16).sum::<usize>() as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEXT: &str = r#"GET http://localhost/users
+[Options]
+variable: page=1
+HTTP 200
+[Captures]
+  user_id: jsonpath "$[0].id"
+[Asserts]
+jsonpath "$" count > 0
+"#;
+
+    #[test]
+    fn test_contexts() {
+        assert_eq!(
+            contexts(TEXT),
+            vec![
+                Context::Request,
+                Context::RequestSection("Options".to_string()),
+                Context::RequestSection("Options".to_string()),
+                Context::Response,
+                Context::ResponseSection("Captures".to_string()),
+                Context::ResponseSection("Captures".to_string()),
+                Context::ResponseSection("Asserts".to_string()),
+                Context::ResponseSection("Asserts".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_definitions() {
+        assert_eq!(
+            definitions(TEXT),
+            vec![
+                Definition {
+                    name: "page".to_string(),
+                    range: Range::new(Position::new(2, 10), Position::new(2, 14)),
+                },
+                Definition {
+                    name: "user_id".to_string(),
+                    range: Range::new(Position::new(5, 2), Position::new(5, 9)),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_word_at() {
+        assert_eq!(
+            word_at(
+                "id: header \"x\" toInt",
+                Position::new(0, 17),
+                char::is_alphanumeric
+            ),
+            Some((
+                "toInt".to_string(),
+                Range::new(Position::new(0, 15), Position::new(0, 20))
+            ))
+        );
+        assert_eq!(
+            word_at("a  b", Position::new(0, 2), char::is_alphanumeric),
+            None
+        );
+    }
+
+    #[test]
+    fn test_to_range() {
+        // `é` is one char and one UTF-16 code unit, `😀` is one char and two UTF-16 code units.
+        let text = "GET http://localhost/é😀/{{id}}\n";
+        assert_eq!(
+            to_range(text, &SourceInfo::new(1, 26, 1, 28)),
+            Range::new(Position::new(0, 26), Position::new(0, 28))
+        );
+        assert_eq!(
+            line_prefix(text, Position::new(0, 24)),
+            "GET http://localhost/é😀"
+        );
+    }
+}
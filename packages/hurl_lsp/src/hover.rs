@@ -0,0 +1,73 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2023 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use lsp_types::{Hover, HoverContents, MarkupContent, MarkupKind, Position};
+
+use crate::document::{self, Context};
+use crate::keywords::{self, Kind};
+
+/// Returns the documentation of the predicate or filter of the Hurl document `text` at
+/// `position`.
+pub fn hover(text: &str, position: Position) -> Option<Hover> {
+    let context = document::contexts(text)
+        .get(position.line as usize)?
+        .clone();
+    if !matches!(context, Context::ResponseSection(_)) {
+        return None;
+    }
+    let is_word_char = |c: char| c.is_alphanumeric() || "=!<>".contains(c);
+    let (word, range) = document::word_at(text, position, is_word_char)?;
+    let keyword = keywords::predicate_or_filter(&word)?;
+    let kind = match keyword.kind {
+        Kind::Predicate => "predicate",
+        _ => "filter",
+    };
+    Some(Hover {
+        contents: HoverContents::Markup(MarkupContent {
+            kind: MarkupKind::Markdown,
+            value: format!("`{}` ({kind})\n\n{}", keyword.name, keyword.doc),
+        }),
+        range: Some(range),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use lsp_types::Range;
+
+    use super::*;
+
+    #[test]
+    fn test_hover() {
+        let text = "GET http://localhost\nHTTP 200\n[Asserts]\njsonpath \"$.books\" count >= 2\n";
+        let hover = hover(text, Position::new(3, 20)).unwrap();
+        assert_eq!(
+            hover.range,
+            Some(Range::new(Position::new(3, 19), Position::new(3, 24)))
+        );
+        assert_eq!(
+            hover.contents,
+            HoverContents::Markup(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value: "`count` (filter)\n\nCounts the items of a collection.".to_string(),
+            })
+        );
+        assert!(super::hover(text, Position::new(3, 26)).is_some());
+        assert!(super::hover(text, Position::new(3, 2)).is_none());
+        assert!(super::hover(text, Position::new(0, 1)).is_none());
+    }
+}
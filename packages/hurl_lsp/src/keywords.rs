@@ -0,0 +1,117 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2023 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+//! Keywords of the Hurl grammar, with their documentation, used for completions and hovers.
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Kind {
+    RequestSection,
+    ResponseSection,
+    Query,
+    Predicate,
+    Filter,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Keyword {
+    pub name: &'static str,
+    pub kind: Kind,
+    pub doc: &'static str,
+}
+
+const fn keyword(name: &'static str, kind: Kind, doc: &'static str) -> Keyword {
+    Keyword { name, kind, doc }
+}
+
+pub const KEYWORDS: &[Keyword] = &[
+    // Sections
+    keyword("QueryStringParams", Kind::RequestSection, "Query parameters added to the URL."),
+    keyword("FormParams", Kind::RequestSection, "Form parameters, sent as an `application/x-www-form-urlencoded` body."),
+    keyword("MultipartFormData", Kind::RequestSection, "Multipart form data, with `file,` parameters for uploads."),
+    keyword("Cookies", Kind::RequestSection, "Cookies added to the request."),
+    keyword("BasicAuth", Kind::RequestSection, "Basic authentication, as a `user: password` line."),
+    keyword("Options", Kind::RequestSection, "Options for this entry only, like `location: true` or `variable: name=value`."),
+    keyword("Captures", Kind::ResponseSection, "Variables captured from the response, available to the next entries."),
+    keyword("Asserts", Kind::ResponseSection, "Explicit asserts on the response."),
+    // Queries
+    keyword("status", Kind::Query, "HTTP response status code."),
+    keyword("url", Kind::Query, "Last fetched URL, after redirections."),
+    keyword("header", Kind::Query, "Value of a response header: `header \"Content-Type\"`."),
+    keyword("cookie", Kind::Query, "Response cookie, or one of its attributes: `cookie \"LSID[Max-Age]\"`."),
+    keyword("body", Kind::Query, "Response body, decoded as text."),
+    keyword("xpath", Kind::Query, "XPath expression evaluated on an HTML or XML body: `xpath \"string(//h1)\"`."),
+    keyword("jsonpath", Kind::Query, "JSONPath expression evaluated on a JSON body: `jsonpath \"$.books[0].title\"`."),
+    keyword("regex", Kind::Query, "First captured group of a regex on the body: `regex \"id=(\\\\d+)\"`."),
+    keyword("variable", Kind::Query, "Value of a variable: `variable \"name\"`."),
+    keyword("duration", Kind::Query, "Response time in milliseconds."),
+    keyword("bytes", Kind::Query, "Response body, as bytes."),
+    keyword("sha256", Kind::Query, "SHA-256 hash of the response body bytes."),
+    keyword("md5", Kind::Query, "MD5 hash of the response body bytes."),
+    keyword("certificate", Kind::Query, "SSL certificate attribute: `Subject`, `Issuer`, `Start-Date`, `Expire-Date` or `Serial-Number`."),
+    // Predicates
+    keyword("==", Kind::Predicate, "Query and predicate value are equal."),
+    keyword("!=", Kind::Predicate, "Query and predicate value are different."),
+    keyword(">", Kind::Predicate, "Query number is greater than predicate value."),
+    keyword(">=", Kind::Predicate, "Query number is greater than or equal to predicate value."),
+    keyword("<", Kind::Predicate, "Query number is less than predicate value."),
+    keyword("<=", Kind::Predicate, "Query number is less than or equal to predicate value."),
+    keyword("startsWith", Kind::Predicate, "Query starts with the predicate value (string or bytes)."),
+    keyword("endsWith", Kind::Predicate, "Query ends with the predicate value (string or bytes)."),
+    keyword("contains", Kind::Predicate, "Query contains the predicate value (string or bytes)."),
+    keyword("includes", Kind::Predicate, "Query collection includes the predicate value."),
+    keyword("matches", Kind::Predicate, "Query string matches the predicate regex."),
+    keyword("exists", Kind::Predicate, "Query returns a value."),
+    keyword("isEmpty", Kind::Predicate, "Query returns an empty collection."),
+    keyword("isInteger", Kind::Predicate, "Query returns an integer."),
+    keyword("isFloat", Kind::Predicate, "Query returns a float."),
+    keyword("isBoolean", Kind::Predicate, "Query returns a boolean."),
+    keyword("isString", Kind::Predicate, "Query returns a string."),
+    keyword("isCollection", Kind::Predicate, "Query returns a collection."),
+    keyword("isDate", Kind::Predicate, "Query returns a date."),
+    keyword("not", Kind::Predicate, "Negates the following predicate."),
+    // Filters
+    keyword("count", Kind::Filter, "Counts the items of a collection."),
+    keyword("daysAfterNow", Kind::Filter, "Returns the number of days between now and a date in the future."),
+    keyword("daysBeforeNow", Kind::Filter, "Returns the number of days between now and a date in the past."),
+    keyword("decode", Kind::Filter, "Decodes bytes to a string with an encoding: `decode \"iso-8859-1\"`."),
+    keyword("format", Kind::Filter, "Formats a date to a string: `format \"%a, %d %b %Y\"`."),
+    keyword("htmlEscape", Kind::Filter, "Converts the characters `&`, `<` and `>` to HTML-safe sequences."),
+    keyword("htmlUnescape", Kind::Filter, "Converts all named and numeric character references to the corresponding characters."),
+    keyword("nth", Kind::Filter, "Returns the element at a zero-based index of a collection: `nth 2`."),
+    keyword("regex", Kind::Filter, "Extracts the first captured group of a regex: `regex /(\\d+)/`."),
+    keyword("replace", Kind::Filter, "Replaces all occurrences of a string or regex: `replace \",\" \" \"`."),
+    keyword("split", Kind::Filter, "Splits a string into a collection: `split \",\"`."),
+    keyword("toDate", Kind::Filter, "Parses a string to a date with a format: `toDate \"%Y-%m-%d\"`."),
+    keyword("toInt", Kind::Filter, "Converts a string to an integer."),
+    keyword("urlDecode", Kind::Filter, "Replaces `%xx` escapes with their single-character equivalent."),
+    keyword("urlEncode", Kind::Filter, "Percent-encodes all the characters which are not unreserved."),
+    keyword("xpath", Kind::Filter, "Evaluates an XPath expression on a string: `xpath \"string(//h1)\"`."),
+];
+
+/// Returns the keywords of this `kind`.
+pub fn keywords(kind: Kind) -> impl Iterator<Item = &'static Keyword> {
+    KEYWORDS.iter().filter(move |k| k.kind == kind)
+}
+
+/// Returns the documentation of the predicate or filter `name`, if any.
+pub fn predicate_or_filter(name: &str) -> Option<&'static Keyword> {
+    KEYWORDS
+        .iter()
+        .filter(|k| k.kind == Kind::Predicate || k.kind == Kind::Filter)
+        .find(|k| k.name == name)
+}
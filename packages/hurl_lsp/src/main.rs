@@ -0,0 +1,32 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2023 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::process;
+
+use lsp_server::Connection;
+
+fn main() {
+    let (connection, io_threads) = Connection::stdio();
+    if let Err(e) = hurl_lsp::server::run(connection) {
+        eprintln!("error: {e}");
+        process::exit(1);
+    }
+    if let Err(e) = io_threads.join() {
+        eprintln!("error: {e}");
+        process::exit(1);
+    }
+}
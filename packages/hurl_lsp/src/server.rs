@@ -0,0 +1,373 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2023 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::collections::HashMap;
+use std::error::Error;
+use std::fs;
+
+use hurl_core::parser;
+use hurlfmt::{format, linter};
+use lsp_server::{Connection, ErrorCode, Message, Notification, Request, Response, ResponseError};
+use lsp_types::notification::{
+    DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument,
+    Notification as NotificationTrait, PublishDiagnostics,
+};
+use lsp_types::request::{
+    Completion, Formatting, GotoDefinition, HoverRequest, Request as RequestTrait,
+};
+use lsp_types::{
+    CompletionOptions, CompletionResponse, DocumentFormattingParams, GotoDefinitionParams,
+    GotoDefinitionResponse, Location, OneOf, PublishDiagnosticsParams, ServerCapabilities,
+    TextDocumentSyncCapability, TextDocumentSyncKind, TextEdit, Url,
+};
+
+use crate::{completion, definition, diagnostics, document, hover};
+
+/// Runs the language server on this `connection`, until it's shut down.
+pub fn run(connection: Connection) -> Result<(), Box<dyn Error + Sync + Send>> {
+    let capabilities = ServerCapabilities {
+        text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+        completion_provider: Some(CompletionOptions {
+            trigger_characters: Some(vec!["[".to_string(), "{".to_string(), " ".to_string()]),
+            ..CompletionOptions::default()
+        }),
+        hover_provider: Some(lsp_types::HoverProviderCapability::Simple(true)),
+        definition_provider: Some(OneOf::Left(true)),
+        document_formatting_provider: Some(OneOf::Left(true)),
+        ..ServerCapabilities::default()
+    };
+    connection.initialize(serde_json::to_value(capabilities)?)?;
+
+    let mut server = Server {
+        connection: &connection,
+        documents: HashMap::new(),
+    };
+    for message in &connection.receiver {
+        match message {
+            Message::Request(request) => {
+                if connection.handle_shutdown(&request)? {
+                    return Ok(());
+                }
+                server.handle_request(request)?;
+            }
+            Message::Notification(notification) => server.handle_notification(notification)?,
+            Message::Response(_) => {}
+        }
+    }
+    Ok(())
+}
+
+struct Server<'a> {
+    connection: &'a Connection,
+    /// Text of the opened documents.
+    documents: HashMap<Url, String>,
+}
+
+impl Server<'_> {
+    fn handle_request(&self, request: Request) -> Result<(), Box<dyn Error + Sync + Send>> {
+        let id = request.id.clone();
+        let response = match self.request_result(request) {
+            Ok(result) => Response::new_ok(id, result),
+            Err(error) => Response {
+                id,
+                result: None,
+                error: Some(error),
+            },
+        };
+        self.connection.sender.send(Message::Response(response))?;
+        Ok(())
+    }
+
+    /// Returns the result of a `request`, or the error to reply to the client if the method is
+    /// unknown or the parameters are invalid.
+    fn request_result(&self, request: Request) -> Result<serde_json::Value, ResponseError> {
+        let result = match request.method.as_str() {
+            Completion::METHOD => {
+                let params = request_params::<Completion>(request)?;
+                let position = params.text_document_position;
+                let items = self
+                    .text(&position.text_document.uri)
+                    .map(|text| completion::completions(text, position.position))
+                    .unwrap_or_default();
+                serde_json::to_value(CompletionResponse::Array(items))
+            }
+            HoverRequest::METHOD => {
+                let params = request_params::<HoverRequest>(request)?;
+                let position = params.text_document_position_params;
+                let hover = self
+                    .text(&position.text_document.uri)
+                    .and_then(|text| hover::hover(text, position.position));
+                serde_json::to_value(hover)
+            }
+            GotoDefinition::METHOD => {
+                let params = request_params::<GotoDefinition>(request)?;
+                serde_json::to_value(self.definition(params))
+            }
+            Formatting::METHOD => {
+                let params = request_params::<Formatting>(request)?;
+                serde_json::to_value(self.formatting(params))
+            }
+            method => {
+                return Err(ResponseError {
+                    code: ErrorCode::MethodNotFound as i32,
+                    message: format!("Unknown method {method}"),
+                    data: None,
+                })
+            }
+        };
+        result.map_err(|error| ResponseError {
+            code: ErrorCode::InternalError as i32,
+            message: error.to_string(),
+            data: None,
+        })
+    }
+
+    /// Handles a `notification` from the client.
+    ///
+    /// A notification with invalid parameters is logged and ignored: there's no response to
+    /// report the error to the client.
+    fn handle_notification(
+        &mut self,
+        notification: Notification,
+    ) -> Result<(), Box<dyn Error + Sync + Send>> {
+        match notification.method.as_str() {
+            DidOpenTextDocument::METHOD => {
+                let Some(params) = notification_params::<DidOpenTextDocument>(notification) else {
+                    return Ok(());
+                };
+                let document = params.text_document;
+                self.documents.insert(document.uri.clone(), document.text);
+                self.publish_diagnostics(document.uri)?;
+            }
+            DidChangeTextDocument::METHOD => {
+                let Some(params) = notification_params::<DidChangeTextDocument>(notification)
+                else {
+                    return Ok(());
+                };
+                // With full synchronization, the last change is the whole document.
+                if let Some(change) = params.content_changes.into_iter().last() {
+                    let uri = params.text_document.uri;
+                    self.documents.insert(uri.clone(), change.text);
+                    self.publish_diagnostics(uri)?;
+                }
+            }
+            DidCloseTextDocument::METHOD => {
+                let Some(params) = notification_params::<DidCloseTextDocument>(notification) else {
+                    return Ok(());
+                };
+                let uri = params.text_document.uri;
+                self.documents.remove(&uri);
+                self.send_diagnostics(uri, vec![])?;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn text(&self, uri: &Url) -> Option<&str> {
+        self.documents.get(uri).map(String::as_str)
+    }
+
+    fn publish_diagnostics(&self, uri: Url) -> Result<(), Box<dyn Error + Sync + Send>> {
+        let Some(text) = self.text(&uri) else {
+            return Ok(());
+        };
+        let diagnostics = diagnostics::diagnostics(text, &linter_config(&uri));
+        self.send_diagnostics(uri, diagnostics)
+    }
+
+    fn send_diagnostics(
+        &self,
+        uri: Url,
+        diagnostics: Vec<lsp_types::Diagnostic>,
+    ) -> Result<(), Box<dyn Error + Sync + Send>> {
+        let params = PublishDiagnosticsParams {
+            uri,
+            diagnostics,
+            version: None,
+        };
+        let notification = Notification::new(PublishDiagnostics::METHOD.to_string(), params);
+        self.connection
+            .sender
+            .send(Message::Notification(notification))?;
+        Ok(())
+    }
+
+    fn definition(&self, params: GotoDefinitionParams) -> Option<GotoDefinitionResponse> {
+        let position = params.text_document_position_params;
+        let uri = position.text_document.uri;
+        let range = definition::definition(self.text(&uri)?, position.position)?;
+        Some(GotoDefinitionResponse::Scalar(Location { uri, range }))
+    }
+
+    fn formatting(&self, params: DocumentFormattingParams) -> Option<Vec<TextEdit>> {
+        let text = self.text(&params.text_document.uri)?;
+        let hurl_file = parser::parse_hurl_file(text).ok()?;
+        let hurl_file = linter::lint_hurl_file(&hurl_file);
+        let new_text = format::format_text(hurl_file, false);
+        if new_text == text {
+            return Some(vec![]);
+        }
+        Some(vec![TextEdit {
+            range: document::full_range(text),
+            new_text,
+        }])
+    }
+}
+
+/// Returns the parameters of a `request`, or an `InvalidParams` error if they can't be decoded.
+fn request_params<R: RequestTrait>(request: Request) -> Result<R::Params, ResponseError> {
+    match request.extract(R::METHOD) {
+        Ok((_, params)) => Ok(params),
+        Err(error) => Err(ResponseError {
+            code: ErrorCode::InvalidParams as i32,
+            message: error.to_string(),
+            data: None,
+        }),
+    }
+}
+
+/// Returns the parameters of a `notification`, or logs an error and returns `None` if they
+/// can't be decoded.
+fn notification_params<N: NotificationTrait>(notification: Notification) -> Option<N::Params> {
+    match notification.extract(N::METHOD) {
+        Ok(params) => Some(params),
+        Err(error) => {
+            eprintln!("{error}");
+            None
+        }
+    }
+}
+
+/// Returns the linter configuration of the document `uri`, found in its directory or its
+/// ancestors, or the default configuration.
+fn linter_config(uri: &Url) -> linter::Config {
+    let path = uri.to_file_path().ok();
+    let Some(path) = path
+        .as_ref()
+        .and_then(|p| p.parent())
+        .and_then(linter::config::find)
+    else {
+        return linter::Config::default();
+    };
+    match fs::read_to_string(&path).map_err(|e| e.to_string()) {
+        Ok(content) => linter::Config::parse(&content).unwrap_or_else(|message| {
+            eprintln!("{}: {message}", path.display());
+            linter::Config::default()
+        }),
+        Err(message) => {
+            eprintln!("{}: {message}", path.display());
+            linter::Config::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use lsp_server::RequestId;
+    use lsp_types::{
+        DidOpenTextDocumentParams, Position, TextDocumentIdentifier, TextDocumentItem,
+        TextDocumentPositionParams,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_server() {
+        let (server, client) = Connection::memory();
+        let thread = std::thread::spawn(move || run(server).unwrap());
+
+        let send = |id: i32, method: &str, params: serde_json::Value| {
+            let request = Request::new(RequestId::from(id), method.to_string(), params);
+            client.sender.send(Message::Request(request)).unwrap();
+            match client.receiver.recv().unwrap() {
+                Message::Response(response) => response,
+                message => panic!("unexpected message {message:?}"),
+            }
+        };
+        let request = |id: i32, method: &str, params: serde_json::Value| {
+            send(id, method, params).result.unwrap()
+        };
+
+        let result = request(1, "initialize", serde_json::json!({ "capabilities": {} }));
+        assert_eq!(result["capabilities"]["definitionProvider"], true);
+        let initialized = Notification::new("initialized".to_string(), serde_json::json!({}));
+        client.sender.send(initialized.into()).unwrap();
+
+        let uri = Url::parse("untitled:test.hurl").unwrap();
+        let params = DidOpenTextDocumentParams {
+            text_document: TextDocumentItem {
+                uri: uri.clone(),
+                language_id: "hurl".to_string(),
+                version: 1,
+                text: "GET  http://localhost\nHTTP 200\n[Captures]\nid: body\n".to_string(),
+            },
+        };
+        let notification = Notification::new(DidOpenTextDocument::METHOD.to_string(), params);
+        client.sender.send(notification.into()).unwrap();
+        let Message::Notification(notification) = client.receiver.recv().unwrap() else {
+            panic!("expecting diagnostics");
+        };
+        let params = notification
+            .extract::<PublishDiagnosticsParams>(PublishDiagnostics::METHOD)
+            .unwrap();
+        assert_eq!(params.diagnostics.len(), 2);
+
+        let params = TextDocumentPositionParams {
+            text_document: TextDocumentIdentifier { uri: uri.clone() },
+            position: Position::new(3, 0),
+        };
+        let result = request(
+            2,
+            GotoDefinition::METHOD,
+            serde_json::to_value(params).unwrap(),
+        );
+        assert_eq!(result["range"]["end"]["character"], 2);
+
+        let params = serde_json::json!({
+            "textDocument": { "uri": uri },
+            "options": { "tabSize": 4, "insertSpaces": true },
+        });
+        let result = request(3, Formatting::METHOD, params);
+        assert_eq!(
+            result[0]["newText"],
+            "GET http://localhost\nHTTP 200\n[Captures]\nid: body\n"
+        );
+
+        // Invalid messages are reported without stopping the server.
+        let response = send(4, "unknown/method", serde_json::Value::Null);
+        assert_eq!(
+            response.error.unwrap().code,
+            ErrorCode::MethodNotFound as i32
+        );
+        let response = send(5, Formatting::METHOD, serde_json::json!({ "uri": 1 }));
+        assert_eq!(
+            response.error.unwrap().code,
+            ErrorCode::InvalidParams as i32
+        );
+        let invalid = Notification::new(
+            DidOpenTextDocument::METHOD.to_string(),
+            serde_json::json!({}),
+        );
+        client.sender.send(invalid.into()).unwrap();
+
+        request(6, "shutdown", serde_json::Value::Null);
+        let exit = Notification::new("exit".to_string(), serde_json::Value::Null);
+        client.sender.send(exit.into()).unwrap();
+        thread.join().unwrap();
+    }
+}
@@ -0,0 +1,601 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2023 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+//! Constructors of Hurl AST nodes, used to import other formats (HAR, OpenAPI etc...).
+//!
+//! The nodes have no source position: they're only built to be formatted with
+//! [`crate::format::format_text`].
+use base64::engine::general_purpose;
+use base64::Engine;
+use hurl_core::ast::*;
+use hurl_core::parser::{self, Reader};
+use serde_yaml::Value;
+
+use crate::escape::{escape_body_braces, escape_key, escape_string, variable_name};
+
+pub fn source_info() -> SourceInfo {
+    SourceInfo::new(0, 0, 0, 0)
+}
+
+pub fn whitespace(value: &str) -> Whitespace {
+    Whitespace {
+        value: value.to_string(),
+        source_info: source_info(),
+    }
+}
+
+pub fn newline() -> LineTerminator {
+    LineTerminator {
+        space0: whitespace(""),
+        comment: None,
+        newline: whitespace("\n"),
+    }
+}
+
+/// Returns a comment line `# text`, or `#` if `text` is empty.
+pub fn comment(text: &str) -> LineTerminator {
+    let value = if text.is_empty() {
+        String::new()
+    } else {
+        format!(" {text}")
+    };
+    LineTerminator {
+        space0: whitespace(""),
+        comment: Some(Comment { value }),
+        newline: whitespace("\n"),
+    }
+}
+
+/// Returns a Hurl file of `entries`, separated by an empty line.
+pub fn hurl_file(mut entries: Vec<Entry>) -> HurlFile {
+    for entry in entries.iter_mut().skip(1) {
+        entry.request.line_terminators.insert(0, newline());
+    }
+    HurlFile {
+        entries,
+        line_terminators: vec![],
+    }
+}
+
+/// Returns a request without headers, sections or body.
+pub fn request(method: &str, url: Template) -> Request {
+    Request {
+        line_terminators: vec![],
+        space0: whitespace(""),
+        method: Method(method.to_string()),
+        space1: whitespace(" "),
+        url,
+        line_terminator0: newline(),
+        headers: vec![],
+        sections: vec![],
+        body: None,
+        source_info: source_info(),
+    }
+}
+
+/// Returns a response without headers, sections or body.
+pub fn response(version: VersionValue, status: StatusValue) -> Response {
+    Response {
+        line_terminators: vec![],
+        version: Version {
+            value: version,
+            source_info: source_info(),
+        },
+        space0: whitespace(""),
+        status: Status {
+            value: status,
+            source_info: source_info(),
+        },
+        space1: whitespace(" "),
+        line_terminator0: newline(),
+        headers: vec![],
+        sections: vec![],
+        body: None,
+        source_info: source_info(),
+    }
+}
+
+/// Appends a section `value` to `sections`, unless it's empty.
+pub fn push_section(sections: &mut Vec<Section>, value: SectionValue) {
+    let is_empty = match &value {
+        SectionValue::QueryParams(items) | SectionValue::FormParams(items) => items.is_empty(),
+        SectionValue::BasicAuth(item) => item.is_none(),
+        SectionValue::MultipartFormData(items) => items.is_empty(),
+        SectionValue::Cookies(items) => items.is_empty(),
+        SectionValue::Captures(items) => items.is_empty(),
+        SectionValue::Asserts(items) => items.is_empty(),
+        SectionValue::Options(items) => items.is_empty(),
+    };
+    if !is_empty {
+        sections.push(section(value));
+    }
+}
+
+pub fn section(value: SectionValue) -> Section {
+    Section {
+        line_terminators: vec![],
+        space0: whitespace(""),
+        line_terminator0: newline(),
+        value,
+        source_info: source_info(),
+    }
+}
+
+/// Returns a key/value line `name: value`.
+pub fn key_value(name: &str, value: Template) -> KeyValue {
+    KeyValue {
+        line_terminators: vec![],
+        space0: whitespace(""),
+        key: key(name),
+        space1: whitespace(""),
+        space2: whitespace(" "),
+        value,
+        line_terminator0: newline(),
+    }
+}
+
+/// Returns a cookie line `name: value`.
+pub fn cookie(name: &str, value: Template) -> Cookie {
+    Cookie {
+        line_terminators: vec![],
+        space0: whitespace(""),
+        name: key(name),
+        space1: whitespace(""),
+        space2: whitespace(" "),
+        value,
+        line_terminator0: newline(),
+    }
+}
+
+/// Returns a multipart file param `name: file,filename; content_type`.
+pub fn file_param(name: &str, filename: &str, content_type: Option<&str>) -> MultipartParam {
+    let space2 = if content_type.is_some() { " " } else { "" };
+    MultipartParam::FileParam(FileParam {
+        line_terminators: vec![],
+        space0: whitespace(""),
+        key: key(name),
+        space1: whitespace(""),
+        space2: whitespace(" "),
+        value: FileValue {
+            space0: whitespace(""),
+            filename: self::filename(filename),
+            space1: whitespace(""),
+            space2: whitespace(space2),
+            content_type: content_type.map(str::to_string),
+        },
+        line_terminator0: newline(),
+    })
+}
+
+/// Returns an unquoted key (header name, capture name etc...).
+pub fn key(name: &str) -> EncodedString {
+    EncodedString {
+        value: name.to_string(),
+        encoded: escape_key(name),
+        quotes: false,
+        source_info: source_info(),
+    }
+}
+
+/// Returns a template of the string `s`, the text outside variables (`{{name}}`) being encoded
+/// with `escape`.
+///
+/// The template is delimited by `delimiter` (for quoted strings for instance). Invalid variables
+/// (like `{{$guid}}`) are kept as text.
+pub fn template(s: &str, escape: fn(&str) -> String, delimiter: Option<char>) -> Template {
+    let mut elements = vec![];
+    let mut text = String::new();
+    let mut rest = s;
+    while let Some(start) = rest.find("{{") {
+        let Some(end) = rest[start..].find("}}").map(|end| start + end) else {
+            break;
+        };
+        let inner = &rest[start + 2..end];
+        let name = inner.trim();
+        text.push_str(&rest[..start]);
+        if is_variable_name(name) {
+            if !text.is_empty() {
+                elements.push(template_string(&text, escape));
+                text = String::new();
+            }
+            let space0 = &inner[..inner.len() - inner.trim_start().len()];
+            let space1 = &inner[inner.trim_end().len()..];
+            let mut expression = expr(name);
+            expression.space0 = whitespace(space0);
+            expression.space1 = whitespace(space1);
+            elements.push(TemplateElement::Expression(expression));
+        } else {
+            text.push_str(&rest[start..end + 2]);
+        }
+        rest = &rest[end + 2..];
+    }
+    text.push_str(rest);
+    if !text.is_empty() {
+        elements.push(template_string(&text, escape));
+    }
+    Template {
+        delimiter,
+        elements,
+        source_info: source_info(),
+    }
+}
+
+/// Returns a template of an imported string `s`, whose variables (`{{name}}`) are renamed with
+/// valid Hurl variable names, the text outside variables being encoded with `escape`.
+///
+/// Dynamic variables (`{{$guid}}`, `{{$timestamp}}` etc...) of other tools are not supported
+/// by Hurl: they're kept as text, encoded with `escape_dynamic` so that they're not parsed as
+/// templates, and returned alongside the template.
+pub fn import_template(
+    s: &str,
+    escape: fn(&str) -> String,
+    escape_dynamic: fn(&str) -> String,
+) -> (Template, Vec<String>) {
+    let mut elements = vec![];
+    let mut dynamics = vec![];
+    let mut rest = s;
+    while let Some(start) = rest.find("{{") {
+        let Some(end) = rest[start..].find("}}").map(|end| start + end) else {
+            break;
+        };
+        let name = rest[start + 2..end].trim();
+        push_text(&mut elements, &rest[..start], escape);
+        if name.starts_with('$') {
+            dynamics.push(name.to_string());
+            push_text(&mut elements, &rest[start..end + 2], escape_dynamic);
+        } else {
+            elements.push(TemplateElement::Expression(expr(&variable_name(name))));
+        }
+        rest = &rest[end + 2..];
+    }
+    push_text(&mut elements, rest, escape);
+    let template = Template {
+        delimiter: None,
+        elements,
+        source_info: source_info(),
+    };
+    (template, dynamics)
+}
+
+/// Appends a text `value` to the template `elements`, encoded with `escape`.
+fn push_text(elements: &mut Vec<TemplateElement>, value: &str, escape: fn(&str) -> String) {
+    if value.is_empty() {
+        return;
+    }
+    match elements.last_mut() {
+        Some(TemplateElement::String {
+            value: last,
+            encoded,
+        }) => {
+            last.push_str(value);
+            encoded.push_str(&escape(value));
+        }
+        _ => elements.push(template_string(value, escape)),
+    }
+}
+
+/// Returns the text of a `template`, as written in a Hurl file (without delimiters).
+fn encoded(template: &Template) -> String {
+    template
+        .elements
+        .iter()
+        .map(|element| match element {
+            TemplateElement::String { encoded, .. } => encoded.clone(),
+            TemplateElement::Expression(expr) => format!("{{{{{expr}}}}}"),
+        })
+        .collect()
+}
+
+/// Returns a quoted string template `"s"`.
+pub fn quoted(s: &str) -> Template {
+    template(s, escape_string, Some('"'))
+}
+
+fn template_string(value: &str, escape: fn(&str) -> String) -> TemplateElement {
+    TemplateElement::String {
+        value: value.to_string(),
+        encoded: escape(value),
+    }
+}
+
+fn is_variable_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '-')
+}
+
+/// Returns the expression `{{name}}`.
+pub fn expr(name: &str) -> Expr {
+    Expr {
+        space0: whitespace(""),
+        variable: Variable {
+            name: name.to_string(),
+            source_info: source_info(),
+        },
+        space1: whitespace(""),
+    }
+}
+
+pub fn filename(path: &str) -> Filename {
+    Filename {
+        value: path.to_string(),
+        source_info: source_info(),
+    }
+}
+
+/// Returns a file body `file,path;`.
+pub fn file(path: &str) -> Bytes {
+    Bytes::File(File {
+        space0: whitespace(""),
+        filename: filename(path),
+        space1: whitespace(""),
+    })
+}
+
+/// Returns a base64 body `base64,...;`.
+pub fn base64(value: &[u8]) -> Base64 {
+    Base64 {
+        space0: whitespace(""),
+        value: value.to_vec(),
+        encoded: general_purpose::STANDARD.encode(value),
+        space1: whitespace(""),
+    }
+}
+
+/// Returns a multiline string body of a `content` in the language `lang` (`json`, `xml`,
+/// `graphql` or none).
+///
+/// `content` is converted to base64 if it can't be written as a multiline string (when it
+/// includes the ``` delimiter or doesn't end with a newline).
+pub fn multiline(content: &str, lang: &str) -> Bytes {
+    if content.contains("```") || !content.ends_with('\n') {
+        return Bytes::Base64(base64(content.as_bytes()));
+    }
+    multiline_template(template(content, str::to_string, None), lang)
+}
+
+/// Returns a multiline string body of a template `value`, ending with a newline, in the language
+/// `lang`.
+pub fn multiline_template(value: Template, lang: &str) -> Bytes {
+    if lang == "graphql" {
+        return graphql(value, None);
+    }
+    let text = Text {
+        space: whitespace(""),
+        newline: whitespace("\n"),
+        value,
+    };
+    let multiline = match lang {
+        "json" => MultilineString::Json(text),
+        "xml" => MultilineString::Xml(text),
+        _ => MultilineString::Text(text),
+    };
+    Bytes::MultilineString(multiline)
+}
+
+/// Returns a GraphQL body of a `query`, ending with a newline, with optional `variables`.
+pub fn graphql(query: Template, variables: Option<JsonValue>) -> Bytes {
+    let variables = variables.map(|value| GraphQlVariables {
+        space: whitespace(" "),
+        value,
+        whitespace: whitespace("\n"),
+    });
+    Bytes::MultilineString(MultilineString::GraphQl(GraphQl {
+        space: whitespace(""),
+        newline: whitespace("\n"),
+        value: query,
+        variables,
+    }))
+}
+
+/// Returns a request or response body.
+pub fn body(value: Bytes) -> Body {
+    Body {
+        line_terminators: vec![],
+        space0: whitespace(""),
+        value,
+        line_terminator0: newline(),
+    }
+}
+
+/// Returns the body of an imported `text`, and its dynamic variables (see [`import_template`]).
+///
+/// JSON bodies (if `json` is true or if `text` looks like JSON) are kept as is, other bodies are
+/// converted to multiline strings, or base64 when they can't be represented as multiline strings
+/// (when they include the ``` delimiter for instance).
+pub fn import_body(text: &str, json: bool) -> (Bytes, Vec<String>) {
+    let (mut value, dynamics) = import_template(text, str::to_string, escape_body_braces);
+    let trimmed = text.trim();
+    let json = json
+        || (trimmed.starts_with('{') && trimmed.ends_with('}'))
+        || (trimmed.starts_with('[') && trimmed.ends_with(']'));
+    if json {
+        if let Some(json) = parse_json(encoded(&value).trim()) {
+            return (Bytes::Json(json), dynamics);
+        }
+    }
+    if text.contains("```") {
+        return (Bytes::Base64(base64(text.as_bytes())), dynamics);
+    }
+    push_text(&mut value.elements, "\n", str::to_string);
+    (multiline_template(value, ""), dynamics)
+}
+
+/// Parses a JSON `text`, keeping its formatting, or returns `None` if it's not a valid Hurl
+/// JSON body.
+pub fn parse_json(text: &str) -> Option<JsonValue> {
+    let mut reader = Reader::new(text);
+    let value = parser::parse_json(&mut reader).ok()?;
+    if reader.is_eof() {
+        Some(value)
+    } else {
+        None
+    }
+}
+
+/// Returns a JSON value of a YAML/JSON `value`, indented with 4 spaces at the nesting `level`
+/// and keeping the order of the object keys.
+pub fn json(value: &Value, level: usize) -> JsonValue {
+    indent_json(json_value(value), level)
+}
+
+fn json_value(value: &Value) -> JsonValue {
+    match value {
+        Value::Null => JsonValue::Null,
+        Value::Bool(b) => JsonValue::Boolean(*b),
+        Value::Number(n) => JsonValue::Number(n.to_string()),
+        Value::String(s) => JsonValue::String(template(s, escape_json, Some('"'))),
+        Value::Sequence(items) => JsonValue::List {
+            space0: String::new(),
+            elements: items
+                .iter()
+                .map(|item| JsonListElement {
+                    space0: String::new(),
+                    value: json_value(item),
+                    space1: String::new(),
+                })
+                .collect(),
+        },
+        Value::Mapping(mapping) => JsonValue::Object {
+            space0: String::new(),
+            elements: mapping
+                .iter()
+                .map(|(name, value)| {
+                    let name = match name {
+                        Value::String(s) => s.clone(),
+                        Value::Null => String::new(),
+                        Value::Bool(b) => b.to_string(),
+                        Value::Number(n) => n.to_string(),
+                        _ => String::new(),
+                    };
+                    JsonObjectElement {
+                        space0: String::new(),
+                        name: template(&name, escape_json, None),
+                        space1: String::new(),
+                        space2: String::new(),
+                        value: json_value(value),
+                        space3: String::new(),
+                    }
+                })
+                .collect(),
+        },
+        Value::Tagged(tagged) => json_value(&tagged.value),
+    }
+}
+
+/// Returns a JSON `value` indented with 4 spaces at the nesting `level`.
+pub fn indent_json(value: JsonValue, level: usize) -> JsonValue {
+    let indent = format!("\n{}", "    ".repeat(level + 1));
+    let end_indent = format!("\n{}", "    ".repeat(level));
+    match value {
+        JsonValue::List { elements, .. } => {
+            let count = elements.len();
+            let elements = elements
+                .into_iter()
+                .enumerate()
+                .map(|(i, element)| JsonListElement {
+                    space0: if i == 0 { "" } else { &indent }.to_string(),
+                    value: indent_json(element.value, level + 1),
+                    space1: if i == count - 1 { &end_indent } else { "" }.to_string(),
+                })
+                .collect::<Vec<_>>();
+            JsonValue::List {
+                space0: if count == 0 { "" } else { &indent }.to_string(),
+                elements,
+            }
+        }
+        JsonValue::Object { elements, .. } => {
+            let count = elements.len();
+            let elements = elements
+                .into_iter()
+                .enumerate()
+                .map(|(i, element)| JsonObjectElement {
+                    space0: if i == 0 { "" } else { &indent }.to_string(),
+                    name: element.name,
+                    space1: String::new(),
+                    space2: " ".to_string(),
+                    value: indent_json(element.value, level + 1),
+                    space3: if i == count - 1 { &end_indent } else { "" }.to_string(),
+                })
+                .collect::<Vec<_>>();
+            JsonValue::Object {
+                space0: if count == 0 { "" } else { &indent }.to_string(),
+                elements,
+            }
+        }
+        value => value,
+    }
+}
+
+/// Encodes a string to be used in a JSON string.
+fn escape_json(s: &str) -> String {
+    let quoted = serde_json::to_string(s).unwrap();
+    quoted[1..quoted.len() - 1].to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::escape::{escape_url_braces, escape_value, escape_value_braces};
+    use crate::format;
+
+    #[test]
+    fn test_import_template() {
+        let (value, dynamics) =
+            import_template("{{base.url}}/a#b", escape_value, escape_value_braces);
+        assert_eq!(encoded(&value), "{{base_url}}/a\\#b");
+        assert!(dynamics.is_empty());
+
+        let (value, dynamics) =
+            import_template("{{ id }}-{{$guid}}-{{", escape_value, escape_value_braces);
+        assert_eq!(encoded(&value), "{{id}}-\\u{7b}\\u{7b}$guid}}-{{");
+        assert_eq!(dynamics, vec!["$guid".to_string()]);
+
+        let (value, dynamics) = import_template("/{{$guid}}", str::to_string, escape_url_braces);
+        assert_eq!(encoded(&value), "/%7B%7B$guid%7D%7D");
+        assert_eq!(dynamics, vec!["$guid".to_string()]);
+    }
+
+    #[test]
+    fn test_import_body() {
+        let body = |text: &str, json: bool| {
+            let (bytes, _) = import_body(text, json);
+            let mut request = request("POST", template("http://localhost", str::to_string, None));
+            request.body = Some(self::body(bytes));
+            let entry = Entry {
+                request,
+                response: None,
+            };
+            format::format_text(hurl_file(vec![entry]), false)
+        };
+        assert_eq!(
+            body(" [1,2,3]\n", false),
+            "POST http://localhost\n[1,2,3]\n"
+        );
+        assert_eq!(
+            body("{\"id\": {{id}}}", true),
+            "POST http://localhost\n```\n{\"id\": {{id}}}\n```\n"
+        );
+        assert_eq!(
+            body("Hello {{$guid}}", false),
+            "POST http://localhost\n```\nHello \\{\\{$guid}}\n```\n"
+        );
+        assert_eq!(body("```", false), "POST http://localhost\nbase64,YGBg;\n");
+    }
+}
@@ -0,0 +1,258 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2023 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use colored::*;
+
+/// Number of unchanged lines around the changes of a hunk.
+const CONTEXT: usize = 3;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Edit {
+    /// Line kept, at these indexes of the old and new texts.
+    Equal(usize, usize),
+    /// Line of the old text removed.
+    Delete(usize),
+    /// Line of the new text inserted.
+    Insert(usize),
+}
+
+/// Returns the unified diff between the `old` and `new` texts of the file `filename`, or `None` if
+/// the texts are equal.
+pub fn unified_diff(filename: &str, old: &str, new: &str, color: bool) -> Option<String> {
+    if old == new {
+        return None;
+    }
+    let old_lines = old.split_inclusive('\n').collect::<Vec<_>>();
+    let new_lines = new.split_inclusive('\n').collect::<Vec<_>>();
+    let edits = diff(&old_lines, &new_lines);
+
+    let mut output = String::new();
+    let header = format!("--- {filename}\n+++ {filename} (formatted)\n");
+    output.push_str(&colorize(&header, color, |s| s.bold()));
+    for hunk in hunks(&edits) {
+        let edits = &edits[hunk];
+        let old_count = edits
+            .iter()
+            .filter(|e| !matches!(e, Edit::Insert(_)))
+            .count();
+        let new_count = edits
+            .iter()
+            .filter(|e| !matches!(e, Edit::Delete(_)))
+            .count();
+        // Without old (or new) lines, the text is empty and the hunk starts at 0.
+        let old_start = edits
+            .iter()
+            .find_map(|e| match e {
+                Edit::Equal(i, _) | Edit::Delete(i) => Some(*i + 1),
+                Edit::Insert(_) => None,
+            })
+            .unwrap_or(0);
+        let new_start = edits
+            .iter()
+            .find_map(|e| match e {
+                Edit::Equal(_, j) | Edit::Insert(j) => Some(*j + 1),
+                Edit::Delete(_) => None,
+            })
+            .unwrap_or(0);
+        let range = format!("@@ -{old_start},{old_count} +{new_start},{new_count} @@\n");
+        output.push_str(&colorize(&range, color, |s| s.cyan()));
+        for edit in edits {
+            let (prefix, line) = match *edit {
+                Edit::Equal(i, _) => (' ', old_lines[i]),
+                Edit::Delete(i) => ('-', old_lines[i]),
+                Edit::Insert(j) => ('+', new_lines[j]),
+            };
+            let mut line = format!("{prefix}{}", line.trim_end_matches(['\n', '\r']));
+            if !line_ends_with_newline(edit, &old_lines, &new_lines) {
+                line.push_str("\n\\ No newline at end of file");
+            }
+            line.push('\n');
+            let line = match prefix {
+                '-' => colorize(&line, color, |s| s.red()),
+                '+' => colorize(&line, color, |s| s.green()),
+                _ => line,
+            };
+            output.push_str(&line);
+        }
+    }
+    Some(output)
+}
+
+fn colorize(s: &str, color: bool, style: fn(&str) -> ColoredString) -> String {
+    if !color {
+        return s.to_string();
+    }
+    // Colors are applied line by line, so that they don't span several lines.
+    s.split_inclusive('\n')
+        .map(|line| match line.strip_suffix('\n') {
+            Some(line) => format!("{}\n", style(line)),
+            None => style(line).to_string(),
+        })
+        .collect()
+}
+
+fn line_ends_with_newline(edit: &Edit, old_lines: &[&str], new_lines: &[&str]) -> bool {
+    match *edit {
+        Edit::Equal(i, _) | Edit::Delete(i) => old_lines[i].ends_with('\n'),
+        Edit::Insert(j) => new_lines[j].ends_with('\n'),
+    }
+}
+
+/// Returns the ranges of `edits` of each hunk: the changes with their context lines.
+fn hunks(edits: &[Edit]) -> Vec<std::ops::Range<usize>> {
+    let mut hunks: Vec<std::ops::Range<usize>> = vec![];
+    for (i, edit) in edits.iter().enumerate() {
+        if matches!(edit, Edit::Equal(..)) {
+            continue;
+        }
+        let start = i.saturating_sub(CONTEXT);
+        let end = (i + 1 + CONTEXT).min(edits.len());
+        match hunks.last_mut() {
+            Some(last) if start <= last.end => last.end = end,
+            _ => hunks.push(start..end),
+        }
+    }
+    hunks
+}
+
+/// Returns the shortest edit script from `old` to `new` lines (Myers algorithm).
+fn diff(old: &[&str], new: &[&str]) -> Vec<Edit> {
+    let n = old.len() as isize;
+    let m = new.len() as isize;
+    let max = n + m;
+    let offset = max + 1;
+    let mut v = vec![0_isize; 2 * max as usize + 3];
+    let mut trace = vec![];
+    'search: for d in 0..=max {
+        trace.push(v.clone());
+        for k in (-d..=d).step_by(2) {
+            let index = (k + offset) as usize;
+            let mut x = if k == -d || (k != d && v[index - 1] < v[index + 1]) {
+                v[index + 1]
+            } else {
+                v[index - 1] + 1
+            };
+            let mut y = x - k;
+            while x < n && y < m && old[x as usize] == new[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            v[index] = x;
+            if x >= n && y >= m {
+                break 'search;
+            }
+        }
+    }
+
+    // Backtracks from the end to find the path.
+    let mut edits = vec![];
+    let (mut x, mut y) = (n, m);
+    for (d, v) in trace.iter().enumerate().rev() {
+        let d = d as isize;
+        let k = x - y;
+        let index = (k + offset) as usize;
+        let prev_k = if k == -d || (k != d && v[index - 1] < v[index + 1]) {
+            k + 1
+        } else {
+            k - 1
+        };
+        let prev_x = v[(prev_k + offset) as usize];
+        let prev_y = prev_x - prev_k;
+        while x > prev_x && y > prev_y {
+            edits.push(Edit::Equal(x as usize - 1, y as usize - 1));
+            x -= 1;
+            y -= 1;
+        }
+        if d > 0 {
+            if x == prev_x {
+                edits.push(Edit::Insert(y as usize - 1));
+            } else {
+                edits.push(Edit::Delete(x as usize - 1));
+            }
+        }
+        x = prev_x;
+        y = prev_y;
+    }
+    edits.reverse();
+    edits
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff() {
+        assert_eq!(
+            diff(&["a", "b", "c"], &["a", "x", "c", "d"]),
+            vec![
+                Edit::Equal(0, 0),
+                Edit::Delete(1),
+                Edit::Insert(1),
+                Edit::Equal(2, 2),
+                Edit::Insert(3),
+            ]
+        );
+        assert_eq!(diff(&[], &["a"]), vec![Edit::Insert(0)]);
+        assert_eq!(diff(&["a"], &[]), vec![Edit::Delete(0)]);
+    }
+
+    #[test]
+    fn test_unified_diff() {
+        assert_eq!(unified_diff("test.hurl", "a\n", "a\n", false), None);
+
+        let old = "GET  http://localhost\n1\n2\n3\n4\n5\n6\n7\n8\n9\nHTTP 200\n";
+        let new = "GET http://localhost\n1\n2\n3\n4\n5\n6\n7\n8\n9\nHTTP 200\n[Asserts]\n";
+        assert_eq!(
+            unified_diff("test.hurl", old, new, false).unwrap(),
+            r#"--- test.hurl
++++ test.hurl (formatted)
+@@ -1,4 +1,4 @@
+-GET  http://localhost
++GET http://localhost
+ 1
+ 2
+ 3
+@@ -9,3 +9,4 @@
+ 8
+ 9
+ HTTP 200
++[Asserts]
+"#
+        );
+    }
+
+    #[test]
+    fn test_no_newline_at_end_of_file() {
+        assert_eq!(
+            unified_diff(
+                "test.hurl",
+                "GET  http://localhost",
+                "GET http://localhost\n",
+                false
+            )
+            .unwrap(),
+            r#"--- test.hurl
++++ test.hurl (formatted)
+@@ -1,1 +1,1 @@
+-GET  http://localhost
+\ No newline at end of file
++GET http://localhost
+"#
+        );
+    }
+}
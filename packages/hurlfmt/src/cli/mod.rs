@@ -16,15 +16,19 @@
  *
  */
 
+pub use self::diff::unified_diff;
 pub use self::fs::read_to_string;
 pub use self::logger::{
     log_info, make_logger_error_message, make_logger_linter_error, make_logger_parser_error,
     make_logger_verbose,
 };
+pub use self::report::{format_linter_reports_json, FormatReport, LinterReport};
 
+mod diff;
 mod fs;
 mod logger;
 pub mod options;
+mod report;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct CliError {
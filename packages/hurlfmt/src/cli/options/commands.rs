@@ -26,6 +26,16 @@ pub fn check() -> clap::Arg {
         .conflicts_with("output")
 }
 
+pub fn check_format() -> clap::Arg {
+    clap::Arg::new("check_format")
+        .long("check-format")
+        .value_name("FORMAT")
+        .value_parser(["text", "json"])
+        .default_value("text")
+        .help("Specify check report format: text or json")
+        .num_args(1)
+}
+
 pub fn color() -> clap::Arg {
     clap::Arg::new("color")
         .long("color")
@@ -35,6 +45,31 @@ pub fn color() -> clap::Arg {
         .conflicts_with("in_place")
 }
 
+pub fn config() -> clap::Arg {
+    clap::Arg::new("config")
+        .long("config")
+        .value_name("FILE")
+        .help("Read linter configuration from FILE instead of .hurlfmt.toml")
+        .num_args(1)
+}
+
+pub fn css_file() -> clap::Arg {
+    clap::Arg::new("css_file")
+        .long("css-file")
+        .value_name("FILE")
+        .help("Write the CSS stylesheet of the html output to FILE")
+        .num_args(1)
+}
+
+pub fn extract_json_bodies() -> clap::Arg {
+    clap::Arg::new("extract_json_bodies")
+        .long("extract-json-bodies")
+        .help("Move inline JSON bodies to files, referenced with file, bodies")
+        .action(ArgAction::SetTrue)
+        .conflicts_with("check")
+        .conflicts_with("inline_json_bodies")
+}
+
 pub fn format() -> clap::Arg {
     clap::Arg::new("format")
         .long("format")
@@ -45,6 +80,14 @@ pub fn format() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn format_bodies() -> clap::Arg {
+    clap::Arg::new("format_bodies")
+        .long("format-bodies")
+        .help("Pretty-print JSON and XML request bodies")
+        .action(ArgAction::SetTrue)
+        .conflicts_with("check")
+}
+
 pub fn in_place() -> clap::Arg {
     clap::Arg::new("in_place")
         .long("in-place")
@@ -54,6 +97,14 @@ pub fn in_place() -> clap::Arg {
         .conflicts_with("color")
 }
 
+pub fn inline_json_bodies() -> clap::Arg {
+    clap::Arg::new("inline_json_bodies")
+        .long("inline-json-bodies")
+        .help("Inline the JSON files referenced by file, bodies")
+        .action(ArgAction::SetTrue)
+        .conflicts_with("check")
+}
+
 pub fn input_files() -> clap::Arg {
     clap::Arg::new("input_files")
         .help("Sets the input file to use")
@@ -66,11 +117,34 @@ pub fn input_format() -> clap::Arg {
     clap::Arg::new("input_format")
         .long("in")
         .value_name("FORMAT")
-        .help("Specify input format: hurl or curl")
+        .help("Specify input format: hurl, curl, har, http, json, openapi or postman")
         .conflicts_with("check")
         .default_value("hurl")
         .num_args(1)
 }
+pub fn json() -> clap::Arg {
+    clap::Arg::new("json")
+        .long("json")
+        .help("Report check results in JSON, same as --check-format json")
+        .action(ArgAction::SetTrue)
+        .requires("check")
+        .conflicts_with("check_format")
+}
+
+pub fn line_numbers() -> clap::Arg {
+    clap::Arg::new("line_numbers")
+        .long("line-numbers")
+        .help("Number the lines of the html output, with anchors")
+        .action(ArgAction::SetTrue)
+}
+
+pub fn links() -> clap::Arg {
+    clap::Arg::new("links")
+        .long("links")
+        .help("Add anchors to the entries of the html output, and link variables to their captures")
+        .action(ArgAction::SetTrue)
+}
+
 pub fn no_color() -> clap::Arg {
     clap::Arg::new("no_color")
         .long("no-color")
@@ -92,15 +166,61 @@ pub fn output_format() -> clap::Arg {
     clap::Arg::new("output_format")
         .long("out")
         .value_name("FORMAT")
-        .help("Specify output format: hurl, json or html")
+        .help("Specify output format: hurl, curl, json, html, http or markdown")
         .conflicts_with("check")
         .default_value("hurl")
         .num_args(1)
 }
 
+pub fn rename_variable() -> clap::Arg {
+    clap::Arg::new("rename_variable")
+        .long("rename-variable")
+        .value_name("OLD=NEW")
+        .help("Rename variable OLD to NEW, where it is defined, captured and used")
+        .action(ArgAction::Append)
+        .conflicts_with("check")
+        .num_args(1)
+}
+
+pub fn replace_url_prefix() -> clap::Arg {
+    clap::Arg::new("replace_url_prefix")
+        .long("replace-url-prefix")
+        .value_name("OLD=NEW")
+        .help("Replace the prefix OLD of the request URLs by NEW")
+        .action(ArgAction::Append)
+        .conflicts_with("check")
+        .num_args(1)
+}
+
+pub fn sort_json_keys() -> clap::Arg {
+    clap::Arg::new("sort_json_keys")
+        .long("sort-json-keys")
+        .help("Sort the keys of JSON request bodies, with --format-bodies")
+        .action(ArgAction::SetTrue)
+        .requires("format_bodies")
+}
+
 pub fn standalone() -> clap::Arg {
     clap::Arg::new("standalone")
         .long("standalone")
         .help("Standalone Html")
         .action(ArgAction::SetTrue)
 }
+
+pub fn theme() -> clap::Arg {
+    clap::Arg::new("theme")
+        .long("theme")
+        .value_name("THEME")
+        .value_parser(["light", "dark"])
+        .default_value("light")
+        .help("Specify the theme of the html output: light or dark")
+        .num_args(1)
+}
+
+pub fn variables_file() -> clap::Arg {
+    clap::Arg::new("variables_file")
+        .long("variables-file")
+        .value_name("FILE")
+        .help("Write the variables of an imported postman collection to FILE")
+        .num_args(1)
+}
@@ -16,9 +16,10 @@
  *
  */
 use super::OptionsError;
-use crate::cli::options::{InputFormat, OutputFormat};
+use crate::cli::options::{CheckFormat, InputFormat, OutputFormat};
 use clap::parser::ValueSource;
 use clap::ArgMatches;
+use hurl_core::format::HtmlTheme;
 use std::io;
 use std::io::IsTerminal;
 use std::path::{Path, PathBuf};
@@ -27,6 +28,17 @@ pub fn check(arg_matches: &ArgMatches) -> bool {
     has_flag(arg_matches, "check")
 }
 
+pub fn check_format(arg_matches: &ArgMatches) -> Result<CheckFormat, OptionsError> {
+    if has_flag(arg_matches, "json") {
+        return Ok(CheckFormat::Json);
+    }
+    match get_string(arg_matches, "check_format").unwrap().as_str() {
+        "text" => Ok(CheckFormat::Text),
+        "json" => Ok(CheckFormat::Json),
+        v => Err(OptionsError::Error(format!("Invalid check format {v}"))),
+    }
+}
+
 pub fn color(arg_matches: &ArgMatches) -> bool {
     if has_flag(arg_matches, "color") {
         true
@@ -37,10 +49,66 @@ pub fn color(arg_matches: &ArgMatches) -> bool {
     }
 }
 
+pub fn config_file(arg_matches: &ArgMatches) -> Option<PathBuf> {
+    get_string(arg_matches, "config").map(|s| Path::new(&s).to_path_buf())
+}
+
+pub fn css_file(arg_matches: &ArgMatches) -> Result<Option<PathBuf>, OptionsError> {
+    let css_file = get_string(arg_matches, "css_file").map(|s| Path::new(&s).to_path_buf());
+    if css_file.is_some() && !is_html_output(arg_matches) {
+        return Err(OptionsError::Error(
+            "use --css-file option only with html output".to_string(),
+        ));
+    }
+    Ok(css_file)
+}
+
+pub fn extract_json_bodies(arg_matches: &ArgMatches) -> Result<bool, OptionsError> {
+    json_bodies_flag(arg_matches, "extract_json_bodies", "--extract-json-bodies")
+}
+
+pub fn format_bodies(arg_matches: &ArgMatches) -> Result<bool, OptionsError> {
+    if has_flag(arg_matches, "format_bodies")
+        && get_string(arg_matches, "output_format") != Some("hurl".to_string())
+    {
+        return Err(OptionsError::Error(
+            "use --format-bodies option only with hurl output".to_string(),
+        ));
+    }
+    Ok(has_flag(arg_matches, "format_bodies"))
+}
+
+pub fn inline_json_bodies(arg_matches: &ArgMatches) -> Result<bool, OptionsError> {
+    json_bodies_flag(arg_matches, "inline_json_bodies", "--inline-json-bodies")
+}
+
+/// Returns the flag `name`: JSON body files are relative to the input files, so the option
+/// `option` can not be used with the standard input stream.
+fn json_bodies_flag(
+    arg_matches: &ArgMatches,
+    name: &str,
+    option: &str,
+) -> Result<bool, OptionsError> {
+    if !has_flag(arg_matches, name) {
+        return Ok(false);
+    }
+    if get_string(arg_matches, "input_files").is_none() {
+        return Err(OptionsError::Error(format!(
+            "You can not use {option} with standard input stream!"
+        )));
+    }
+    Ok(true)
+}
+
 pub fn input_format(arg_matches: &ArgMatches) -> Result<InputFormat, OptionsError> {
     match get_string(arg_matches, "input_format").unwrap().as_str() {
         "hurl" => Ok(InputFormat::Hurl),
         "curl" => Ok(InputFormat::Curl),
+        "har" => Ok(InputFormat::Har),
+        "http" => Ok(InputFormat::Http),
+        "json" => Ok(InputFormat::Json),
+        "openapi" => Ok(InputFormat::OpenApi),
+        "postman" => Ok(InputFormat::Postman),
         v => Err(OptionsError::Error(format!("Invalid input format {v}"))),
     }
 }
@@ -59,12 +127,29 @@ pub fn output_format(arg_matches: &ArgMatches) -> Result<OutputFormat, OptionsEr
 
     match get_string(arg_matches, "output_format").unwrap().as_str() {
         "hurl" => Ok(OutputFormat::Hurl),
+        "curl" => Ok(OutputFormat::Curl),
         "json" => Ok(OutputFormat::Json),
         "html" => Ok(OutputFormat::Html),
+        "http" => Ok(OutputFormat::Http),
+        "markdown" => Ok(OutputFormat::Markdown),
         v => Err(OptionsError::Error(format!("Invalid output format {v}"))),
     }
 }
 
+/// Returns the flag `name` of the option `option`, that can be used only with html output.
+pub fn html_flag(arg_matches: &ArgMatches, name: &str, option: &str) -> Result<bool, OptionsError> {
+    if has_flag(arg_matches, name) && !is_html_output(arg_matches) {
+        return Err(OptionsError::Error(format!(
+            "use {option} option only with html output"
+        )));
+    }
+    Ok(has_flag(arg_matches, name))
+}
+
+fn is_html_output(arg_matches: &ArgMatches) -> bool {
+    get_string(arg_matches, "output_format") == Some("html".to_string())
+}
+
 pub fn in_place(arg_matches: &ArgMatches) -> Result<bool, OptionsError> {
     if has_flag(arg_matches, "in_place") {
         if get_string(arg_matches, "format") != Some("hurl".to_string()) {
@@ -109,6 +194,53 @@ pub fn output_file(arg_matches: &ArgMatches) -> Option<PathBuf> {
     get_string(arg_matches, "output").map(|s| Path::new(&s).to_path_buf())
 }
 
+pub fn rename_variables(arg_matches: &ArgMatches) -> Result<Vec<(String, String)>, OptionsError> {
+    let mut renames = vec![];
+    for value in get_strings(arg_matches, "rename_variable").unwrap_or_default() {
+        match value.split_once('=') {
+            Some((old, new)) if is_variable_name(old) && is_variable_name(new) => {
+                renames.push((old.to_string(), new.to_string()));
+            }
+            _ => {
+                return Err(OptionsError::Error(format!(
+                "Invalid value '{value}' for --rename-variable, expecting OLD=NEW variable names"
+            )))
+            }
+        }
+    }
+    Ok(renames)
+}
+
+fn is_variable_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+pub fn replace_url_prefixes(
+    arg_matches: &ArgMatches,
+) -> Result<Vec<(String, String)>, OptionsError> {
+    let mut prefixes = vec![];
+    for value in get_strings(arg_matches, "replace_url_prefix").unwrap_or_default() {
+        match value.split_once('=') {
+            Some((old, new)) if !old.is_empty() => {
+                prefixes.push((old.to_string(), new.to_string()));
+            }
+            _ => {
+                return Err(OptionsError::Error(format!(
+                    "Invalid value '{value}' for --replace-url-prefix, expecting OLD=NEW"
+                )))
+            }
+        }
+    }
+    Ok(prefixes)
+}
+
+pub fn sort_json_keys(arg_matches: &ArgMatches) -> bool {
+    has_flag(arg_matches, "sort_json_keys")
+}
+
 pub fn standalone(arg_matches: &ArgMatches) -> Result<bool, OptionsError> {
     if has_flag(arg_matches, "standalone") {
         if get_string(arg_matches, "output_format") != Some("html".to_string()) {
@@ -123,6 +255,24 @@ pub fn standalone(arg_matches: &ArgMatches) -> Result<bool, OptionsError> {
     }
 }
 
+pub fn theme(arg_matches: &ArgMatches) -> Result<HtmlTheme, OptionsError> {
+    if arg_matches.value_source("theme") == Some(ValueSource::CommandLine)
+        && !is_html_output(arg_matches)
+    {
+        return Err(OptionsError::Error(
+            "use --theme option only with html output".to_string(),
+        ));
+    }
+    match get_string(arg_matches, "theme").unwrap().as_str() {
+        "dark" => Ok(HtmlTheme::Dark),
+        _ => Ok(HtmlTheme::Light),
+    }
+}
+
+pub fn variables_file(arg_matches: &ArgMatches) -> Option<PathBuf> {
+    get_string(arg_matches, "variables_file").map(|s| Path::new(&s).to_path_buf())
+}
+
 fn has_flag(matches: &ArgMatches, name: &str) -> bool {
     matches.get_one::<bool>(name) == Some(&true)
 }
@@ -20,6 +20,7 @@ mod commands;
 mod matches;
 
 use clap::ArgMatches;
+use hurl_core::format::HtmlTheme;
 use std::io::IsTerminal;
 use std::path::PathBuf;
 use std::{env, io};
@@ -27,26 +28,53 @@ use std::{env, io};
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Options {
     pub check: bool,
+    pub check_format: CheckFormat,
     pub color: bool,
+    pub config_file: Option<PathBuf>,
+    pub css_file: Option<PathBuf>,
+    pub extract_json_bodies: bool,
+    pub format_bodies: bool,
     pub in_place: bool,
+    pub inline_json_bodies: bool,
     pub input_files: Vec<String>,
     pub input_format: InputFormat,
+    pub line_numbers: bool,
+    pub links: bool,
     pub output_file: Option<PathBuf>,
     pub output_format: OutputFormat,
+    pub rename_variables: Vec<(String, String)>,
+    pub replace_url_prefixes: Vec<(String, String)>,
+    pub sort_json_keys: bool,
     pub standalone: bool,
+    pub theme: HtmlTheme,
+    pub variables_file: Option<PathBuf>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CheckFormat {
+    Text,
+    Json,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum InputFormat {
     Curl,
+    Har,
+    Http,
+    Json,
+    OpenApi,
+    Postman,
     Hurl,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum OutputFormat {
     Hurl,
+    Curl,
     Json,
     Html,
+    Http,
+    Markdown,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -71,15 +99,29 @@ pub fn parse() -> Result<Options, OptionsError> {
         .disable_colored_help(true)
         .about("Format hurl FILE")
         .arg(commands::check())
+        .arg(commands::check_format())
         .arg(commands::color())
+        .arg(commands::config())
+        .arg(commands::css_file())
+        .arg(commands::extract_json_bodies())
         .arg(commands::format())
+        .arg(commands::format_bodies())
         .arg(commands::in_place())
+        .arg(commands::inline_json_bodies())
         .arg(commands::input_files())
         .arg(commands::input_format())
+        .arg(commands::json())
+        .arg(commands::line_numbers())
+        .arg(commands::links())
         .arg(commands::no_color())
         .arg(commands::output())
         .arg(commands::output_format())
-        .arg(commands::standalone());
+        .arg(commands::rename_variable())
+        .arg(commands::replace_url_prefix())
+        .arg(commands::sort_json_keys())
+        .arg(commands::standalone())
+        .arg(commands::theme())
+        .arg(commands::variables_file());
 
     let arg_matches = command.try_get_matches_from_mut(env::args_os())?;
     let opts = parse_matches(&arg_matches)?;
@@ -93,21 +135,47 @@ pub fn parse() -> Result<Options, OptionsError> {
 
 fn parse_matches(arg_matches: &ArgMatches) -> Result<Options, OptionsError> {
     let check = matches::check(arg_matches);
+    let check_format = matches::check_format(arg_matches)?;
     let color = matches::color(arg_matches);
+    let config_file = matches::config_file(arg_matches);
+    let css_file = matches::css_file(arg_matches)?;
+    let extract_json_bodies = matches::extract_json_bodies(arg_matches)?;
+    let format_bodies = matches::format_bodies(arg_matches)?;
     let in_place = matches::in_place(arg_matches)?;
+    let inline_json_bodies = matches::inline_json_bodies(arg_matches)?;
     let input_files = matches::input_files(arg_matches)?;
     let input_format = matches::input_format(arg_matches)?;
+    let line_numbers = matches::html_flag(arg_matches, "line_numbers", "--line-numbers")?;
+    let links = matches::html_flag(arg_matches, "links", "--links")?;
     let output_file = matches::output_file(arg_matches);
     let output_format = matches::output_format(arg_matches)?;
+    let rename_variables = matches::rename_variables(arg_matches)?;
+    let replace_url_prefixes = matches::replace_url_prefixes(arg_matches)?;
+    let sort_json_keys = matches::sort_json_keys(arg_matches);
     let standalone = matches::standalone(arg_matches)?;
+    let theme = matches::theme(arg_matches)?;
+    let variables_file = matches::variables_file(arg_matches);
     Ok(Options {
         check,
+        check_format,
         color,
+        config_file,
+        css_file,
+        extract_json_bodies,
+        format_bodies,
         in_place,
+        inline_json_bodies,
         input_files,
         input_format,
+        line_numbers,
+        links,
         output_file,
         output_format,
+        rename_variables,
+        replace_url_prefixes,
+        sort_json_keys,
         standalone,
+        theme,
+        variables_file,
     })
 }
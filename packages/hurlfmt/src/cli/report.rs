@@ -0,0 +1,131 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2023 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::error::Error;
+
+use crate::linter;
+use crate::linter::Severity;
+
+/// A linter error found in an input file.
+pub struct LinterReport {
+    pub filename: String,
+    pub error: linter::Error,
+    pub severity: Severity,
+}
+
+/// An input file that is not formatted.
+pub struct FormatReport {
+    pub filename: String,
+    /// First line changed by the formatting.
+    pub line: usize,
+    /// Unified diff between the input file and its formatted text.
+    pub diff: String,
+}
+
+/// Returns a JSON array of the linter `reports`, followed by the `format_reports`, to be consumed
+/// by other tools.
+pub fn format_linter_reports_json(
+    reports: &[LinterReport],
+    format_reports: &[FormatReport],
+) -> String {
+    let format_reports = format_reports.iter().map(|report| {
+        serde_json::json!({
+            "filename": report.filename,
+            "line": report.line,
+            "column": 1,
+            "end_line": report.line,
+            "end_column": 1,
+            "rule": "format",
+            "severity": Severity::Error.as_str(),
+            "message": "File is not formatted",
+            "fixme": "Format the file with hurlfmt",
+            "diff": report.diff,
+        })
+    });
+    let reports = reports
+        .iter()
+        .map(|report| {
+            let source_info = report.error.source_info();
+            serde_json::json!({
+                "filename": report.filename,
+                "line": source_info.start.line,
+                "column": source_info.start.column,
+                "end_line": source_info.end.line,
+                "end_column": source_info.end.column,
+                "rule": report.error.inner.rule(),
+                "severity": report.severity.as_str(),
+                "message": report.error.description().trim(),
+                "fixme": report.error.fixme(),
+            })
+        })
+        .chain(format_reports)
+        .collect::<Vec<_>>();
+    serde_json::to_string_pretty(&reports).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::SourceInfo;
+
+    use super::*;
+    use crate::linter::LinterError;
+
+    #[test]
+    fn test_format_json() {
+        let reports = vec![LinterReport {
+            filename: "test.hurl".to_string(),
+            error: linter::Error {
+                source_info: SourceInfo::new(1, 4, 1, 5),
+                inner: LinterError::OneSpace,
+            },
+            severity: Severity::Error,
+        }];
+        let format_reports = vec![FormatReport {
+            filename: "test.hurl".to_string(),
+            line: 1,
+            diff: "--- test.hurl\n+++ test.hurl (formatted)\n".to_string(),
+        }];
+        assert_eq!(
+            format_linter_reports_json(&reports, &format_reports),
+            r#"[
+  {
+    "column": 4,
+    "end_column": 5,
+    "end_line": 1,
+    "filename": "test.hurl",
+    "fixme": "Use only one space",
+    "line": 1,
+    "message": "One space",
+    "rule": "one-space",
+    "severity": "error"
+  },
+  {
+    "column": 1,
+    "diff": "--- test.hurl\n+++ test.hurl (formatted)\n",
+    "end_column": 1,
+    "end_line": 1,
+    "filename": "test.hurl",
+    "fixme": "Format the file with hurlfmt",
+    "line": 1,
+    "message": "File is not formatted",
+    "rule": "format",
+    "severity": "error"
+  }
+]"#
+        );
+    }
+}
@@ -17,18 +17,72 @@
  */
 use clap::{value_parser, ArgAction};
 
+pub fn cacert() -> clap::Arg {
+    clap::Arg::new("cacert")
+        .long("cacert")
+        .value_name("FILE")
+        .num_args(1)
+}
+
+pub fn cert() -> clap::Arg {
+    clap::Arg::new("cert")
+        .long("cert")
+        .short('E')
+        .value_name("CERTIFICATE[:PASSWORD]")
+        .num_args(1)
+}
+
 pub fn compressed() -> clap::Arg {
     clap::Arg::new("compressed").long("compressed").num_args(0)
 }
 
+pub fn cookies() -> clap::Arg {
+    clap::Arg::new("cookies")
+        .long("cookie")
+        .short('b')
+        .value_name("NAME1=VALUE1; NAME2=VALUE2")
+        .action(ArgAction::Append)
+        .num_args(1)
+}
+
 pub fn data() -> clap::Arg {
     clap::Arg::new("data")
         .long("data")
         .short('d')
         .value_name("data")
+        .action(ArgAction::Append)
+        .num_args(1)
+}
+
+pub fn data_binary() -> clap::Arg {
+    clap::Arg::new("data_binary")
+        .long("data-binary")
+        .value_name("data")
+        .action(ArgAction::Append)
+        .num_args(1)
+}
+
+pub fn data_urlencode() -> clap::Arg {
+    clap::Arg::new("data_urlencode")
+        .long("data-urlencode")
+        .value_name("data")
+        .action(ArgAction::Append)
         .num_args(1)
 }
 
+pub fn form() -> clap::Arg {
+    clap::Arg::new("form")
+        .long("form")
+        .short('F')
+        .value_name("NAME=CONTENT")
+        .action(ArgAction::Append)
+        .num_args(1)
+}
+
+pub fn get() -> clap::Arg {
+    clap::Arg::new("get").long("get").short('G').num_args(0)
+}
+
 pub fn headers() -> clap::Arg {
     clap::Arg::new("headers")
         .long("header")
@@ -45,6 +99,14 @@ pub fn insecure() -> clap::Arg {
         .num_args(0)
 }
 
+pub fn json() -> clap::Arg {
+    clap::Arg::new("json")
+        .long("json")
+        .value_name("data")
+        .action(ArgAction::Append)
+        .num_args(1)
+}
+
 pub fn location() -> clap::Arg {
     clap::Arg::new("location")
         .long("location")
@@ -69,6 +131,22 @@ pub fn method() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn proxy() -> clap::Arg {
+    clap::Arg::new("proxy")
+        .long("proxy")
+        .short('x')
+        .value_name("[PROTOCOL://]HOST[:PORT]")
+        .num_args(1)
+}
+
+pub fn resolve() -> clap::Arg {
+    clap::Arg::new("resolve")
+        .long("resolve")
+        .value_name("HOST:PORT:ADDR")
+        .action(ArgAction::Append)
+        .num_args(1)
+}
+
 pub fn retry() -> clap::Arg {
     clap::Arg::new("retry")
         .long("retry")
@@ -77,6 +155,14 @@ pub fn retry() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn user() -> clap::Arg {
+    clap::Arg::new("user")
+        .long("user")
+        .short('u')
+        .value_name("USER:PASSWORD")
+        .num_args(1)
+}
+
 pub fn url() -> clap::Arg {
     clap::Arg::new("url")
         .help("Sets the url to use")
@@ -16,25 +16,51 @@
  *
  */
 use super::HurlOption;
+use crate::escape::{escape_key, escape_value, url_decode};
 use clap::ArgMatches;
 
-pub fn body(arg_matches: &ArgMatches) -> Option<String> {
-    match get_string(arg_matches, "data") {
-        None => None,
-        Some(v) => {
-            if let Some(filename) = v.strip_prefix('@') {
-                Some(format!("file, {filename};"))
-            } else {
-                Some(format!("```\n{v}\n```"))
-            }
+/// Returns the request body from `-d/--data`, `--data-binary` and `--json` arguments.
+///
+/// Data values are joined with `&`, while `--json` values are concatenated. Data read from a
+/// file (`@file`) can't be mixed with other data.
+pub fn body(arg_matches: &ArgMatches) -> Result<Option<String>, String> {
+    if has_flag(arg_matches, "get") || has_form_params(arg_matches) {
+        return Ok(None);
+    }
+    let data = data(arg_matches);
+    let json = get_strings(arg_matches, "json").unwrap_or_default();
+    let (values, separator) = match (data.is_empty(), json.is_empty()) {
+        (true, true) => return Ok(None),
+        (false, true) => (data, "&"),
+        (true, false) => (json, ""),
+        (false, false) => return Err("--json is not supported with other data".to_string()),
+    };
+    if values.len() > 1 {
+        if let Some(file) = values.iter().find(|v| v.starts_with('@')) {
+            return Err(format!(
+                "data file <{file}> is not supported with other data"
+            ));
         }
     }
+    let v = values.join(separator);
+    if let Some(filename) = v.strip_prefix('@') {
+        Ok(Some(format!("file, {filename};")))
+    } else {
+        Ok(Some(format!("```\n{v}\n```")))
+    }
 }
 
 pub fn method(arg_matches: &ArgMatches) -> String {
     match get_string(arg_matches, "method") {
         None => {
-            if arg_matches.contains_id("data") {
+            if has_flag(arg_matches, "get") {
+                "GET".to_string()
+            } else if arg_matches.contains_id("data")
+                || arg_matches.contains_id("data_binary")
+                || arg_matches.contains_id("data_urlencode")
+                || arg_matches.contains_id("json")
+                || arg_matches.contains_id("form")
+            {
                 "POST".to_string()
             } else {
                 "GET".to_string()
@@ -54,23 +80,113 @@ pub fn url(arg_matches: &ArgMatches) -> String {
 }
 
 pub fn headers(arg_matches: &ArgMatches) -> Vec<String> {
-    let mut headers = match get_strings(arg_matches, "headers") {
-        None => vec![],
-        Some(v) => v,
-    };
-    if !has_content_type(&headers) {
-        if let Some(data) = get_string(arg_matches, "data") {
-            if !data.starts_with('@') {
-                headers.push("Content-Type: application/x-www-form-urlencoded".to_string())
-            }
+    let mut headers = get_strings(arg_matches, "headers").unwrap_or_default();
+    if arg_matches.contains_id("json") {
+        if !has_header(&headers, "Content-Type") {
+            headers.push("Content-Type: application/json".to_string())
+        }
+        if !has_header(&headers, "Accept") {
+            headers.push("Accept: application/json".to_string())
+        }
+    }
+    if !has_header(&headers, "Content-Type") && matches!(body(arg_matches), Ok(Some(_))) {
+        let data = data(arg_matches).join("&");
+        if !data.starts_with('@') {
+            headers.push("Content-Type: application/x-www-form-urlencoded".to_string())
         }
     }
 
     headers
 }
 
+/// Returns the `[QueryStringParams]` section lines.
+///
+/// With `-G/--get`, data given with `-d`, `--data-binary` and `--data-urlencode` are sent
+/// in the URL query string instead of the body.
+pub fn query_params(arg_matches: &ArgMatches) -> Result<Vec<String>, String> {
+    if !has_flag(arg_matches, "get") {
+        return Ok(vec![]);
+    }
+    params(arg_matches)
+}
+
+/// Returns the `[FormParams]` section lines, used when `--data-urlencode` is present.
+pub fn form_params(arg_matches: &ArgMatches) -> Result<Vec<String>, String> {
+    if !has_form_params(arg_matches) {
+        return Ok(vec![]);
+    }
+    params(arg_matches)
+}
+
+/// Returns the `[MultipartFormData]` section lines from `-F/--form` arguments.
+pub fn multipart_params(arg_matches: &ArgMatches) -> Result<Vec<String>, String> {
+    let mut params = vec![];
+    for form in get_strings(arg_matches, "form").unwrap_or_default() {
+        let (name, content) = match form.split_once('=') {
+            Some(v) => v,
+            None => return Err(format!("invalid form parameter <{form}>")),
+        };
+        let name = escape_key(name);
+        if let Some(file) = content.strip_prefix('@') {
+            // File parameters can have additional attributes: we only keep the content type.
+            let mut attributes = file.split(';');
+            let filename = attributes.next().unwrap_or_default();
+            let content_type = attributes.find_map(|a| a.trim().strip_prefix("type="));
+            match content_type {
+                Some(content_type) => {
+                    params.push(format!("{name}: file,{filename}; {content_type}"))
+                }
+                None => params.push(format!("{name}: file,{filename};")),
+            }
+        } else if content.starts_with('<') {
+            return Err(format!(
+                "form parameter <{form}> with content read from a file is not supported"
+            ));
+        } else {
+            params.push(format!("{name}: {}", escape_value(content)));
+        }
+    }
+    Ok(params)
+}
+
+/// Returns the `[Cookies]` section lines from `-b/--cookie` arguments.
+pub fn cookies(arg_matches: &ArgMatches) -> Result<Vec<String>, String> {
+    let mut cookies = vec![];
+    for cookie in get_strings(arg_matches, "cookies").unwrap_or_default() {
+        if !cookie.contains('=') {
+            return Err(format!("cookie file <{cookie}> is not supported"));
+        }
+        for c in cookie.split(';') {
+            let c = c.trim();
+            if c.is_empty() {
+                continue;
+            }
+            match c.split_once('=') {
+                Some((name, value)) => {
+                    cookies.push(format!("{}: {}", escape_key(name), escape_value(value)))
+                }
+                None => return Err(format!("invalid cookie <{c}>")),
+            }
+        }
+    }
+    Ok(cookies)
+}
+
+/// Returns the `[BasicAuth]` section line from `-u/--user` argument.
+pub fn basic_auth(arg_matches: &ArgMatches) -> Option<String> {
+    let user = get_string(arg_matches, "user")?;
+    let (name, password) = user.split_once(':').unwrap_or((user.as_str(), ""));
+    Some(format!("{}: {}", escape_key(name), escape_value(password)))
+}
+
 pub fn options(arg_matches: &ArgMatches) -> Vec<HurlOption> {
     let mut options = vec![];
+    if let Some(value) = get_string(arg_matches, "cacert") {
+        options.push(HurlOption::new("cacert", value.as_str()));
+    }
+    if let Some(value) = get_string(arg_matches, "cert") {
+        options.push(HurlOption::new("cert", value.as_str()));
+    }
     if has_flag(arg_matches, "compressed") {
         options.push(HurlOption::new("compressed", "true"));
     }
@@ -83,19 +199,64 @@ pub fn options(arg_matches: &ArgMatches) -> Vec<HurlOption> {
     if let Some(value) = get::<i32>(arg_matches, "max_redirects") {
         options.push(HurlOption::new("max-redirs", value.to_string().as_str()));
     }
+    if let Some(value) = get_string(arg_matches, "proxy") {
+        options.push(HurlOption::new("proxy", value.as_str()));
+    }
+    for value in get_strings(arg_matches, "resolve").unwrap_or_default() {
+        options.push(HurlOption::new("resolve", value.as_str()));
+    }
     if let Some(value) = get::<i32>(arg_matches, "retry") {
         options.push(HurlOption::new("retry", value.to_string().as_str()));
     }
     options
 }
 
-fn has_content_type(headers: &Vec<String>) -> bool {
-    for header in headers {
-        if header.starts_with("Content-Type") {
-            return true;
+/// Returns the values of `-d/--data` and `--data-binary` arguments.
+fn data(arg_matches: &ArgMatches) -> Vec<String> {
+    let mut values = get_strings(arg_matches, "data").unwrap_or_default();
+    values.extend(get_strings(arg_matches, "data_binary").unwrap_or_default());
+    values
+}
+
+/// Returns [`true`] if data must be sent as a `[FormParams]` section, [`false`] otherwise.
+fn has_form_params(arg_matches: &ArgMatches) -> bool {
+    !has_flag(arg_matches, "get") && arg_matches.contains_id("data_urlencode")
+}
+
+/// Returns all the data arguments as a list of `name: value` lines.
+fn params(arg_matches: &ArgMatches) -> Result<Vec<String>, String> {
+    let mut params = vec![];
+    for value in data(arg_matches) {
+        if value.starts_with('@') {
+            return Err(format!(
+                "data file <{value}> is not supported with parameters"
+            ));
+        }
+        // Data from `-d/--data` and `--data-binary` is already url-encoded.
+        for param in value.split('&').filter(|p| !p.is_empty()) {
+            let (name, value) = param.split_once('=').unwrap_or((param, ""));
+            let name = url_decode(name);
+            let value = url_decode(value);
+            params.push(format!("{}: {}", escape_key(&name), escape_value(&value)));
+        }
+    }
+    for value in get_strings(arg_matches, "data_urlencode").unwrap_or_default() {
+        match value.split_once('=') {
+            Some((name, value)) if !name.is_empty() => {
+                params.push(format!("{}: {}", escape_key(name), escape_value(value)))
+            }
+            _ => return Err(format!("data <{value}> without name is not supported")),
         }
     }
-    false
+    Ok(params)
+}
+
+/// Returns true if `headers` contains a header `name` (case insensitive).
+fn has_header(headers: &[String], name: &str) -> bool {
+    headers.iter().any(|header| match header.split_once(':') {
+        Some((header_name, _)) => header_name.trim().eq_ignore_ascii_case(name),
+        None => false,
+    })
 }
 
 fn has_flag(matches: &ArgMatches, name: &str) -> bool {
@@ -117,3 +278,19 @@ fn get_strings(matches: &ArgMatches, name: &str) -> Option<Vec<String>> {
         .get_many::<String>(name)
         .map(|v| v.map(|x| x.to_string()).collect())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_has_header() {
+        let headers = vec![
+            "content-type: application/json".to_string(),
+            "Content-Type-Foo: bar".to_string(),
+        ];
+        assert!(has_header(&headers, "Content-Type"));
+        assert!(!has_header(&headers[1..], "Content-Type"));
+        assert!(!has_header(&headers, "Accept"));
+    }
+}
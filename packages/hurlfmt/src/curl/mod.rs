@@ -42,6 +42,37 @@ impl fmt::Display for HurlOption {
     }
 }
 
+/// A request section (`[Cookies]`, `[QueryStringParams]` etc...) with its lines.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HurlSection {
+    name: String,
+    lines: Vec<String>,
+}
+
+impl HurlSection {
+    pub fn new(name: &str, lines: Vec<String>) -> HurlSection {
+        HurlSection {
+            name: name.to_string(),
+            lines,
+        }
+    }
+
+    /// Returns true if this section has no line.
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+}
+
+impl fmt::Display for HurlSection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}]", self.name)?;
+        for line in &self.lines {
+            write!(f, "\n{line}")?;
+        }
+        Ok(())
+    }
+}
+
 pub fn parse(s: &str) -> Result<String, String> {
     let lines: Vec<&str> = regex::Regex::new(r"\n|\r\n")
         .unwrap()
@@ -60,14 +91,25 @@ pub fn parse(s: &str) -> Result<String, String> {
 
 fn parse_line(s: &str) -> Result<String, String> {
     let mut command = clap::Command::new("curl")
+        .arg(commands::cacert())
+        .arg(commands::cert())
         .arg(commands::compressed())
+        .arg(commands::cookies())
         .arg(commands::data())
+        .arg(commands::data_binary())
+        .arg(commands::data_urlencode())
+        .arg(commands::form())
+        .arg(commands::get())
         .arg(commands::headers())
         .arg(commands::insecure())
+        .arg(commands::json())
         .arg(commands::location())
         .arg(commands::max_redirects())
         .arg(commands::method())
+        .arg(commands::proxy())
+        .arg(commands::resolve())
         .arg(commands::retry())
+        .arg(commands::user())
         .arg(commands::url());
 
     let params = args::split(s)?;
@@ -79,9 +121,21 @@ fn parse_line(s: &str) -> Result<String, String> {
     let method = matches::method(&arg_matches);
     let url = matches::url(&arg_matches);
     let headers = matches::headers(&arg_matches);
+    let mut sections = vec![
+        HurlSection::new("QueryStringParams", matches::query_params(&arg_matches)?),
+        HurlSection::new("FormParams", matches::form_params(&arg_matches)?),
+        HurlSection::new(
+            "MultipartFormData",
+            matches::multipart_params(&arg_matches)?,
+        ),
+        HurlSection::new("Cookies", matches::cookies(&arg_matches)?),
+    ];
+    if let Some(basic_auth) = matches::basic_auth(&arg_matches) {
+        sections.push(HurlSection::new("BasicAuth", vec![basic_auth]));
+    }
     let options = matches::options(&arg_matches);
-    let body = matches::body(&arg_matches);
-    let s = format(&method, &url, headers, &options, body);
+    let body = matches::body(&arg_matches)?;
+    let s = format(&method, &url, headers, &sections, &options, body);
     Ok(s)
 }
 
@@ -89,6 +143,7 @@ fn format(
     method: &str,
     url: &str,
     headers: Vec<String>,
+    sections: &[HurlSection],
     options: &[HurlOption],
     body: Option<String>,
 ) -> String {
@@ -96,6 +151,11 @@ fn format(
     for header in headers {
         s.push_str(format!("\n{header}").as_str());
     }
+    for section in sections {
+        if !section.lines.is_empty() {
+            s.push_str(format!("\n{section}").as_str());
+        }
+    }
     if !options.is_empty() {
         s.push_str("\n[Options]");
         for option in options {
@@ -256,6 +316,48 @@ file, filename;
         );
     }
 
+    #[test]
+    fn test_post_json_option() {
+        let hurl_str = r#"POST http://localhost:3000/data
+Content-Type: application/json
+Accept: application/json
+```
+{"key1":"value1", "key2":"value2"}
+```
+"#;
+        assert_eq!(
+            parse_line(r#"curl --json '{"key1":"value1", ' --json '"key2":"value2"}' http://localhost:3000/data"#).unwrap(),
+            hurl_str
+        );
+
+        let hurl_str = r#"POST http://localhost:3000/data
+Content-Type: application/json
+Accept: application/json
+file, data.json;
+"#;
+        assert_eq!(
+            parse_line(r#"curl --json @data.json http://localhost:3000/data"#).unwrap(),
+            hurl_str
+        );
+        assert_eq!(
+            parse_line(r#"curl --json '{}' -d 'a=1' http://localhost:3000/data"#).unwrap_err(),
+            "--json is not supported with other data"
+        );
+    }
+
+    #[test]
+    fn test_post_file_with_data() {
+        assert_eq!(
+            parse_line(r#"curl -d 'a=1' -d @data.txt http://localhost:3000/data"#).unwrap_err(),
+            "data file <@data.txt> is not supported with other data"
+        );
+        assert_eq!(
+            parse_line(r#"curl --json @a.json --json @b.json http://localhost:3000/data"#)
+                .unwrap_err(),
+            "data file <@a.json> is not supported with other data"
+        );
+    }
+
     #[test]
     fn test_redirect() {
         let hurl_str = r#"GET http://localhost:8000/redirect-absolute
@@ -291,4 +393,118 @@ max-redirs: 10
             hurl_str
         );
     }
+
+    #[test]
+    fn test_cookies() {
+        let hurl_str = r#"GET http://localhost:8000/cookies
+[Cookies]
+user1: Bob
+user2: Bill
+"#;
+        assert_eq!(
+            parse_line(r#"curl -b 'user1=Bob; user2=Bill' http://localhost:8000/cookies"#).unwrap(),
+            hurl_str
+        );
+        assert!(parse_line(r#"curl -b cookies.txt http://localhost:8000/cookies"#).is_err());
+    }
+
+    #[test]
+    fn test_basic_auth() {
+        let hurl_str = r#"GET http://localhost:8000/basic-authentication
+[BasicAuth]
+bob: secret\#1
+"#;
+        assert_eq!(
+            parse_line(r#"curl -u 'bob:secret#1' http://localhost:8000/basic-authentication"#)
+                .unwrap(),
+            hurl_str
+        );
+    }
+
+    #[test]
+    fn test_multipart_form_data() {
+        let hurl_str = r#"POST http://localhost:8000/multipart-form-data
+[MultipartFormData]
+key1: value1
+upload1: file,data.txt; text/plain
+upload2: file,data.html;
+"#;
+        assert_eq!(
+            parse_line(r#"curl -F 'key1=value1' -F 'upload1=@data.txt;type=text/plain' --form 'upload2=@data.html' http://localhost:8000/multipart-form-data"#).unwrap(),
+            hurl_str
+        );
+        assert!(parse_line(
+            r#"curl -F 'key1=<data.txt' http://localhost:8000/multipart-form-data"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_data_urlencode() {
+        let hurl_str = r#"POST http://localhost:8000/form-params
+[FormParams]
+param1: value1
+param2: a b&c
+"#;
+        assert_eq!(
+            parse_line(r#"curl -d 'param1=value1' --data-urlencode 'param2=a b&c' http://localhost:8000/form-params"#).unwrap(),
+            hurl_str
+        );
+    }
+
+    #[test]
+    fn test_get() {
+        let hurl_str = r#"GET http://localhost:8000/querystring-params
+[QueryStringParams]
+param1: value1
+param2: 
+param3: a=b
+param4: 1,2,3
+"#;
+        assert_eq!(
+            parse_line(r#"curl -G -d 'param1=value1&param2=&param3=a%3Db' --data-urlencode 'param4=1,2,3' http://localhost:8000/querystring-params"#).unwrap(),
+            hurl_str
+        );
+    }
+
+    #[test]
+    fn test_data_binary() {
+        let hurl_str = r#"POST http://localhost:8000/post-file
+file, data.bin;
+"#;
+        assert_eq!(
+            parse_line(r#"curl --data-binary @data.bin http://localhost:8000/post-file"#).unwrap(),
+            hurl_str
+        );
+    }
+
+    #[test]
+    fn test_json() {
+        let hurl_str = r#"POST http://localhost:8000/post-json
+Content-Type: application/json
+Accept: application/json
+```
+{"name": "Bob"}
+```
+"#;
+        assert_eq!(
+            parse_line(r#"curl --json '{"name": "Bob"}' http://localhost:8000/post-json"#).unwrap(),
+            hurl_str
+        );
+    }
+
+    #[test]
+    fn test_options() {
+        let hurl_str = r#"GET https://localhost:8001/hello
+[Options]
+cacert: ca.pem
+cert: client.pem
+proxy: http://localhost:3128
+resolve: foo.com:8001:127.0.0.1
+"#;
+        assert_eq!(
+            parse_line(r#"curl --cacert ca.pem --cert client.pem -x http://localhost:3128 --resolve foo.com:8001:127.0.0.1 https://localhost:8001/hello"#).unwrap(),
+            hurl_str
+        );
+    }
 }
@@ -0,0 +1,198 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2023 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+//! Helpers to produce valid Hurl strings from imported formats (curl, HAR etc...).
+use serde_yaml::Value;
+
+/// Encodes a string to be used as a Hurl key.
+pub fn escape_key(s: &str) -> String {
+    s.chars()
+        .map(|c| {
+            if c.is_alphanumeric() || "_-.[]@$".contains(c) {
+                c.to_string()
+            } else {
+                format!("\\u{{{:x}}}", c as u32)
+            }
+        })
+        .collect()
+}
+
+/// Encodes a string to be used as a Hurl value.
+pub fn escape_value(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('#', "\\#")
+}
+
+/// Encodes a string to be used in a Hurl quoted string (without the quotes).
+pub fn escape_string(s: &str) -> String {
+    let mut escaped = String::new();
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Decodes an url-encoded string (`+` being decoded as a space).
+pub fn url_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut decoded = vec![];
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes
+            .get(i + 1..i + 3)
+            .and_then(|h| std::str::from_utf8(h).ok())
+            .and_then(|h| u8::from_str_radix(h, 16).ok());
+        match (bytes[i], hex) {
+            (b'%', Some(b)) => {
+                decoded.push(b);
+                i += 2;
+            }
+            (b'+', _) => decoded.push(b' '),
+            (b, _) => decoded.push(b),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&decoded).to_string()
+}
+
+/// Returns a valid Hurl variable name from an imported variable `name`.
+pub fn variable_name(name: &str) -> String {
+    name.trim_start_matches('$')
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '_' || c == '-' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// Encodes the braces of a dynamic variable `s` to be used as text in a Hurl value.
+pub fn escape_value_braces(s: &str) -> String {
+    s.replace('{', "\\u{7b}")
+}
+
+/// Encodes the braces of a dynamic variable `s` to be used as text in a Hurl multiline body,
+/// where backslashes are kept as is.
+pub fn escape_body_braces(s: &str) -> String {
+    s.replace('{', "\\{")
+}
+
+/// Encodes the braces of a dynamic variable `s` to be used as text in an url.
+pub fn escape_url_braces(s: &str) -> String {
+    s.replace('{', "%7B").replace('}', "%7D")
+}
+
+/// Returns the text of a scalar YAML/JSON `value`, other values being serialized to JSON.
+pub fn scalar_to_string(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::Bool(b) => b.to_string(),
+        Value::Number(n) => n.to_string(),
+        Value::String(s) => s.clone(),
+        _ => to_json(value, 0),
+    }
+}
+
+/// Serializes a `value` to JSON, keeping the order of the object keys.
+pub fn to_json(value: &Value, level: usize) -> String {
+    let indent = "    ".repeat(level + 1);
+    let end_indent = "    ".repeat(level);
+    match value {
+        Value::Null => "null".to_string(),
+        Value::Bool(b) => b.to_string(),
+        Value::Number(n) => n.to_string(),
+        Value::String(s) => serde_json::to_string(s).unwrap(),
+        Value::Sequence(items) if items.is_empty() => "[]".to_string(),
+        Value::Sequence(items) => {
+            let items = items
+                .iter()
+                .map(|v| format!("{indent}{}", to_json(v, level + 1)))
+                .collect::<Vec<_>>();
+            format!("[\n{}\n{end_indent}]", items.join(",\n"))
+        }
+        Value::Mapping(m) if m.is_empty() => "{}".to_string(),
+        Value::Mapping(m) => {
+            let items = m
+                .iter()
+                .map(|(k, v)| {
+                    let key = serde_json::to_string(&scalar_to_string(k)).unwrap();
+                    format!("{indent}{key}: {}", to_json(v, level + 1))
+                })
+                .collect::<Vec<_>>();
+            format!("{{\n{}\n{end_indent}}}", items.join(",\n"))
+        }
+        Value::Tagged(tagged) => to_json(&tagged.value, level),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_key() {
+        assert_eq!(escape_key("values[0]"), "values[0]");
+        assert_eq!(escape_key("a:b c"), "a\\u{3a}b\\u{20}c");
+    }
+
+    #[test]
+    fn test_escape_string() {
+        assert_eq!(escape_string("a\"b\\c\nd"), "a\\\"b\\\\c\\nd");
+    }
+
+    #[test]
+    fn test_escape_value() {
+        assert_eq!(escape_value("a b"), "a b");
+        assert_eq!(escape_value("#1\\"), "\\#1\\\\");
+    }
+
+    #[test]
+    fn test_url_decode() {
+        assert_eq!(url_decode("a%3Db"), "a=b");
+        assert_eq!(url_decode("a+b%20c"), "a b c");
+        assert_eq!(url_decode("100%"), "100%");
+        assert_eq!(url_decode("%E2%82%AC"), "€");
+    }
+
+    #[test]
+    fn test_to_json() {
+        let value: Value = serde_yaml::from_str("[]").unwrap();
+        assert_eq!(to_json(&value, 0), "[]");
+        let value: Value = serde_yaml::from_str("{b: [1, \"x\\\"y\"], a: {}, c: null}").unwrap();
+        assert_eq!(
+            to_json(&value, 0),
+            r#"{
+    "b": [
+        1,
+        "x\"y"
+    ],
+    "a": {},
+    "c": null
+}"#
+        );
+    }
+}
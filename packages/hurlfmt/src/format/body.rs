@@ -0,0 +1,352 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2023 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+//! Pretty-printing of request bodies.
+//!
+//! JSON and XML bodies (inline or in `json` / `xml` multiline strings) are re-indented with two
+//! spaces. Templates are kept as is. Response bodies are not modified: they are compared byte
+//! for byte to the actual response.
+use hurl_core::ast::*;
+use hurl_core::parser;
+
+use crate::format::text::format_token;
+use crate::format::token::Tokenizable;
+
+const INDENT: &str = "  ";
+
+/// Returns a copy of `hurl_file` with pretty-printed request bodies, and JSON object keys sorted
+/// if `sort_keys` is true.
+pub fn format(hurl_file: &HurlFile, sort_keys: bool) -> HurlFile {
+    let mut hurl_file = hurl_file.clone();
+    for entry in &mut hurl_file.entries {
+        if let Some(body) = &mut entry.request.body {
+            format_bytes(&mut body.value, sort_keys);
+        }
+    }
+    hurl_file
+}
+
+fn format_bytes(bytes: &mut Bytes, sort_keys: bool) {
+    match bytes {
+        Bytes::Json(value) => format_json(value, 0, sort_keys),
+        Bytes::Xml(value) => {
+            if let Some(xml) = format_xml(value) {
+                *value = xml;
+            }
+        }
+        Bytes::MultilineString(multiline) => {
+            if let Some(formatted) = format_multiline(multiline, sort_keys) {
+                *bytes = formatted;
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Re-indents the `value` at the nesting `level`.
+fn format_json(value: &mut JsonValue, level: usize, sort_keys: bool) {
+    let newline = |level: usize| format!("\n{}", INDENT.repeat(level));
+    match value {
+        JsonValue::List { space0, elements } => {
+            *space0 = if elements.is_empty() {
+                String::new()
+            } else {
+                newline(level + 1)
+            };
+            let count = elements.len();
+            for (i, element) in elements.iter_mut().enumerate() {
+                element.space0 = if i == 0 {
+                    String::new()
+                } else {
+                    newline(level + 1)
+                };
+                format_json(&mut element.value, level + 1, sort_keys);
+                element.space1 = if i == count - 1 {
+                    newline(level)
+                } else {
+                    String::new()
+                };
+            }
+        }
+        JsonValue::Object { space0, elements } => {
+            *space0 = if elements.is_empty() {
+                String::new()
+            } else {
+                newline(level + 1)
+            };
+            if sort_keys {
+                elements.sort_by_key(|e| to_text(e.name.tokenize()));
+            }
+            let count = elements.len();
+            for (i, element) in elements.iter_mut().enumerate() {
+                element.space0 = if i == 0 {
+                    String::new()
+                } else {
+                    newline(level + 1)
+                };
+                element.space1 = String::new();
+                element.space2 = " ".to_string();
+                format_json(&mut element.value, level + 1, sort_keys);
+                element.space3 = if i == count - 1 {
+                    newline(level)
+                } else {
+                    String::new()
+                };
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Formats a multiline string: JSON and XML contents are re-indented, and spaces after the
+/// language are removed. Returns `None` if the string can't be formatted.
+fn format_multiline(multiline: &MultilineString, sort_keys: bool) -> Option<Bytes> {
+    let content = match multiline {
+        MultilineString::Json(text) => {
+            let content = to_text(text.value.tokenize());
+            let Some(Bytes::Json(mut value)) = parse_body(content.trim()) else {
+                return None;
+            };
+            format_json(&mut value, 0, sort_keys);
+            to_text(value.tokenize())
+        }
+        MultilineString::Xml(text) => {
+            let content = to_text(text.value.tokenize());
+            format_xml(content.trim())?
+        }
+        MultilineString::Text(_) | MultilineString::GraphQl(_) => {
+            return normalize_multiline(multiline)
+        }
+        MultilineString::OneLineText(_) => return None,
+    };
+    parse_body(&format!("```{}\n{content}\n```", multiline.lang()))
+}
+
+/// Removes the spaces between the language of a multiline string and the first newline.
+fn normalize_multiline(multiline: &MultilineString) -> Option<Bytes> {
+    let mut multiline = multiline.clone();
+    let space = match &mut multiline {
+        MultilineString::Text(text) => &mut text.space,
+        MultilineString::GraphQl(graphql) => &mut graphql.space,
+        _ => return None,
+    };
+    if space.value.is_empty() {
+        return None;
+    }
+    space.value = String::new();
+    Some(Bytes::MultilineString(multiline))
+}
+
+/// Parses `text` as the body of a request.
+fn parse_body(text: &str) -> Option<Bytes> {
+    let hurl_file = parser::parse_hurl_file(&format!("GET http://localhost\n{text}\n")).ok()?;
+    let entry = hurl_file.entries.into_iter().next()?;
+    entry.request.body.map(|body| body.value)
+}
+
+/// Returns the text of these `tokens`, as written in a Hurl file.
+fn to_text(tokens: Vec<crate::format::Token>) -> String {
+    tokens
+        .into_iter()
+        .map(|token| format_token(token, false))
+        .collect()
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum XmlToken<'a> {
+    Open(&'a str),
+    Close(&'a str),
+    /// Empty element, comment, CDATA, processing instruction or document type.
+    Other(&'a str),
+    Text(&'a str),
+}
+
+/// Re-indents the XML document `xml`, one element per line.
+///
+/// Elements whose content is a single text (or CDATA) are kept on one line. Returns `None` if the
+/// document has mixed content (texts and elements), as its whitespaces are significant.
+fn format_xml(xml: &str) -> Option<String> {
+    let tokens = xml_tokens(xml)?;
+    let mut lines = vec![];
+    let mut level = 0_usize;
+    let mut i = 0;
+    while i < tokens.len() {
+        let indent = INDENT.repeat(level);
+        match tokens[i..] {
+            // Texts never start with `<`: the only other inlined content is CDATA.
+            [XmlToken::Open(open), XmlToken::Text(s) | XmlToken::Other(s), XmlToken::Close(close), ..]
+                if !s.starts_with('<') || s.starts_with("<![CDATA[") =>
+            {
+                lines.push(format!("{indent}{open}{s}{close}"));
+                i += 3;
+            }
+            [XmlToken::Open(open), XmlToken::Close(close), ..] => {
+                lines.push(format!("{indent}{open}{close}"));
+                i += 2;
+            }
+            [XmlToken::Open(open), ..] => {
+                lines.push(format!("{indent}{open}"));
+                level += 1;
+                i += 1;
+            }
+            [XmlToken::Close(close), ..] => {
+                level = level.checked_sub(1)?;
+                lines.push(format!("{}{close}", INDENT.repeat(level)));
+                i += 1;
+            }
+            [XmlToken::Other(other), ..] => {
+                lines.push(format!("{indent}{other}"));
+                i += 1;
+            }
+            [XmlToken::Text(text), ..] => {
+                if !text.trim().is_empty() {
+                    return None;
+                }
+                i += 1;
+            }
+            [] => break,
+        }
+    }
+    if level != 0 {
+        return None;
+    }
+    Some(lines.join("\n"))
+}
+
+fn xml_tokens<'a>(xml: &'a str) -> Option<Vec<XmlToken<'a>>> {
+    let mut tokens = vec![];
+    let mut rest = xml;
+    while !rest.is_empty() {
+        let (end, kind): (usize, fn(&'a str) -> XmlToken<'a>) = if rest.starts_with("<!--") {
+            (rest.find("-->")? + 3, XmlToken::Other)
+        } else if rest.starts_with("<![CDATA[") {
+            (rest.find("]]>")? + 3, XmlToken::Other)
+        } else if rest.starts_with("<?") {
+            (rest.find("?>")? + 2, XmlToken::Other)
+        } else if rest.starts_with("<!") {
+            let end = rest.find('>')? + 1;
+            // Document types with an internal subset are not supported.
+            if rest[..end].contains('[') {
+                return None;
+            }
+            (end, XmlToken::Other)
+        } else if rest.starts_with("</") {
+            (rest.find('>')? + 1, XmlToken::Close)
+        } else if rest.starts_with('<') {
+            let end = tag_end(rest)?;
+            if rest[..end].ends_with("/>") {
+                (end, XmlToken::Other)
+            } else {
+                (end, XmlToken::Open)
+            }
+        } else {
+            (rest.find('<').unwrap_or(rest.len()), XmlToken::Text)
+        };
+        tokens.push(kind(&rest[..end]));
+        rest = &rest[end..];
+    }
+    Some(tokens)
+}
+
+/// Returns the end of the tag starting `s`, skipping the quoted attribute values.
+fn tag_end(s: &str) -> Option<usize> {
+    let mut quote = None;
+    for (i, c) in s.char_indices() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), c) if q == c => quote = None,
+            (None, '>') => return Some(i + 1),
+            _ => {}
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::format::format_text;
+
+    fn format_body(text: &str, sort_keys: bool) -> String {
+        let hurl_file = parser::parse_hurl_file(text).unwrap();
+        format_text(format(&hurl_file, sort_keys), false)
+    }
+
+    #[test]
+    fn test_json() {
+        let text = r#"POST http://localhost
+{"name": "{{name}}" , "tags" :[ "a",{{tag}} ],"address":{ "city":"Paris" }, "empty": { }}
+HTTP 200
+{"id":1}
+"#;
+        assert_eq!(
+            format_body(text, false),
+            r#"POST http://localhost
+{
+  "name": "{{name}}",
+  "tags": [
+    "a",
+    {{tag}}
+  ],
+  "address": {
+    "city": "Paris"
+  },
+  "empty": {}
+}
+HTTP 200
+{"id":1}
+"#
+        );
+        assert_eq!(
+            format_body("POST http://localhost\n{\"b\":{\"d\":1,\"c\":2},\"a\":[]}\n", true),
+            "POST http://localhost\n{\n  \"a\": [],\n  \"b\": {\n    \"c\": 2,\n    \"d\": 1\n  }\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_xml() {
+        assert_eq!(
+            format_xml(
+                r#"<?xml version="1.0"?><!-- users --><users><user id="1" name="a>b"><name>Bob</name><tags/><empty></empty><data><![CDATA[<x>]]></data></user></users>"#
+            )
+            .unwrap(),
+            r#"<?xml version="1.0"?>
+<!-- users -->
+<users>
+  <user id="1" name="a>b">
+    <name>Bob</name>
+    <tags/>
+    <empty></empty>
+    <data><![CDATA[<x>]]></data>
+  </user>
+</users>"#
+        );
+        // Mixed content is kept as is.
+        assert_eq!(format_xml("<p>Hello <b>Bob</b></p>"), None);
+        assert_eq!(format_xml("<a><b></a>"), None);
+    }
+
+    #[test]
+    fn test_multiline() {
+        let text = "POST http://localhost\n```json   \n{\"id\": {{id}} }\n```\n\nPOST http://localhost\n```xml\n<a><b>{{name}}</b></a>\n```\n\nPOST http://localhost\n```graphql   \n{ me }\n```\n";
+        assert_eq!(
+            format_body(text, false),
+            "POST http://localhost\n```json\n{\n  \"id\": {{id}}\n}\n```\n\nPOST http://localhost\n```xml\n<a>\n  <b>{{name}}</b>\n</a>\n```\n\nPOST http://localhost\n```graphql\n{ me }\n```\n"
+        );
+    }
+}
@@ -0,0 +1,440 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2023 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+//! Export of Hurl files to curl command lines.
+//!
+//! Each entry is converted to a curl command, without running anything: Hurl variables
+//! (`{{name}}`) are kept as shell variables (`${name}`), and `[Options]` variables are
+//! exported as shell assignments before the command. Responses are ignored.
+use hurl_core::ast::*;
+
+use super::text::format_token;
+use super::token::Tokenizable;
+
+/// A part of a shell word: literal text, or a shell variable.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Part {
+    Text(String),
+    Variable(String),
+}
+
+pub fn format(hurl_file: &HurlFile) -> String {
+    hurl_file
+        .entries
+        .iter()
+        .map(|entry| format_request(&entry.request))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn format_request(request: &Request) -> String {
+    let mut s = String::new();
+    let mut arguments = vec!["curl".to_string()];
+
+    let has_data = request.body.is_some()
+        || !request.form_params().is_empty()
+        || !request.multipart_form_data().is_empty();
+    match (request.method.0.as_str(), has_data) {
+        ("GET", false) | ("POST", true) => {}
+        ("HEAD", _) => arguments.push("--head".to_string()),
+        (method, _) => {
+            arguments.push("--request".to_string());
+            arguments.push(method.to_string());
+        }
+    }
+
+    for header in &request.headers {
+        let mut parts = vec![Part::Text(format!("{}: ", header.key.value))];
+        parts.extend(template_parts(&header.value));
+        arguments.push("--header".to_string());
+        arguments.push(shell_word(&parts));
+    }
+    let has_content_type = request
+        .headers
+        .iter()
+        .any(|h| h.key.value.eq_ignore_ascii_case("Content-Type"));
+    if let Some(body) = &request.body {
+        if !has_content_type {
+            arguments.push("--header".to_string());
+            arguments.push(shell_word(&[Part::Text(content_type(&body.value))]));
+        }
+    }
+
+    for param in request.form_params() {
+        let mut parts = vec![Part::Text(format!("{}=", param.key.value))];
+        parts.extend(template_parts(&param.value));
+        arguments.push("--data-urlencode".to_string());
+        arguments.push(shell_word(&parts));
+    }
+    for param in request.multipart_form_data() {
+        let parts = match param {
+            MultipartParam::Param(KeyValue { key, value, .. }) => {
+                let mut parts = vec![Part::Text(format!("{}=", key.value))];
+                parts.extend(template_parts(&value));
+                parts
+            }
+            MultipartParam::FileParam(FileParam { key, value, .. }) => {
+                let mut text = format!("{}=@{}", key.value, value.filename.value);
+                if let Some(content_type) = value.content_type {
+                    text.push_str(format!(";type={content_type}").as_str());
+                }
+                vec![Part::Text(text)]
+            }
+        };
+        arguments.push("--form".to_string());
+        arguments.push(shell_word(&parts));
+    }
+    let cookies = request.cookies();
+    if !cookies.is_empty() {
+        let mut parts = vec![];
+        for (i, cookie) in cookies.iter().enumerate() {
+            let separator = if i > 0 { "; " } else { "" };
+            parts.push(Part::Text(format!("{separator}{}=", cookie.name.value)));
+            parts.extend(template_parts(&cookie.value));
+        }
+        arguments.push("--cookie".to_string());
+        arguments.push(shell_word(&parts));
+    }
+    if let Some(auth) = request.basic_auth() {
+        let mut parts = vec![Part::Text(format!("{}:", auth.key.value))];
+        parts.extend(template_parts(&auth.value));
+        arguments.push("--user".to_string());
+        arguments.push(shell_word(&parts));
+    }
+    if let Some(body) = &request.body {
+        let (option, data) = body_arg(&body.value);
+        arguments.push(option.to_string());
+        arguments.push(data);
+    }
+
+    for option in request.options() {
+        match option.kind {
+            OptionKind::Variable(VariableDefinition { name, value, .. }) => {
+                let parts = match value {
+                    VariableValue::String(template) => template_parts(&template),
+                    value => vec![Part::Text(value.to_string())],
+                };
+                s.push_str(format!("{}={}\n", shell_name(&name), shell_word(&parts)).as_str());
+            }
+            kind => arguments.extend(option_args(&kind)),
+        }
+    }
+
+    let mut url = template_parts(&request.url);
+    for (i, param) in request.querystring_params().iter().enumerate() {
+        let separator = if i == 0 && !request.url.to_string().contains('?') {
+            '?'
+        } else {
+            '&'
+        };
+        url.push(Part::Text(format!(
+            "{separator}{}=",
+            url_encode(&param.key.value)
+        )));
+        for part in template_parts(&param.value) {
+            url.push(match part {
+                Part::Text(text) => Part::Text(url_encode(&text)),
+                variable => variable,
+            });
+        }
+    }
+    arguments.push(shell_word(&url));
+
+    s.push_str(arguments.join(" ").as_str());
+    s
+}
+
+/// Returns the `Content-Type` header added by Hurl for a body, an empty value removing the
+/// default curl `application/x-www-form-urlencoded` header.
+fn content_type(bytes: &Bytes) -> String {
+    let content_type = match bytes {
+        Bytes::Json(_) => "application/json",
+        Bytes::Xml(_) => "application/xml",
+        Bytes::MultilineString(MultilineString::Json(_))
+        | Bytes::MultilineString(MultilineString::GraphQl(_)) => "application/json",
+        Bytes::MultilineString(MultilineString::Xml(_)) => "application/xml",
+        Bytes::Base64(_) | Bytes::Hex(_) => "application/octet-stream",
+        _ => "",
+    };
+    if content_type.is_empty() {
+        "Content-Type:".to_string()
+    } else {
+        format!("Content-Type: {content_type}")
+    }
+}
+
+/// Returns the curl option and its value for a request body.
+///
+/// Literal bodies are sent with `--data-raw`, as `--data` would read a file from a value starting
+/// with `@`.
+fn body_arg(bytes: &Bytes) -> (&'static str, String) {
+    match bytes {
+        Bytes::Json(value) => {
+            let text = value
+                .tokenize()
+                .into_iter()
+                .map(|t| format_token(t, false))
+                .collect::<String>();
+            ("--data-raw", shell_word(&text_parts(&text)))
+        }
+        Bytes::Xml(value) => ("--data-raw", shell_word(&[Part::Text(value.clone())])),
+        Bytes::MultilineString(MultilineString::GraphQl(graphql)) => {
+            // GraphQL queries are sent as JSON, like the Hurl runner does.
+            let query = serde_json::to_string(&graphql.value.to_string()).unwrap();
+            let text = match &graphql.variables {
+                Some(variables) => {
+                    format!("{{\"query\":{query},\"variables\":{}}}", variables.value)
+                }
+                None => format!("{{\"query\":{query}}}"),
+            };
+            ("--data-raw", shell_word(&text_parts(&text)))
+        }
+        Bytes::MultilineString(MultilineString::OneLineText(template))
+        | Bytes::OnelineString(template) => ("--data-raw", shell_word(&template_parts(template))),
+        Bytes::MultilineString(MultilineString::Text(text))
+        | Bytes::MultilineString(MultilineString::Json(text))
+        | Bytes::MultilineString(MultilineString::Xml(text)) => {
+            ("--data-raw", shell_word(&template_parts(&text.value)))
+        }
+        Bytes::Base64(Base64 { value, .. }) | Bytes::Hex(Hex { value, .. }) => {
+            let bytes = value
+                .iter()
+                .map(|b| format!("\\x{b:02x}"))
+                .collect::<String>();
+            ("--data-binary", format!("$'{bytes}'"))
+        }
+        Bytes::File(file) => (
+            "--data-binary",
+            shell_word(&[Part::Text(format!("@{}", file.filename.value))]),
+        ),
+    }
+}
+
+/// Returns the curl arguments of an entry option, options without curl equivalent (retry,
+/// verbosity) being ignored.
+fn option_args(kind: &OptionKind) -> Vec<String> {
+    let name = format!("--{}", kind.name());
+    match kind {
+        OptionKind::Compressed(true)
+        | OptionKind::Digest(true)
+        | OptionKind::Insecure(true)
+        | OptionKind::FollowLocation(true)
+        | OptionKind::Http10(true)
+        | OptionKind::Http11(true)
+        | OptionKind::Http2(true)
+        | OptionKind::Http2PriorKnowledge(true)
+        | OptionKind::Http3(true)
+        | OptionKind::Negotiate(true)
+        | OptionKind::Ntlm(true)
+        | OptionKind::PathAsIs(true)
+        | OptionKind::Verbose(true) => vec![name],
+        OptionKind::AwsSigV4(_)
+        | OptionKind::CaCertificate(_)
+        | OptionKind::ClientCert(_)
+        | OptionKind::ClientKey(_)
+        | OptionKind::ConnectTo(_)
+        | OptionKind::MaxRedirect(_)
+        | OptionKind::Proxy(_)
+        | OptionKind::Resolve(_)
+        | OptionKind::UnixSocket(_) => {
+            let value = shell_word(&[Part::Text(kind.value_as_str())]);
+            vec![name, value]
+        }
+        _ => vec![],
+    }
+}
+
+fn template_parts(template: &Template) -> Vec<Part> {
+    template
+        .elements
+        .iter()
+        .map(|e| match e {
+            TemplateElement::String { value, .. } => Part::Text(value.clone()),
+            TemplateElement::Expression(expr) => Part::Variable(expr.variable.name.clone()),
+        })
+        .collect()
+}
+
+/// Splits a `text` with `{{name}}` templates in parts.
+fn text_parts(text: &str) -> Vec<Part> {
+    let mut parts = vec![];
+    let mut rest = text;
+    while let Some(start) = rest.find("{{") {
+        let end = match rest[start..].find("}}") {
+            Some(end) => start + end,
+            None => break,
+        };
+        parts.push(Part::Text(rest[..start].to_string()));
+        parts.push(Part::Variable(rest[start + 2..end].trim().to_string()));
+        rest = &rest[end + 2..];
+    }
+    parts.push(Part::Text(rest.to_string()));
+    parts
+}
+
+/// Returns a shell word from `parts`: text is single quoted, and variables are double quoted
+/// to be expanded by the shell.
+fn shell_word(parts: &[Part]) -> String {
+    // Adjacent texts are merged to be quoted once.
+    let mut merged: Vec<Part> = vec![];
+    for part in parts {
+        match (merged.last_mut(), part) {
+            (Some(Part::Text(last)), Part::Text(text)) => last.push_str(text),
+            _ => merged.push(part.clone()),
+        }
+    }
+    let word = merged
+        .iter()
+        .map(|part| match part {
+            Part::Text(text) if text.is_empty() => String::new(),
+            Part::Text(text) => encode_shell_string(text),
+            Part::Variable(name) => format!("\"${{{}}}\"", shell_name(name)),
+        })
+        .collect::<String>();
+    if word.is_empty() {
+        "''".to_string()
+    } else {
+        word
+    }
+}
+
+/// Returns a valid shell variable name from a Hurl variable `name`.
+fn shell_name(name: &str) -> String {
+    name.replace('-', "_")
+}
+
+fn encode_shell_string(s: &str) -> String {
+    // $'...' form is used to encode escaped sequences (\n, \t or ')
+    if s.contains(['\n', '\t', '\'']) {
+        let escaped = s
+            .replace('\\', "\\\\")
+            .replace('\n', "\\n")
+            .replace('\t', "\\t")
+            .replace('\'', "\\'");
+        format!("$'{escaped}'")
+    } else {
+        format!("'{s}'")
+    }
+}
+
+fn url_encode(s: &str) -> String {
+    s.bytes()
+        .map(|b| {
+            if b.is_ascii_alphanumeric() {
+                (b as char).to_string()
+            } else {
+                format!("%{b:02X}")
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::parser;
+
+    use super::*;
+
+    fn format_str(s: &str) -> String {
+        format(&parser::parse_hurl_file(s).unwrap())
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!(
+            format_str("GET http://localhost:8000/hello\n"),
+            "curl 'http://localhost:8000/hello'"
+        );
+        assert_eq!(
+            format_str(
+                r#"GET http://{{host}}/search
+Authorization: Bearer {{token}}
+[QueryStringParams]
+q: hurl rocks
+page: {{page}}
+[Options]
+variable: page=2
+insecure: true
+location: true
+retry: 3
+"#
+            ),
+            r#"page='2'
+curl --header 'Authorization: Bearer '"${token}" --insecure --location 'http://'"${host}"'/search?q=hurl%20rocks&page='"${page}""#
+        );
+    }
+
+    #[test]
+    fn test_format_form() {
+        assert_eq!(
+            format_str(
+                r#"PUT http://localhost:8000/users
+[FormParams]
+name: Bob's
+[Cookies]
+session: {{session}}
+[BasicAuth]
+bob: secret
+"#
+            ),
+            r#"curl --request PUT --data-urlencode $'name=Bob\'s' --cookie 'session='"${session}" --user 'bob:secret' 'http://localhost:8000/users'"#
+        );
+        assert_eq!(
+            format_str(
+                r#"POST http://localhost:8000/upload
+[MultipartFormData]
+key: value
+file: file,data.txt; text/plain
+"#
+            ),
+            "curl --form 'key=value' --form 'file=@data.txt;type=text/plain' 'http://localhost:8000/upload'"
+        );
+    }
+
+    #[test]
+    fn test_format_body() {
+        assert_eq!(
+            format_str("POST http://localhost:8000/users\n{\"id\": {{id}}, \"name\": \"Bob\"}\n"),
+            r#"curl --header 'Content-Type: application/json' --data-raw '{"id": '"${id}"', "name": "Bob"}' 'http://localhost:8000/users'"#
+        );
+        assert_eq!(
+            format_str("POST http://localhost:8000/data\nhex,0a0b;\n"),
+            r#"curl --header 'Content-Type: application/octet-stream' --data-binary $'\x0a\x0b' 'http://localhost:8000/data'"#
+        );
+        assert_eq!(
+            format_str("POST http://localhost:8000/data\n```\nHello\n```\n"),
+            r#"curl --header 'Content-Type:' --data-raw $'Hello\n' 'http://localhost:8000/data'"#
+        );
+        assert_eq!(
+            format_str("POST http://localhost:8000/data\n`@data.txt`\n"),
+            "curl --header 'Content-Type:' --data-raw '@data.txt' 'http://localhost:8000/data'"
+        );
+    }
+
+    #[test]
+    fn test_shell_word() {
+        assert_eq!(shell_word(&[]), "''");
+        assert_eq!(
+            shell_word(&[
+                Part::Variable("base-url".to_string()),
+                Part::Text("/a b".to_string())
+            ]),
+            "\"${base_url}\"'/a b'"
+        );
+    }
+}
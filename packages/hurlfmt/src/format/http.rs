@@ -0,0 +1,268 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2023 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+//! Export of Hurl files to `.http` files, as used by the VS Code REST Client and JetBrains HTTP
+//! Client.
+//!
+//! Only requests are exported: responses (captures and asserts) and options other than
+//! variables have no equivalent in `.http` files.
+use hurl_core::ast::*;
+
+use super::text::format_token;
+use super::token::Tokenizable;
+
+const BOUNDARY: &str = "boundary";
+
+pub fn format(hurl_file: &HurlFile) -> String {
+    hurl_file
+        .entries
+        .iter()
+        .map(|entry| format_request(&entry.request))
+        .collect::<Vec<_>>()
+        .join("\n###\n\n")
+}
+
+fn format_request(request: &Request) -> String {
+    let mut s = String::new();
+    for option in request.options() {
+        if let OptionKind::Variable(VariableDefinition { name, value, .. }) = option.kind {
+            s.push_str(format!("@{name} = {value}\n").as_str());
+        }
+    }
+
+    let mut url = request.url.to_string();
+    let params = request.querystring_params();
+    if !params.is_empty() {
+        url.push(if url.contains('?') { '&' } else { '?' });
+        url.push_str(url_encoded(&params).as_str());
+    }
+    s.push_str(format!("{} {url}\n", request.method).as_str());
+
+    let mut headers = request
+        .headers
+        .iter()
+        .map(|h| format!("{}: {}", h.key.value, h.value))
+        .collect::<Vec<_>>();
+    let cookies = request.cookies();
+    if !cookies.is_empty() {
+        let cookies = cookies
+            .iter()
+            .map(|c| format!("{}={}", c.name.value, c.value))
+            .collect::<Vec<_>>();
+        headers.push(format!("Cookie: {}", cookies.join("; ")));
+    }
+    if let Some(auth) = request.basic_auth() {
+        headers.push(format!(
+            "Authorization: Basic {}:{}",
+            auth.key.value, auth.value
+        ));
+    }
+
+    let mut body = None;
+    let params = request.form_params();
+    if !params.is_empty() {
+        headers.push("Content-Type: application/x-www-form-urlencoded".to_string());
+        body = Some(url_encoded(&params));
+    }
+    let params = request.multipart_form_data();
+    if !params.is_empty() {
+        headers.push(format!(
+            "Content-Type: multipart/form-data; boundary={BOUNDARY}"
+        ));
+        body = Some(multipart(&params));
+    }
+    if let Some(b) = &request.body {
+        if let Bytes::MultilineString(MultilineString::GraphQl(_)) = b.value {
+            headers.push("X-Request-Type: GraphQL".to_string());
+        }
+        body = Some(format_body(&b.value));
+    }
+
+    for header in headers {
+        s.push_str(format!("{header}\n").as_str());
+    }
+    if let Some(body) = body {
+        s.push_str(format!("\n{body}\n").as_str());
+    }
+    s
+}
+
+fn format_body(bytes: &Bytes) -> String {
+    match bytes {
+        Bytes::Json(value) => value
+            .tokenize()
+            .into_iter()
+            .map(|t| format_token(t, false))
+            .collect(),
+        Bytes::Xml(value) => value.clone(),
+        Bytes::MultilineString(value) => value.to_string().trim_end_matches('\n').to_string(),
+        Bytes::OnelineString(value) => value.to_string(),
+        Bytes::File(file) => format!("< {}", file_path(&file.filename.value)),
+        Bytes::Base64(Base64 { value, .. }) | Bytes::Hex(Hex { value, .. }) => {
+            match std::str::from_utf8(value) {
+                Ok(text) => text.to_string(),
+                Err(_) => "# Unsupported binary body".to_string(),
+            }
+        }
+    }
+}
+
+/// Returns a `multipart/form-data` body, files being referenced with `<` lines.
+fn multipart(params: &[MultipartParam]) -> String {
+    let mut s = String::new();
+    for param in params {
+        s.push_str(format!("--{BOUNDARY}\n").as_str());
+        match param {
+            MultipartParam::Param(KeyValue { key, value, .. }) => {
+                s.push_str(
+                    format!("Content-Disposition: form-data; name=\"{}\"\n", key.value).as_str(),
+                );
+                s.push_str(format!("\n{value}\n").as_str());
+            }
+            MultipartParam::FileParam(FileParam { key, value, .. }) => {
+                let filename = &value.filename.value;
+                let name = filename.rsplit(['/', '\\']).next().unwrap_or(filename);
+                s.push_str(
+                    format!(
+                        "Content-Disposition: form-data; name=\"{}\"; filename=\"{name}\"\n",
+                        key.value
+                    )
+                    .as_str(),
+                );
+                if let Some(content_type) = &value.content_type {
+                    s.push_str(format!("Content-Type: {content_type}\n").as_str());
+                }
+                s.push_str(format!("\n< {}\n", file_path(filename)).as_str());
+            }
+        }
+    }
+    s.push_str(format!("--{BOUNDARY}--").as_str());
+    s
+}
+
+/// Returns a `.http` file reference: relative paths must start with `./`.
+fn file_path(filename: &str) -> String {
+    if filename.starts_with('/') || filename.starts_with('.') {
+        filename.to_string()
+    } else {
+        format!("./{filename}")
+    }
+}
+
+/// Returns `params` as an url-encoded string, templates being kept as is.
+fn url_encoded(params: &[KeyValue]) -> String {
+    params
+        .iter()
+        .map(|p| {
+            let value = p
+                .value
+                .elements
+                .iter()
+                .map(|e| match e {
+                    TemplateElement::String { value, .. } => url_encode(value),
+                    TemplateElement::Expression(_) => e.to_string(),
+                })
+                .collect::<String>();
+            format!("{}={value}", url_encode(&p.key.value))
+        })
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+fn url_encode(s: &str) -> String {
+    s.bytes()
+        .map(|b| {
+            if b.is_ascii_alphanumeric() || b"-_.~".contains(&b) {
+                (b as char).to_string()
+            } else {
+                format!("%{b:02X}")
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::parser;
+
+    use super::*;
+
+    #[test]
+    fn test_format() {
+        let hurl_file = parser::parse_hurl_file(
+            r#"GET http://localhost:8000/users
+Accept: application/json
+[QueryStringParams]
+name: Bob Smith
+page: {{page}}
+[Options]
+variable: page=1
+HTTP 200
+
+POST http://localhost:8000/users
+[BasicAuth]
+bob: secret
+{"name": "Bob", "id": {{id}} }
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            format(&hurl_file),
+            r#"@page = 1
+GET http://localhost:8000/users?name=Bob%20Smith&page={{page}}
+Accept: application/json
+
+###
+
+POST http://localhost:8000/users
+Authorization: Basic bob:secret
+
+{"name": "Bob", "id": {{id}} }
+"#
+        );
+    }
+
+    #[test]
+    fn test_multipart() {
+        let hurl_file = parser::parse_hurl_file(
+            r#"POST http://localhost:8000/upload
+[MultipartFormData]
+key: value
+file: file,data/hello.txt; text/plain
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            format(&hurl_file),
+            r#"POST http://localhost:8000/upload
+Content-Type: multipart/form-data; boundary=boundary
+
+--boundary
+Content-Disposition: form-data; name="key"
+
+value
+--boundary
+Content-Disposition: form-data; name="file"; filename="hello.txt"
+Content-Type: text/plain
+
+< ./data/hello.txt
+--boundary--
+"#
+        );
+    }
+}
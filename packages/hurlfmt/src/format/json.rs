@@ -83,6 +83,9 @@ impl ToJson for Request {
             let cookies = self.cookies().iter().map(|c| c.to_json()).collect();
             attributes.push(("cookies".to_string(), JValue::List(cookies)));
         }
+        if let Some(basic_auth) = self.basic_auth() {
+            attributes.push(("basic_auth".to_string(), basic_auth.to_json()));
+        }
         if !self.options().is_empty() {
             let options = self.options().iter().map(|c| c.to_json()).collect();
             attributes.push(("options".to_string(), JValue::List(options)));
@@ -139,10 +142,19 @@ impl ToJson for Bytes {
             Bytes::Base64(value) => value.to_json(),
             Bytes::Hex(value) => value.to_json(),
             Bytes::File(value) => value.to_json(),
-            Bytes::Json(value) => JValue::Object(vec![
-                ("type".to_string(), JValue::String("json".to_string())),
-                ("value".to_string(), value.to_json()),
-            ]),
+            Bytes::Json(value) => {
+                let mut attributes = vec![
+                    ("type".to_string(), JValue::String("json".to_string())),
+                    ("value".to_string(), value.to_json()),
+                ];
+                let mut pointers = vec![];
+                json_expressions(value, "", &mut pointers);
+                if !pointers.is_empty() {
+                    let pointers = pointers.into_iter().map(JValue::String).collect();
+                    attributes.push(("expressions".to_string(), JValue::List(pointers)));
+                }
+                JValue::Object(attributes)
+            }
             Bytes::Xml(value) => JValue::Object(vec![
                 ("type".to_string(), JValue::String("xml".to_string())),
                 ("value".to_string(), JValue::String(value.clone())),
@@ -279,19 +291,29 @@ impl ToJson for EntryOption {
 
         let name = "value".to_string();
         let value = match &self.kind {
+            OptionKind::AwsSigV4(value) => JValue::String(value.clone()),
             OptionKind::CaCertificate(filename) => JValue::String(filename.value.clone()),
             OptionKind::ClientCert(filename) => JValue::String(filename.value.clone()),
             OptionKind::ClientKey(filename) => JValue::String(filename.value.clone()),
             OptionKind::Compressed(value) => JValue::Boolean(*value),
             OptionKind::ConnectTo(value) => JValue::String(value.clone()),
+            OptionKind::Digest(value) => JValue::Boolean(*value),
             OptionKind::Insecure(value) => JValue::Boolean(*value),
             OptionKind::FollowLocation(value) => JValue::Boolean(*value),
+            OptionKind::Http10(value) => JValue::Boolean(*value),
+            OptionKind::Http11(value) => JValue::Boolean(*value),
+            OptionKind::Http2(value) => JValue::Boolean(*value),
+            OptionKind::Http2PriorKnowledge(value) => JValue::Boolean(*value),
+            OptionKind::Http3(value) => JValue::Boolean(*value),
             OptionKind::MaxRedirect(value) => JValue::Number(value.to_string()),
+            OptionKind::Negotiate(value) => JValue::Boolean(*value),
+            OptionKind::Ntlm(value) => JValue::Boolean(*value),
             OptionKind::PathAsIs(value) => JValue::Boolean(*value),
             OptionKind::Proxy(value) => JValue::String(value.clone()),
             OptionKind::Resolve(value) => JValue::String(value.clone()),
             OptionKind::Retry(value) => JValue::Number(value.to_string()),
             OptionKind::RetryInterval(value) => JValue::Number(value.to_string()),
+            OptionKind::UnixSocket(filename) => JValue::String(filename.value.clone()),
             OptionKind::Variable(value) => {
                 JValue::String(format!("{}={}", value.name, value.value))
             }
@@ -314,10 +336,25 @@ impl ToJson for Capture {
             let filters = JValue::List(self.filters.iter().map(|(_, f)| f.to_json()).collect());
             attributes.push(("filters".to_string(), filters));
         }
+        if let Some(default) = &self.default {
+            attributes.push(("default".to_string(), default.value.to_json()));
+        }
         JValue::Object(attributes)
     }
 }
 
+impl ToJson for VariableValue {
+    fn to_json(&self) -> JValue {
+        match self {
+            VariableValue::Null => JValue::Null,
+            VariableValue::Bool(value) => JValue::Boolean(*value),
+            VariableValue::Integer(value) => JValue::Number(value.to_string()),
+            VariableValue::Float(value) => JValue::Number(value.to_string()),
+            VariableValue::String(value) => JValue::String(value.to_string()),
+        }
+    }
+}
+
 impl ToJson for Assert {
     fn to_json(&self) -> JValue {
         let mut attributes = vec![("query".to_string(), self.query.to_json())];
@@ -544,7 +581,10 @@ fn json_predicate_value(predicate_value: PredicateValue) -> (JValue, Option<Stri
             let base64_string = general_purpose::STANDARD.encode(value.value);
             (JValue::String(base64_string), Some("base64".to_string()))
         }
-        PredicateValue::Expression(value) => (JValue::String(value.to_string()), None),
+        PredicateValue::Expression(value) => (
+            JValue::String(value.variable.name.clone()),
+            Some("expression".to_string()),
+        ),
         PredicateValue::Regex(value) => {
             (JValue::String(value.to_string()), Some("regex".to_string()))
         }
@@ -572,6 +612,29 @@ impl ToJson for JsonValue {
     }
 }
 
+/// Collects the JSON pointers (RFC 6901) of the expressions (`{{name}}`) of a JSON body `value`.
+///
+/// Expressions are exported as `"{{name}}"` strings: their pointers tell them apart from string
+/// templates.
+fn json_expressions(value: &JsonValue, pointer: &str, pointers: &mut Vec<String>) {
+    match value {
+        JsonValue::Expression(_) => pointers.push(pointer.to_string()),
+        JsonValue::List { elements, .. } => {
+            for (i, element) in elements.iter().enumerate() {
+                json_expressions(&element.value, &format!("{pointer}/{i}"), pointers);
+            }
+        }
+        JsonValue::Object { elements, .. } => {
+            for element in elements {
+                let name = element.name.to_string();
+                let name = name.replace('~', "~0").replace('/', "~1");
+                json_expressions(&element.value, &format!("{pointer}/{name}"), pointers);
+            }
+        }
+        _ => {}
+    }
+}
+
 impl ToJson for JsonListElement {
     fn to_json(&self) -> JValue {
         self.value.to_json()
@@ -658,7 +721,7 @@ impl ToJson for FilterValue {
                 attributes.push(("type".to_string(), JValue::String("toInt".to_string())));
             }
             FilterValue::XPath { expr, .. } => {
-                attributes.push(("type".to_string(), JValue::String("toDate".to_string())));
+                attributes.push(("type".to_string(), JValue::String("xpath".to_string())));
                 attributes.push(("expr".to_string(), JValue::String(expr.to_string())));
             }
         }
@@ -830,6 +893,7 @@ pub mod tests {
             space2: whitespace(),
             query: header_query(),
             filters: vec![],
+            default: None,
             line_terminator0: line_terminator(),
         }
     }
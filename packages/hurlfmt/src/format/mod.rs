@@ -15,10 +15,16 @@
  * limitations under the License.
  *
  */
+pub use self::body::format as format_bodies;
+pub use self::curl::format as format_curl;
+pub use self::http::format as format_http;
 pub use self::json::format as format_json;
 pub use self::text::format as format_text;
 pub use self::token::{Token, Tokenizable};
 
+mod body;
+mod curl;
+mod http;
 mod json;
 mod serialize_json;
 mod text;
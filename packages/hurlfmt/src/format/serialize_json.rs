@@ -50,7 +50,10 @@ impl JValue {
             JValue::Object(key_values) => {
                 let s = key_values
                     .iter()
-                    .map(|(k, v)| format!("\"{}\":{}", k, v.clone().format()))
+                    .map(|(k, v)| {
+                        let k = k.chars().map(format_char).collect::<String>();
+                        format!("\"{k}\":{}", v.clone().format())
+                    })
                     .collect::<Vec<String>>()
                     .join(",");
                 format!("{{{s}}}")
@@ -363,11 +363,25 @@ impl Tokenizable for Capture {
             tokens.append(&mut space.clone().tokenize());
             tokens.append(&mut filter.tokenize());
         }
+        if let Some(default) = &self.default {
+            tokens.append(&mut default.tokenize());
+        }
         tokens.append(&mut self.line_terminator0.tokenize());
         tokens
     }
 }
 
+impl Tokenizable for CaptureDefault {
+    fn tokenize(&self) -> Vec<Token> {
+        let mut tokens: Vec<Token> = vec![];
+        tokens.append(&mut self.space0.tokenize());
+        tokens.push(Token::Keyword(String::from("default")));
+        tokens.append(&mut self.space1.tokenize());
+        tokens.append(&mut self.value.tokenize());
+        tokens
+    }
+}
+
 impl Tokenizable for Assert {
     fn tokenize(&self) -> Vec<Token> {
         let mut tokens: Vec<Token> = vec![];
@@ -776,7 +790,17 @@ impl Tokenizable for Comment {
 
 impl Tokenizable for Filename {
     fn tokenize(&self) -> Vec<Token> {
-        let s = self.clone().value.replace(' ', "\\ ");
+        let s = self
+            .value
+            .chars()
+            .map(|c| {
+                if c.is_alphanumeric() || "./_-".contains(c) {
+                    c.to_string()
+                } else {
+                    format!("\\{c}")
+                }
+            })
+            .collect();
         vec![Token::String(s)]
     }
 }
@@ -876,19 +900,29 @@ impl Tokenizable for EntryOption {
 impl Tokenizable for OptionKind {
     fn tokenize(&self) -> Vec<Token> {
         match self {
+            OptionKind::AwsSigV4(value) => vec![Token::String(value.clone())],
             OptionKind::CaCertificate(filename) => filename.tokenize(),
             OptionKind::ClientCert(filename) => filename.tokenize(),
             OptionKind::ClientKey(filename) => filename.tokenize(),
             OptionKind::Compressed(value) => vec![Token::Boolean(value.to_string())],
             OptionKind::ConnectTo(value) => vec![Token::String(value.clone())],
+            OptionKind::Digest(value) => vec![Token::Boolean(value.to_string())],
             OptionKind::Insecure(value) => vec![Token::Boolean(value.to_string())],
             OptionKind::FollowLocation(value) => vec![Token::Boolean(value.to_string())],
+            OptionKind::Http10(value) => vec![Token::Boolean(value.to_string())],
+            OptionKind::Http11(value) => vec![Token::Boolean(value.to_string())],
+            OptionKind::Http2(value) => vec![Token::Boolean(value.to_string())],
+            OptionKind::Http2PriorKnowledge(value) => vec![Token::Boolean(value.to_string())],
+            OptionKind::Http3(value) => vec![Token::Boolean(value.to_string())],
             OptionKind::MaxRedirect(value) => vec![Token::Number(value.to_string())],
+            OptionKind::Negotiate(value) => vec![Token::Boolean(value.to_string())],
+            OptionKind::Ntlm(value) => vec![Token::Boolean(value.to_string())],
             OptionKind::PathAsIs(value) => vec![Token::Boolean(value.to_string())],
             OptionKind::Proxy(value) => vec![Token::String(value.clone())],
             OptionKind::Resolve(value) => vec![Token::String(value.clone())],
             OptionKind::Retry(value) => value.tokenize(),
             OptionKind::RetryInterval(value) => vec![Token::Number(value.to_string())],
+            OptionKind::UnixSocket(filename) => filename.tokenize(),
             OptionKind::Variable(value) => value.tokenize(),
             OptionKind::Verbose(value) => vec![Token::Boolean(value.to_string())],
             OptionKind::VeryVerbose(value) => vec![Token::Boolean(value.to_string())],
@@ -0,0 +1,398 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2023 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+//! Import of [HTTP Archive (HAR)](http://www.softwareishard.com/blog/har-12-spec/) files.
+//!
+//! The HAR entries are converted to a Hurl AST, which is then formatted as text.
+use hurl_core::ast::*;
+use serde_json::Value;
+
+use crate::builder::{self, push_section};
+use crate::escape::{escape_value, url_decode};
+use crate::format;
+
+/// The body of a HAR request, converted to Hurl.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Body {
+    Form(Vec<KeyValue>),
+    Multipart(Vec<MultipartParam>),
+    Raw(Box<Bytes>),
+}
+
+/// Converts a HAR document `s` to a Hurl file content, with one entry per recorded request.
+pub fn parse(s: &str) -> Result<String, String> {
+    let value: Value =
+        serde_json::from_str(s).map_err(|e| format!("Can not parse HAR file: {e}"))?;
+    let entries = match value["log"]["entries"].as_array() {
+        Some(entries) => entries,
+        None => return Err("Can not parse HAR file: missing log.entries".to_string()),
+    };
+    let entries = entries
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| {
+            parse_entry(entry)
+                .map_err(|message| format!("Can not parse HAR entry {}: {message}", i + 1))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(format::format_text(builder::hurl_file(entries), false))
+}
+
+fn parse_entry(entry: &Value) -> Result<Entry, String> {
+    let request = &entry["request"];
+    let method = match request["method"].as_str() {
+        Some(method) => method,
+        None => return Err("missing request method".to_string()),
+    };
+    let url = match request["url"].as_str() {
+        Some(url) => url,
+        None => return Err("missing request url".to_string()),
+    };
+    let (url, query_params) = split_url(url);
+    let cookies = cookies(request)?;
+    let body = body(request)?;
+
+    let url = builder::template(&url, str::to_string, None);
+    let mut hurl_request = builder::request(method, url);
+    hurl_request.headers = headers(request, !cookies.is_empty(), &body)?;
+    let sections = &mut hurl_request.sections;
+    push_section(sections, SectionValue::QueryParams(query_params));
+    push_section(sections, SectionValue::Cookies(cookies));
+    match body {
+        Some(Body::Form(params)) => push_section(sections, SectionValue::FormParams(params)),
+        Some(Body::Multipart(params)) => {
+            push_section(sections, SectionValue::MultipartFormData(params))
+        }
+        Some(Body::Raw(bytes)) => hurl_request.body = Some(builder::body(*bytes)),
+        None => {}
+    }
+
+    // Requests that have not received a response are recorded with a 0 status.
+    let response = match entry["response"]["status"].as_u64() {
+        Some(status) if status > 0 => Some(builder::response(
+            VersionValue::VersionAny,
+            StatusValue::Specific(status),
+        )),
+        _ => None,
+    };
+    Ok(Entry {
+        request: hurl_request,
+        response,
+    })
+}
+
+/// Splits an `url` between its base and its `[QueryStringParams]` section params.
+///
+/// The query is kept in the url if it can't be represented as a list of key/value params.
+fn split_url(url: &str) -> (String, Vec<KeyValue>) {
+    let url = url.split_once('#').map_or(url, |(url, _)| url);
+    let (base, query) = match url.split_once('?') {
+        Some((base, query)) if !query.is_empty() => (base, query),
+        _ => return (url.to_string(), vec![]),
+    };
+    let mut params = vec![];
+    for param in query.split('&') {
+        match param.split_once('=') {
+            Some((name, value)) => params.push(key_value(&url_decode(name), &url_decode(value))),
+            None => return (url.to_string(), vec![]),
+        }
+    }
+    (base.to_string(), params)
+}
+
+fn key_value(name: &str, value: &str) -> KeyValue {
+    builder::key_value(name, builder::template(value, escape_value, None))
+}
+
+/// Returns the name/value pairs of the HAR array `value`.
+fn name_values(value: &Value) -> Result<Vec<(&str, &str)>, String> {
+    let items = match value {
+        Value::Null => return Ok(vec![]),
+        Value::Array(items) => items,
+        _ => return Err("invalid name/value list".to_string()),
+    };
+    let mut pairs = vec![];
+    for item in items {
+        match (item["name"].as_str(), item["value"].as_str()) {
+            (Some(name), Some(value)) => pairs.push((name, value)),
+            _ => return Err(format!("invalid name/value <{item}>")),
+        }
+    }
+    Ok(pairs)
+}
+
+/// Returns the headers of a HAR `request`.
+///
+/// Headers computed by Hurl (`Host`, `Content-Length`, HTTP/2 pseudo-headers), and headers
+/// already described by a section are not kept.
+fn headers(
+    request: &Value,
+    has_cookies: bool,
+    body: &Option<Body>,
+) -> Result<Vec<KeyValue>, String> {
+    let mut headers = vec![];
+    for (name, value) in name_values(&request["headers"])? {
+        let skipped = match name.to_lowercase().as_str() {
+            "host" | "content-length" => true,
+            "cookie" => has_cookies,
+            "content-type" => matches!(body, Some(Body::Form(_)) | Some(Body::Multipart(_))),
+            _ => name.starts_with(':'),
+        };
+        if !skipped {
+            headers.push(key_value(name, value));
+        }
+    }
+    Ok(headers)
+}
+
+/// Returns the `[Cookies]` section of a HAR `request`.
+fn cookies(request: &Value) -> Result<Vec<Cookie>, String> {
+    let cookies = name_values(&request["cookies"])?
+        .iter()
+        .map(|(name, value)| builder::cookie(name, builder::template(value, escape_value, None)))
+        .collect();
+    Ok(cookies)
+}
+
+/// Returns the body of a HAR `request`, from its `postData` field.
+fn body(request: &Value) -> Result<Option<Body>, String> {
+    let post_data = &request["postData"];
+    if post_data.is_null() {
+        return Ok(None);
+    }
+    let mime_type = post_data["mimeType"].as_str().unwrap_or_default();
+    let text = post_data["text"].as_str().unwrap_or_default();
+
+    if mime_type.starts_with("application/x-www-form-urlencoded") {
+        let params = if text.is_empty() {
+            name_values(&post_data["params"])?
+                .iter()
+                .map(|(name, value)| key_value(name, value))
+                .collect()
+        } else {
+            text.split('&')
+                .filter(|param| !param.is_empty())
+                .map(|param| {
+                    let (name, value) = param.split_once('=').unwrap_or((param, ""));
+                    key_value(&url_decode(name), &url_decode(value))
+                })
+                .collect()
+        };
+        return Ok(Some(Body::Form(params)));
+    }
+
+    if mime_type.starts_with("multipart/form-data") {
+        if let Some(items) = post_data["params"].as_array() {
+            let mut params = vec![];
+            for item in items {
+                let name = match item["name"].as_str() {
+                    Some(name) => name,
+                    None => return Err(format!("invalid multipart param <{item}>")),
+                };
+                let param = match item["fileName"].as_str() {
+                    Some(filename) => {
+                        builder::file_param(name, filename, item["contentType"].as_str())
+                    }
+                    None => {
+                        let value = item["value"].as_str().unwrap_or_default();
+                        MultipartParam::Param(key_value(name, value))
+                    }
+                };
+                params.push(param);
+            }
+            if !params.is_empty() {
+                return Ok(Some(Body::Multipart(params)));
+            }
+        }
+    }
+
+    if text.is_empty() {
+        return Ok(None);
+    }
+    // JSON objects and arrays can be used as is as a Hurl body.
+    if let Some(json @ (JsonValue::Object { .. } | JsonValue::List { .. })) =
+        builder::parse_json(text.trim())
+    {
+        return Ok(Some(Body::Raw(Box::new(Bytes::Json(json)))));
+    }
+    let bytes = builder::multiline(&format!("{text}\n"), "");
+    Ok(Some(Body::Raw(Box::new(bytes))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let har = r#"{
+  "log": {
+    "version": "1.2",
+    "entries": [
+      {
+        "request": {
+          "method": "GET",
+          "url": "http://localhost:8000/hello",
+          "headers": [
+            {"name": ":authority", "value": "localhost:8000"},
+            {"name": "Host", "value": "localhost:8000"},
+            {"name": "Accept", "value": "*/*"}
+          ]
+        },
+        "response": {"status": 200}
+      },
+      {
+        "request": {
+          "method": "POST",
+          "url": "http://localhost:8000/post-json",
+          "headers": [{"name": "Content-Type", "value": "application/json"}],
+          "postData": {"mimeType": "application/json", "text": "{\"name\": \"Bob\"}"}
+        },
+        "response": {"status": 0}
+      }
+    ]
+  }
+}"#;
+        assert_eq!(
+            parse(har).unwrap(),
+            r#"GET http://localhost:8000/hello
+Accept: */*
+HTTP 200
+
+POST http://localhost:8000/post-json
+Content-Type: application/json
+{"name": "Bob"}
+"#
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert!(parse("{}").unwrap_err().contains("missing log.entries"));
+        assert_eq!(
+            parse(r#"{"log": {"entries": [{"request": {"url": "http://localhost"}}]}}"#)
+                .unwrap_err(),
+            "Can not parse HAR entry 1: missing request method"
+        );
+    }
+
+    /// Returns the `name: value` lines of key/value `params`.
+    fn lines(params: &[KeyValue]) -> Vec<String> {
+        params
+            .iter()
+            .map(|param| format!("{}: {}", param.key.value, param.value))
+            .collect()
+    }
+
+    #[test]
+    fn test_split_url() {
+        let (url, params) = split_url("http://localhost:8000/hello");
+        assert_eq!(url, "http://localhost:8000/hello");
+        assert!(params.is_empty());
+
+        let (url, params) = split_url(
+            "http://localhost:8000/querystring-params?param1=value1&param2=&param3=a%3Db#top",
+        );
+        assert_eq!(url, "http://localhost:8000/querystring-params");
+        assert_eq!(
+            lines(&params),
+            vec!["param1: value1", "param2: ", "param3: a=b"]
+        );
+
+        let (url, params) = split_url("http://localhost:8000/hello?flag");
+        assert_eq!(url, "http://localhost:8000/hello?flag");
+        assert!(params.is_empty());
+    }
+
+    #[test]
+    fn test_headers() {
+        let request: Value = serde_json::from_str(
+            r##"{"headers": [
+                {"name": "Content-Type", "value": "application/x-www-form-urlencoded"},
+                {"name": "Content-Length", "value": "12"},
+                {"name": "Cookie", "value": "user=Bob"},
+                {"name": "X-Id", "value": "#1"}
+            ]}"##,
+        )
+        .unwrap();
+        let values = headers(&request, true, &Some(Body::Form(vec![]))).unwrap();
+        assert_eq!(lines(&values), vec!["X-Id: #1"]);
+        let values = headers(&request, false, &None).unwrap();
+        assert_eq!(
+            lines(&values),
+            vec![
+                "Content-Type: application/x-www-form-urlencoded",
+                "Cookie: user=Bob",
+                "X-Id: #1",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_body() {
+        let request: Value = serde_json::from_str(
+            r#"{"postData": {"mimeType": "application/x-www-form-urlencoded", "text": "a=1&b=x+y"}}"#,
+        )
+        .unwrap();
+        let Some(Body::Form(params)) = body(&request).unwrap() else {
+            panic!("expecting form params");
+        };
+        assert_eq!(lines(&params), vec!["a: 1", "b: x y"]);
+
+        let request: Value = serde_json::from_str(
+            r#"{"postData": {"mimeType": "multipart/form-data; boundary=xxx", "params": [
+                {"name": "key1", "value": "value1"},
+                {"name": "upload1", "fileName": "data.txt", "contentType": "text/plain"}
+            ]}}"#,
+        )
+        .unwrap();
+        let Some(Body::Multipart(params)) = body(&request).unwrap() else {
+            panic!("expecting multipart params");
+        };
+        assert_eq!(
+            params[1],
+            builder::file_param("upload1", "data.txt", Some("text/plain"))
+        );
+
+        assert_eq!(body(&serde_json::json!({})).unwrap(), None);
+    }
+
+    #[test]
+    fn test_parse_body() {
+        let har = |mime_type: &str, text: &str| {
+            let entry = serde_json::json!({"request": {
+                "method": "POST",
+                "url": "http://localhost:8000/data",
+                "postData": {"mimeType": mime_type, "text": text}
+            }});
+            parse(&serde_json::json!({"log": {"entries": [entry]}}).to_string()).unwrap()
+        };
+        assert_eq!(
+            har("text/plain", "Hello"),
+            "POST http://localhost:8000/data\n```\nHello\n```\n"
+        );
+        assert_eq!(
+            har("text/plain", "```"),
+            "POST http://localhost:8000/data\nbase64,YGBgCg==;\n"
+        );
+        assert_eq!(
+            har("application/x-www-form-urlencoded", "id=%231"),
+            "POST http://localhost:8000/data\n[FormParams]\nid: \\#1\n"
+        );
+    }
+}
@@ -0,0 +1,361 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2023 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+//! Import of `.http` files, as used by the VS Code REST Client and JetBrains HTTP Client.
+use hurl_core::ast::*;
+
+use crate::builder;
+use crate::escape::{escape_url_braces, escape_value, escape_value_braces, variable_name};
+use crate::format;
+
+const METHODS: [&str; 9] = [
+    "GET", "HEAD", "POST", "PUT", "DELETE", "CONNECT", "OPTIONS", "TRACE", "PATCH",
+];
+
+/// Converts a `.http` file content `s` to a Hurl file content.
+///
+/// Requests are separated by `###` lines, and file variables (`@name = value`) are converted to
+/// `[Options]` variables of the next request. Response handlers and dynamic variables are not
+/// supported by Hurl: they're kept as comments.
+pub fn parse(s: &str) -> Result<String, String> {
+    let mut entries = vec![];
+    let mut trailing_comments = vec![];
+    let mut comments = vec![];
+    let mut variables = vec![];
+    for (i, block) in blocks(s).iter().enumerate() {
+        let entry = parse_block(block, &mut comments, &mut variables)
+            .map_err(|message| format!("Can not parse request {}: {message}", i + 1))?;
+        if let Some((entry, comments)) = entry {
+            entries.push(entry);
+            trailing_comments.push(comments);
+        }
+    }
+    if entries.is_empty() {
+        return Err("Can not parse http file: no request found".to_string());
+    }
+    let mut hurl_file = builder::hurl_file(entries);
+    // Comments following an entry are written before the empty line separating it from the
+    // next entry.
+    for (i, comments) in trailing_comments.into_iter().enumerate() {
+        match hurl_file.entries.get_mut(i + 1) {
+            Some(next) => {
+                next.request.line_terminators.splice(0..0, comments);
+            }
+            None => hurl_file.line_terminators = comments,
+        }
+    }
+    Ok(format::format_text(hurl_file, false))
+}
+
+/// Splits `s` in blocks of lines separated by `###` lines, the separator line being kept as
+/// the first line of the next block.
+fn blocks(s: &str) -> Vec<Vec<&str>> {
+    let mut blocks = vec![vec![]];
+    for line in s.lines() {
+        if line.starts_with("###") {
+            blocks.push(vec![]);
+        }
+        blocks.last_mut().unwrap().push(line);
+    }
+    blocks
+}
+
+/// Converts a block of `lines` to a Hurl entry, and the comments following it.
+///
+/// Blocks without request (for instance, a block of file variables) return `None`, their
+/// comments and variables being kept in `comments` and `variables` for the next request.
+fn parse_block(
+    lines: &[&str],
+    comments: &mut Vec<LineTerminator>,
+    variables: &mut Vec<(String, String)>,
+) -> Result<Option<(Entry, Vec<LineTerminator>)>, String> {
+    let mut lines = lines.iter().peekable();
+
+    // Comments and variables before the request line.
+    let request_line = loop {
+        let line = match lines.next() {
+            Some(line) => line.trim(),
+            None => return Ok(None),
+        };
+        if let Some(title) = line.strip_prefix("###") {
+            let title = title.trim();
+            if !title.is_empty() {
+                comments.push(builder::comment(title));
+            }
+        } else if let Some(comment) = comment(line) {
+            comments.push(comment);
+        } else if let Some(variable) = line.strip_prefix('@') {
+            variables.push(variable_definition(variable)?);
+        } else if !line.is_empty() {
+            break line;
+        }
+    };
+    let (method, mut url) = request(request_line)?;
+    while let Some(line) = lines.peek() {
+        let line = line.trim();
+        if !line.starts_with('?') && !line.starts_with('&') {
+            break;
+        }
+        url.push_str(line);
+        lines.next();
+    }
+    let mut dynamics = vec![];
+    let (url, names) = builder::import_template(&url, str::to_string, escape_url_braces);
+    dynamics.extend(names);
+    let mut request = builder::request(&method, url);
+
+    // Comments are attached to the next header, section or body.
+    let mut pending = vec![];
+    for line in lines.by_ref() {
+        let line = line.trim();
+        if line.is_empty() {
+            break;
+        }
+        if let Some(comment) = comment(line) {
+            pending.push(comment);
+            continue;
+        }
+        let (name, value) = header(line)?;
+        let (value, names) = builder::import_template(value, escape_value, escape_value_braces);
+        dynamics.extend(names);
+        let mut header = builder::key_value(name, value);
+        header.line_terminators = std::mem::take(&mut pending);
+        request.headers.push(header);
+    }
+
+    let mut body = vec![];
+    let mut handlers = vec![];
+    let mut in_script = false;
+    for line in lines {
+        if in_script || line.starts_with('>') {
+            // Scripts `> {% ... %}` can span multiple lines.
+            if in_script || line.starts_with("> {%") {
+                in_script = !line.contains("%}");
+            }
+            handlers.push(builder::comment(line));
+        } else if line.starts_with("<> ") {
+            // Responses of previous runs, not used by Hurl.
+        } else {
+            body.push(*line);
+        }
+    }
+    let body = body.join("\n");
+    let body = body.trim_matches('\n');
+
+    if !variables.is_empty() {
+        let mut options = vec![];
+        for (name, value) in variables.drain(..) {
+            let (value, names) = if value.is_empty() {
+                (builder::quoted(""), vec![])
+            } else {
+                builder::import_template(&value, escape_value, escape_value_braces)
+            };
+            dynamics.extend(names);
+            options.push(variable_option(name, value));
+        }
+        let mut section = builder::section(SectionValue::Options(options));
+        section.line_terminators = std::mem::take(&mut pending);
+        request.sections.push(section);
+    }
+    if !body.is_empty() {
+        let bytes = match body.strip_prefix('<') {
+            Some(path) if !body.contains('\n') => {
+                let path = path.trim();
+                builder::file(path.strip_prefix('@').unwrap_or(path).trim())
+            }
+            _ => {
+                let (bytes, names) = builder::import_body(body, false);
+                dynamics.extend(names);
+                bytes
+            }
+        };
+        let mut body = builder::body(bytes);
+        body.line_terminators = std::mem::take(&mut pending);
+        request.body = Some(body);
+    }
+    if !handlers.is_empty() {
+        pending.push(builder::comment("Unsupported response handler:"));
+        pending.append(&mut handlers);
+    }
+
+    let mut line_terminators = dynamics
+        .iter()
+        .map(|name| builder::comment(&format!("Unsupported dynamic variable {{{{{name}}}}}")))
+        .collect::<Vec<_>>();
+    line_terminators.append(comments);
+    request.line_terminators = line_terminators;
+    let entry = Entry {
+        request,
+        response: None,
+    };
+    Ok(Some((entry, pending)))
+}
+
+/// Returns a Hurl comment if `line` is a `#` or `//` comment.
+fn comment(line: &str) -> Option<LineTerminator> {
+    let value = match line.strip_prefix('#') {
+        Some(value) => value,
+        None => line.strip_prefix("//")?,
+    };
+    Some(LineTerminator {
+        space0: builder::whitespace(""),
+        comment: Some(Comment {
+            value: value.to_string(),
+        }),
+        newline: builder::whitespace("\n"),
+    })
+}
+
+/// Splits a file variable `name = value` (without the leading `@`) in a Hurl variable name and a
+/// value.
+fn variable_definition(s: &str) -> Result<(String, String), String> {
+    match s.split_once('=') {
+        Some((name, value)) => Ok((variable_name(name.trim()), value.trim().to_string())),
+        None => Err(format!("invalid variable <@{s}>")),
+    }
+}
+
+/// Returns a `variable: name=value` option.
+fn variable_option(name: String, value: Template) -> EntryOption {
+    EntryOption {
+        line_terminators: vec![],
+        space0: builder::whitespace(""),
+        space1: builder::whitespace(""),
+        space2: builder::whitespace(" "),
+        kind: OptionKind::Variable(VariableDefinition {
+            name,
+            space0: builder::whitespace(""),
+            space1: builder::whitespace(""),
+            value: VariableValue::String(value),
+        }),
+        line_terminator0: builder::newline(),
+    }
+}
+
+/// Returns the method and url of a request `line`, the HTTP version being ignored.
+fn request(line: &str) -> Result<(String, String), String> {
+    let mut tokens = line.split_whitespace().collect::<Vec<_>>();
+    if tokens.len() > 1 && tokens[tokens.len() - 1].starts_with("HTTP/") {
+        tokens.pop();
+    }
+    match tokens.as_slice() {
+        [url] => Ok(("GET".to_string(), url.to_string())),
+        [method, url] if METHODS.contains(method) => Ok((method.to_string(), url.to_string())),
+        _ => Err(format!("invalid request line <{line}>")),
+    }
+}
+
+/// Splits a `Name: value` header `line` in its name and value.
+fn header(line: &str) -> Result<(&str, &str), String> {
+    match line.split_once(':') {
+        Some((name, value)) => Ok((name.trim(), value.trim())),
+        None => Err(format!("invalid header <{line}>")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let s = r#"@host = localhost:8000
+@token = abc#123
+
+### Get users
+GET http://{{host}}/users
+    ?page=1
+    &size=10
+Accept: application/json
+
+###
+POST http://{{host}}/users HTTP/1.1
+Authorization: Bearer {{token}}
+Content-Type: application/json
+
+{
+    "name": "Bob"
+}
+
+> {%
+    client.test("ok", function() {});
+%}
+"#;
+        assert_eq!(
+            parse(s).unwrap(),
+            r#"# Get users
+GET http://{{host}}/users?page=1&size=10
+Accept: application/json
+[Options]
+variable: host=localhost:8000
+variable: token=abc\#123
+
+POST http://{{host}}/users
+Authorization: Bearer {{token}}
+Content-Type: application/json
+{
+    "name": "Bob"
+}
+# Unsupported response handler:
+# > {%
+#     client.test("ok", function() {});
+# %}
+"#
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            parse("FOO http://localhost bar").unwrap_err(),
+            "Can not parse request 1: invalid request line <FOO http://localhost bar>"
+        );
+        assert_eq!(
+            parse("@host = localhost").unwrap_err(),
+            "Can not parse http file: no request found"
+        );
+    }
+
+    #[test]
+    fn test_body() {
+        assert_eq!(
+            parse("POST http://localhost\n\n< ./data/user.json\n").unwrap(),
+            "POST http://localhost\nfile,./data/user.json;\n"
+        );
+        assert_eq!(
+            parse("POST http://localhost\n\n< @ ./my data.json\n").unwrap(),
+            "POST http://localhost\nfile,./my\\ data.json;\n"
+        );
+        assert_eq!(
+            parse("POST http://localhost\n\nHello {{$guid}}\n").unwrap(),
+            "# Unsupported dynamic variable {{$guid}}\nPOST http://localhost\n```\nHello \\{\\{$guid}}\n```\n"
+        );
+    }
+
+    #[test]
+    fn test_request() {
+        assert_eq!(
+            request("http://localhost").unwrap(),
+            ("GET".to_string(), "http://localhost".to_string())
+        );
+        assert_eq!(
+            request("DELETE http://localhost/1 HTTP/2").unwrap(),
+            ("DELETE".to_string(), "http://localhost/1".to_string())
+        );
+    }
+}
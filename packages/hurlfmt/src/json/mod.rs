@@ -0,0 +1,927 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2023 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+//! Import of the JSON representation of Hurl files, as produced by `hurlfmt --out json`.
+//!
+//! The JSON document is parsed with the Hurl JSON parser, which keeps the order of the object
+//! keys and the text of the numbers. It's converted to a Hurl AST, which is then formatted as
+//! text.
+use base64::engine::general_purpose;
+use base64::Engine;
+use hurl_core::ast::*;
+use hurl_core::parser::{self, Reader};
+
+use crate::builder::{self, push_section, quoted, whitespace};
+use crate::escape::{escape_string, escape_value};
+use crate::format;
+
+/// Converts a JSON Hurl file `s` to a Hurl file content.
+pub fn parse(s: &str) -> Result<String, String> {
+    let mut reader = Reader::new(s.trim());
+    let value = match parser::parse_json(&mut reader) {
+        Ok(value) if reader.is_eof() => value,
+        _ => {
+            let pos = reader.state.pos;
+            let message = format!("invalid JSON at line {} column {}", pos.line, pos.column);
+            return Err(format!("Can not parse JSON file: {message}"));
+        }
+    };
+    let entries = match get(&value, "entries") {
+        Some(JsonValue::List { elements, .. }) => elements,
+        _ => return Err("Can not parse JSON file: missing entries".to_string()),
+    };
+    let entries = entries
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| {
+            parse_entry(&entry.value)
+                .map_err(|message| format!("Can not parse entry {}: {message}", i + 1))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(format::format_text(builder::hurl_file(entries), false))
+}
+
+fn parse_entry(entry: &JsonValue) -> Result<Entry, String> {
+    let request = match get(entry, "request") {
+        Some(request) => parse_request(request)?,
+        None => return Err("missing request".to_string()),
+    };
+    let response = match get(entry, "response") {
+        Some(response) => Some(parse_response(response)?),
+        None => None,
+    };
+    Ok(Entry { request, response })
+}
+
+fn parse_request(value: &JsonValue) -> Result<Request, String> {
+    let method = string(value, "method")?;
+    let url = builder::template(&string(value, "url")?, str::to_string, None);
+    let mut request = builder::request(&method, url);
+    request.headers = list(value, "headers")?
+        .into_iter()
+        .map(key_value)
+        .collect::<Result<_, _>>()?;
+
+    let sections = &mut request.sections;
+    let params = list(value, "query_string_params")?;
+    let params = params
+        .into_iter()
+        .map(key_value)
+        .collect::<Result<_, _>>()?;
+    push_section(sections, SectionValue::QueryParams(params));
+    let params = list(value, "form_params")?;
+    let params = params
+        .into_iter()
+        .map(key_value)
+        .collect::<Result<_, _>>()?;
+    push_section(sections, SectionValue::FormParams(params));
+    let params = list(value, "multipart_form_data")?;
+    let params = params
+        .into_iter()
+        .map(multipart_param)
+        .collect::<Result<_, _>>()?;
+    push_section(sections, SectionValue::MultipartFormData(params));
+    let cookies = list(value, "cookies")?;
+    let cookies = cookies.into_iter().map(cookie).collect::<Result<_, _>>()?;
+    push_section(sections, SectionValue::Cookies(cookies));
+    if let Some(auth) = get(value, "basic_auth") {
+        push_section(sections, SectionValue::BasicAuth(Some(key_value(auth)?)));
+    }
+    let options = list(value, "options")?;
+    let options = options.into_iter().map(option).collect::<Result<_, _>>()?;
+    push_section(sections, SectionValue::Options(options));
+
+    if let Some(value) = get(value, "body") {
+        request.body = Some(builder::body(body(value)?));
+    }
+    Ok(request)
+}
+
+fn parse_response(value: &JsonValue) -> Result<Response, String> {
+    let version = match get(value, "version").and_then(as_str).as_deref() {
+        None => VersionValue::VersionAny,
+        Some("HTTP/1.0") => VersionValue::Version1,
+        Some("HTTP/1.1") => VersionValue::Version11,
+        Some("HTTP/2") => VersionValue::Version2,
+        Some(version) => return Err(format!("invalid response version <{version}>")),
+    };
+    let status = match get(value, "status") {
+        Some(status) => match as_u64(status) {
+            Some(n) => StatusValue::Specific(n),
+            None => return Err("invalid response status".to_string()),
+        },
+        None => StatusValue::Any,
+    };
+    let mut response = builder::response(version, status);
+    response.headers = list(value, "headers")?
+        .into_iter()
+        .map(key_value)
+        .collect::<Result<_, _>>()?;
+
+    let captures = list(value, "captures")?;
+    let captures = captures
+        .into_iter()
+        .map(capture)
+        .collect::<Result<_, _>>()?;
+    push_section(&mut response.sections, SectionValue::Captures(captures));
+    let asserts = list(value, "asserts")?;
+    let asserts = asserts.into_iter().map(assert).collect::<Result<_, _>>()?;
+    push_section(&mut response.sections, SectionValue::Asserts(asserts));
+
+    if let Some(value) = get(value, "body") {
+        response.body = Some(builder::body(body(value)?));
+    }
+    Ok(response)
+}
+
+/// Converts a `{"name": ..., "value": ...}` object to a Hurl key/value.
+fn key_value(value: &JsonValue) -> Result<KeyValue, String> {
+    let name = string(value, "name")?;
+    let value = string(value, "value")?;
+    Ok(builder::key_value(
+        &name,
+        builder::template(&value, escape_value, None),
+    ))
+}
+
+fn cookie(value: &JsonValue) -> Result<Cookie, String> {
+    let name = string(value, "name")?;
+    let value = string(value, "value")?;
+    Ok(builder::cookie(
+        &name,
+        builder::template(&value, escape_value, None),
+    ))
+}
+
+fn multipart_param(value: &JsonValue) -> Result<MultipartParam, String> {
+    let filename = match get(value, "filename").and_then(as_str) {
+        Some(filename) => filename,
+        None => return Ok(MultipartParam::Param(key_value(value)?)),
+    };
+    let name = string(value, "name")?;
+    let content_type = get(value, "content_type").and_then(as_str);
+    Ok(builder::file_param(
+        &name,
+        &filename,
+        content_type.as_deref(),
+    ))
+}
+
+fn option(value: &JsonValue) -> Result<EntryOption, String> {
+    let name = string(value, "name")?;
+    let option_value = get(value, "value");
+    let invalid = || format!("invalid value for option <{name}>");
+    let boolean = || option_value.and_then(as_bool).ok_or_else(invalid);
+    let text = || option_value.and_then(as_str).ok_or_else(invalid);
+    let integer = || option_value.and_then(as_i64).ok_or_else(invalid);
+    let kind = match name.as_str() {
+        "aws-sigv4" => OptionKind::AwsSigV4(text()?),
+        "cacert" => OptionKind::CaCertificate(builder::filename(&text()?)),
+        "cert" => OptionKind::ClientCert(builder::filename(&text()?)),
+        "key" => OptionKind::ClientKey(builder::filename(&text()?)),
+        "compressed" => OptionKind::Compressed(boolean()?),
+        "connect-to" => OptionKind::ConnectTo(text()?),
+        "digest" => OptionKind::Digest(boolean()?),
+        "insecure" => OptionKind::Insecure(boolean()?),
+        "location" => OptionKind::FollowLocation(boolean()?),
+        "http1.0" => OptionKind::Http10(boolean()?),
+        "http1.1" => OptionKind::Http11(boolean()?),
+        "http2" => OptionKind::Http2(boolean()?),
+        "http2-prior-knowledge" => OptionKind::Http2PriorKnowledge(boolean()?),
+        "http3" => OptionKind::Http3(boolean()?),
+        "max-redirs" => OptionKind::MaxRedirect(integer()?.try_into().map_err(|_| invalid())?),
+        "negotiate" => OptionKind::Negotiate(boolean()?),
+        "ntlm" => OptionKind::Ntlm(boolean()?),
+        "path-as-is" => OptionKind::PathAsIs(boolean()?),
+        "proxy" => OptionKind::Proxy(text()?),
+        "resolve" => OptionKind::Resolve(text()?),
+        "retry" => OptionKind::Retry(match integer()? {
+            -1 => Retry::Infinite,
+            0 => Retry::None,
+            n => Retry::Finite(n.try_into().map_err(|_| invalid())?),
+        }),
+        "retry-interval" => {
+            OptionKind::RetryInterval(integer()?.try_into().map_err(|_| invalid())?)
+        }
+        "unix-socket" => OptionKind::UnixSocket(builder::filename(&text()?)),
+        "variable" => match text()?.split_once('=') {
+            Some((name, value)) => OptionKind::Variable(VariableDefinition {
+                name: name.to_string(),
+                space0: whitespace(""),
+                space1: whitespace(""),
+                value: variable_value(value),
+            }),
+            None => return Err(invalid()),
+        },
+        "verbose" => OptionKind::Verbose(boolean()?),
+        "very-verbose" => OptionKind::VeryVerbose(boolean()?),
+        _ => return Err(format!("unknown option <{name}>")),
+    };
+    Ok(EntryOption {
+        line_terminators: vec![],
+        space0: whitespace(""),
+        space1: whitespace(""),
+        space2: whitespace(" "),
+        kind,
+        line_terminator0: builder::newline(),
+    })
+}
+
+/// Converts the value of a `variable` option, as written in a Hurl file.
+fn variable_value(s: &str) -> VariableValue {
+    match s {
+        "null" => VariableValue::Null,
+        "true" => VariableValue::Bool(true),
+        "false" => VariableValue::Bool(false),
+        _ => match (s.parse::<i64>(), s.parse::<f64>()) {
+            (Ok(n), _) => VariableValue::Integer(n),
+            (_, Ok(value)) if s.contains('.') => VariableValue::Float(Float {
+                value,
+                encoded: s.to_string(),
+            }),
+            _ => VariableValue::String(builder::template(s, escape_value, None)),
+        },
+    }
+}
+
+fn body(value: &JsonValue) -> Result<Bytes, String> {
+    if let Some(encoding) = get(value, "encoding").and_then(as_str) {
+        return match encoding.as_str() {
+            "base64" => Ok(Bytes::Base64(base64(&string(value, "value")?)?)),
+            _ => Err(format!("unknown body encoding <{encoding}>")),
+        };
+    }
+    let body_type = string(value, "type")?;
+    let body_value = get(value, "value");
+    let text = body_value.and_then(as_str);
+    let bytes = match (body_type.as_str(), text) {
+        ("file", _) => builder::file(&string(value, "filename")?),
+        // Multiline JSON strings end with a newline, unlike JSON string bodies.
+        ("json", Some(text)) if text.ends_with('\n') => builder::multiline(&text, "json"),
+        ("json", _) => match body_value {
+            Some(json) => {
+                let expressions = list(value, "expressions")?
+                    .into_iter()
+                    .map(|pointer| as_str(pointer).ok_or("invalid expression pointer"))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Bytes::Json(builder::indent_json(json_body(json, "", &expressions)?, 0))
+            }
+            None => return Err("invalid body of type <json>".to_string()),
+        },
+        // Multiline XML strings end with a newline, unlike raw XML bodies.
+        ("xml", Some(text)) if text.starts_with('<') && !text.ends_with('\n') => Bytes::Xml(text),
+        ("xml", Some(text)) => builder::multiline(&text, "xml"),
+        ("graphql", Some(text)) => builder::multiline(&text, "graphql"),
+        ("text", Some(text)) if !text.contains('\n') => {
+            Bytes::OnelineString(builder::template(&text, escape_oneline_string, Some('`')))
+        }
+        ("text", Some(text)) => builder::multiline(&text, ""),
+        (body_type, _) => return Err(format!("invalid body of type <{body_type}>")),
+    };
+    Ok(bytes)
+}
+
+/// Returns a JSON body of a JSON `value` at the `pointer` path, where the strings pointed by
+/// `expressions` are converted back to expressions.
+fn json_body(
+    value: &JsonValue,
+    pointer: &str,
+    expressions: &[String],
+) -> Result<JsonValue, String> {
+    let value = match value {
+        JsonValue::String(s) if expressions.iter().any(|p| p == pointer) => {
+            let s = s.to_string();
+            match s.strip_prefix("{{").and_then(|s| s.strip_suffix("}}")) {
+                Some(name) => JsonValue::Expression(builder::expr(name.trim())),
+                None => return Err(format!("invalid expression <{s}>")),
+            }
+        }
+        JsonValue::List { space0, elements } => JsonValue::List {
+            space0: space0.clone(),
+            elements: elements
+                .iter()
+                .enumerate()
+                .map(|(i, element)| {
+                    let pointer = format!("{pointer}/{i}");
+                    Ok(JsonListElement {
+                        value: json_body(&element.value, &pointer, expressions)?,
+                        ..element.clone()
+                    })
+                })
+                .collect::<Result<_, String>>()?,
+        },
+        JsonValue::Object { space0, elements } => JsonValue::Object {
+            space0: space0.clone(),
+            elements: elements
+                .iter()
+                .map(|element| {
+                    let name = element.name.to_string();
+                    let name = name.replace('~', "~0").replace('/', "~1");
+                    let pointer = format!("{pointer}/{name}");
+                    Ok(JsonObjectElement {
+                        value: json_body(&element.value, &pointer, expressions)?,
+                        ..element.clone()
+                    })
+                })
+                .collect::<Result<_, String>>()?,
+        },
+        value => value.clone(),
+    };
+    Ok(value)
+}
+
+fn escape_oneline_string(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('`', "\\`")
+}
+
+fn base64(s: &str) -> Result<Base64, String> {
+    match general_purpose::STANDARD.decode(s) {
+        Ok(value) => Ok(builder::base64(&value)),
+        Err(_) => Err(format!("invalid base64 value <{s}>")),
+    }
+}
+
+fn capture(value: &JsonValue) -> Result<Capture, String> {
+    let name = string(value, "name")?;
+    let default = match get(value, "default") {
+        Some(default) => Some(CaptureDefault {
+            space0: whitespace(" "),
+            space1: whitespace(" "),
+            value: match scalar(default)? {
+                PredicateValue::Null => VariableValue::Null,
+                PredicateValue::Bool(b) => VariableValue::Bool(b),
+                PredicateValue::Integer(n) => VariableValue::Integer(n),
+                PredicateValue::Float(f) => VariableValue::Float(f),
+                PredicateValue::String(s) => VariableValue::String(s),
+                _ => return Err("invalid capture default".to_string()),
+            },
+        }),
+        None => None,
+    };
+    Ok(Capture {
+        line_terminators: vec![],
+        space0: whitespace(""),
+        name: builder::key(&name),
+        space1: whitespace(""),
+        space2: whitespace(" "),
+        query: query(value)?,
+        filters: filters(value)?,
+        default,
+        line_terminator0: builder::newline(),
+    })
+}
+
+fn assert(value: &JsonValue) -> Result<Assert, String> {
+    let predicate = match get(value, "predicate") {
+        Some(predicate) => predicate,
+        None => return Err("missing assert predicate".to_string()),
+    };
+    Ok(Assert {
+        line_terminators: vec![],
+        space0: whitespace(""),
+        query: query(value)?,
+        filters: filters(value)?,
+        space1: whitespace(" "),
+        predicate: self::predicate(predicate)?,
+        line_terminator0: builder::newline(),
+    })
+}
+
+fn query(value: &JsonValue) -> Result<Query, String> {
+    let query = match get(value, "query") {
+        Some(query) => query,
+        None => return Err("missing query".to_string()),
+    };
+    let space0 = whitespace(" ");
+    let query_type = string(query, "type")?;
+    let value = match query_type.as_str() {
+        "status" => QueryValue::Status,
+        "url" => QueryValue::Url,
+        "body" => QueryValue::Body,
+        "duration" => QueryValue::Duration,
+        "bytes" => QueryValue::Bytes,
+        "sha256" => QueryValue::Sha256,
+        "md5" => QueryValue::Md5,
+        "jsonpath" => QueryValue::Jsonpath {
+            space0,
+            expr: quoted(&string(query, "expr")?),
+        },
+        "xpath" => QueryValue::Xpath {
+            space0,
+            expr: quoted(&string(query, "expr")?),
+        },
+        "header" => QueryValue::Header {
+            space0,
+            name: quoted(&string(query, "name")?),
+        },
+        "variable" => QueryValue::Variable {
+            space0,
+            name: quoted(&string(query, "name")?),
+        },
+        "cookie" => QueryValue::Cookie {
+            space0,
+            expr: cookie_path(&string(query, "expr")?)?,
+        },
+        "certificate" => QueryValue::Certificate {
+            space0,
+            attribute_name: match string(query, "expr")?.as_str() {
+                "Subject" => CertificateAttributeName::Subject,
+                "Issuer" => CertificateAttributeName::Issuer,
+                "Start-Date" => CertificateAttributeName::StartDate,
+                "Expire-Date" => CertificateAttributeName::ExpireDate,
+                "Serial-Number" => CertificateAttributeName::SerialNumber,
+                name => return Err(format!("unknown certificate attribute <{name}>")),
+            },
+        },
+        "regex" => QueryValue::Regex {
+            space0,
+            value: regex_value(get(query, "expr"))?,
+        },
+        _ => return Err(format!("unknown query type <{query_type}>")),
+    };
+    Ok(Query {
+        source_info: builder::source_info(),
+        value,
+    })
+}
+
+/// Converts a cookie query expression `name[attribute]`.
+fn cookie_path(s: &str) -> Result<CookiePath, String> {
+    let (name, attribute) = match s.split_once('[') {
+        Some((name, attribute)) => match attribute.strip_suffix(']') {
+            Some(attribute) => (name, Some(attribute.to_string())),
+            None => return Err(format!("invalid cookie expression <{s}>")),
+        },
+        None => (s, None),
+    };
+    let attribute = match attribute {
+        Some(attribute) => {
+            let name = match attribute.to_lowercase().as_str() {
+                "value" => CookieAttributeName::Value(attribute),
+                "expires" => CookieAttributeName::Expires(attribute),
+                "max-age" => CookieAttributeName::MaxAge(attribute),
+                "domain" => CookieAttributeName::Domain(attribute),
+                "path" => CookieAttributeName::Path(attribute),
+                "secure" => CookieAttributeName::Secure(attribute),
+                "httponly" => CookieAttributeName::HttpOnly(attribute),
+                "samesite" => CookieAttributeName::SameSite(attribute),
+                _ => return Err(format!("invalid cookie attribute <{attribute}>")),
+            };
+            Some(CookieAttribute {
+                space0: whitespace(""),
+                name,
+                space1: whitespace(""),
+            })
+        }
+        None => None,
+    };
+    Ok(CookiePath {
+        name: builder::template(name, escape_string, None),
+        attribute,
+    })
+}
+
+fn filters(value: &JsonValue) -> Result<Vec<(Whitespace, Filter)>, String> {
+    let mut filters = vec![];
+    for filter in list(value, "filters")? {
+        let space0 = whitespace(" ");
+        let filter_type = string(filter, "type")?;
+        let value = match filter_type.as_str() {
+            "count" => FilterValue::Count,
+            "daysAfterNow" => FilterValue::DaysAfterNow,
+            "daysBeforeNow" => FilterValue::DaysBeforeNow,
+            "htmlEscape" => FilterValue::HtmlEscape,
+            "htmlUnescape" => FilterValue::HtmlUnescape,
+            "urlEncode" => FilterValue::UrlEncode,
+            "urlDecode" => FilterValue::UrlDecode,
+            "toInt" => FilterValue::ToInt,
+            "decode" => FilterValue::Decode {
+                space0,
+                encoding: quoted(&string(filter, "encoding")?),
+            },
+            "format" => FilterValue::Format {
+                space0,
+                fmt: quoted(&string(filter, "fmt")?),
+            },
+            "split" => FilterValue::Split {
+                space0,
+                sep: quoted(&string(filter, "sep")?),
+            },
+            // Older exports wrote xpath filters with a `toDate` type.
+            "xpath" | "toDate" if get(filter, "expr").is_some() => FilterValue::XPath {
+                space0,
+                expr: quoted(&string(filter, "expr")?),
+            },
+            "toDate" => FilterValue::ToDate {
+                space0,
+                fmt: quoted(&string(filter, "fmt")?),
+            },
+            "nth" => match get(filter, "n").and_then(as_u64) {
+                Some(n) => FilterValue::Nth { space0, n },
+                _ => return Err("invalid nth filter".to_string()),
+            },
+            "regex" => FilterValue::Regex {
+                space0,
+                value: regex_value(get(filter, "expr"))?,
+            },
+            "replace" => FilterValue::Replace {
+                space0,
+                old_value: regex_value(get(filter, "old_value"))?,
+                space1: whitespace(" "),
+                new_value: quoted(&string(filter, "new_value")?),
+            },
+            _ => return Err(format!("unknown filter type <{filter_type}>")),
+        };
+        let filter = Filter {
+            source_info: builder::source_info(),
+            value,
+        };
+        filters.push((whitespace(" "), filter));
+    }
+    Ok(filters)
+}
+
+fn predicate(value: &JsonValue) -> Result<Predicate, String> {
+    let predicate_type = string(value, "type")?;
+    let space0 = whitespace(" ");
+    let predicate_value = || -> Result<PredicateValue, String> {
+        let predicate_value = match get(value, "value") {
+            Some(predicate_value) => predicate_value,
+            None => return Err(format!("missing value for predicate <{predicate_type}>")),
+        };
+        match get(value, "encoding").and_then(as_str).as_deref() {
+            Some("base64") => Ok(PredicateValue::Base64(base64(&string(value, "value")?)?)),
+            Some("regex") => Ok(PredicateValue::Regex(regex(&string(value, "value")?)?)),
+            Some("expression") => Ok(PredicateValue::Expression(builder::expr(&string(
+                value, "value",
+            )?))),
+            Some(encoding) => Err(format!("unknown predicate encoding <{encoding}>")),
+            None => scalar(predicate_value),
+        }
+    };
+    let func = match predicate_type.as_str() {
+        "equal" => PredicateFuncValue::Equal {
+            space0,
+            value: predicate_value()?,
+            operator: true,
+        },
+        "not-equal" => PredicateFuncValue::NotEqual {
+            space0,
+            value: predicate_value()?,
+            operator: true,
+        },
+        "greater" => PredicateFuncValue::GreaterThan {
+            space0,
+            value: predicate_value()?,
+            operator: true,
+        },
+        "greater-or-equal" => PredicateFuncValue::GreaterThanOrEqual {
+            space0,
+            value: predicate_value()?,
+            operator: true,
+        },
+        "less" => PredicateFuncValue::LessThan {
+            space0,
+            value: predicate_value()?,
+            operator: true,
+        },
+        "less-or-equal" => PredicateFuncValue::LessThanOrEqual {
+            space0,
+            value: predicate_value()?,
+            operator: true,
+        },
+        "start-with" => PredicateFuncValue::StartWith {
+            space0,
+            value: predicate_value()?,
+        },
+        "end-with" => PredicateFuncValue::EndWith {
+            space0,
+            value: predicate_value()?,
+        },
+        "contain" => PredicateFuncValue::Contain {
+            space0,
+            value: predicate_value()?,
+        },
+        "include" => PredicateFuncValue::Include {
+            space0,
+            value: predicate_value()?,
+        },
+        "match" => PredicateFuncValue::Match {
+            space0,
+            value: predicate_value()?,
+        },
+        "exist" => PredicateFuncValue::Exist,
+        "isInteger" => PredicateFuncValue::IsInteger,
+        "isFloat" => PredicateFuncValue::IsFloat,
+        "isBoolean" => PredicateFuncValue::IsBoolean,
+        "isString" => PredicateFuncValue::IsString,
+        "isCollection" => PredicateFuncValue::IsCollection,
+        "isDate" => PredicateFuncValue::IsDate,
+        "isEmpty" => PredicateFuncValue::IsEmpty,
+        _ => return Err(format!("unknown predicate type <{predicate_type}>")),
+    };
+    Ok(Predicate {
+        not: get(value, "not").and_then(as_bool) == Some(true),
+        space0: whitespace(" "),
+        predicate_func: PredicateFunc {
+            source_info: builder::source_info(),
+            value: func,
+        },
+    })
+}
+
+/// Converts a scalar JSON `value` to a predicate value, strings being quoted.
+///
+/// Expressions (`{{name}}`) are exported with an `expression` encoding, so strings are never
+/// converted to expressions.
+fn scalar(value: &JsonValue) -> Result<PredicateValue, String> {
+    match value {
+        JsonValue::Null => Ok(PredicateValue::Null),
+        JsonValue::Boolean(b) => Ok(PredicateValue::Bool(*b)),
+        JsonValue::Number(n) => match (n.parse::<i64>(), n.parse::<f64>()) {
+            (Ok(n), _) => Ok(PredicateValue::Integer(n)),
+            (Err(_), Ok(value)) => Ok(PredicateValue::Float(Float {
+                value,
+                encoded: number(n, value),
+            })),
+            _ => Err(format!("invalid number <{n}>")),
+        },
+        JsonValue::String(s) => Ok(PredicateValue::String(quoted(&s.to_string()))),
+        _ => Err("invalid scalar value".to_string()),
+    }
+}
+
+/// Returns the Hurl text of a JSON float `n` of `value`.
+///
+/// The text is kept as is (`1.070` stays `1.070`), unless it's not a valid Hurl float: floats
+/// with an exponent or without a decimal part are rewritten from their value.
+fn number(n: &str, value: f64) -> String {
+    if n.contains('.') && !n.contains(['e', 'E']) {
+        return n.to_string();
+    }
+    let s = value.to_string();
+    if s.contains('.') {
+        s
+    } else {
+        format!("{s}.0")
+    }
+}
+
+/// Converts a regex query or filter argument: either a string template or a regex object.
+fn regex_value(value: Option<&JsonValue>) -> Result<RegexValue, String> {
+    match value {
+        Some(JsonValue::String(s)) => Ok(RegexValue::Template(quoted(&s.to_string()))),
+        Some(value) if get(value, "type").and_then(as_str).as_deref() == Some("regex") => {
+            Ok(RegexValue::Regex(regex(&string(value, "value")?)?))
+        }
+        _ => Err("invalid regex".to_string()),
+    }
+}
+
+fn regex(s: &str) -> Result<Regex, String> {
+    match regex::Regex::new(s) {
+        Ok(inner) => Ok(Regex { inner }),
+        Err(_) => Err(format!("invalid regex <{s}>")),
+    }
+}
+
+/// Returns the member `key` of a JSON object `value`.
+fn get<'a>(value: &'a JsonValue, key: &str) -> Option<&'a JsonValue> {
+    match value {
+        JsonValue::Object { elements, .. } => elements
+            .iter()
+            .find(|element| element.name.to_string() == key)
+            .map(|element| &element.value),
+        _ => None,
+    }
+}
+
+fn as_str(value: &JsonValue) -> Option<String> {
+    match value {
+        JsonValue::String(s) => Some(s.to_string()),
+        _ => None,
+    }
+}
+
+fn as_bool(value: &JsonValue) -> Option<bool> {
+    match value {
+        JsonValue::Boolean(b) => Some(*b),
+        _ => None,
+    }
+}
+
+fn as_i64(value: &JsonValue) -> Option<i64> {
+    match value {
+        JsonValue::Number(n) => n.parse().ok(),
+        _ => None,
+    }
+}
+
+fn as_u64(value: &JsonValue) -> Option<u64> {
+    match value {
+        JsonValue::Number(n) => n.parse().ok(),
+        _ => None,
+    }
+}
+
+/// Returns the string member `key` of a JSON object `value`.
+fn string(value: &JsonValue, key: &str) -> Result<String, String> {
+    match get(value, key).and_then(as_str) {
+        Some(s) => Ok(s),
+        None => Err(format!("missing string <{key}>")),
+    }
+}
+
+fn list<'a>(value: &'a JsonValue, key: &str) -> Result<Vec<&'a JsonValue>, String> {
+    match get(value, key) {
+        None => Ok(vec![]),
+        Some(JsonValue::List { elements, .. }) => Ok(elements.iter().map(|e| &e.value).collect()),
+        Some(_) => Err(format!("<{key}> must be a list")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::parser;
+
+    use super::*;
+    use crate::format;
+
+    /// Checks that a Hurl file `s` is unchanged after a JSON round trip.
+    fn round_trip(s: &str) -> String {
+        let hurl_file = parser::parse_hurl_file(s).unwrap();
+        let json = format::format_json(&hurl_file);
+        parse(&json).unwrap()
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let s = r#"POST http://localhost:8000/users?id=1
+User-Agent: hurl
+[QueryStringParams]
+name: Bob
+[Cookies]
+session: {{session}}
+[BasicAuth]
+bob: secret
+[Options]
+insecure: true
+variable: id=1
+{
+    "name": "Bob",
+    "age": 30
+}
+HTTP/1.1 201
+[Captures]
+id: jsonpath "$.id" toInt
+location: header "Location" regex /\/users\/(\d+)/ default "none"
+[Asserts]
+jsonpath "$.name" == "Bob"
+jsonpath "$.age" not isString
+header "Content-Type" contains "json"
+bytes count == {{count}}
+`Created`
+"#;
+        assert_eq!(round_trip(s), s);
+    }
+
+    #[test]
+    fn test_round_trip_bodies() {
+        let s = r#"POST http://localhost:8000/upload
+[MultipartFormData]
+key: value
+file: file,data.txt; text/plain
+
+POST http://localhost:8000/data
+file,data.bin;
+
+POST http://localhost:8000/data
+base64,SGVsbG8=;
+
+POST http://localhost:8000/data
+```
+Hello
+World
+```
+HTTP *
+"#;
+        assert_eq!(round_trip(s), s);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            parse("{}").unwrap_err(),
+            "Can not parse JSON file: missing entries"
+        );
+        assert_eq!(
+            parse(r#"{"entries":[{"request":{"url":"http://localhost"}}]}"#).unwrap_err(),
+            "Can not parse entry 1: missing string <method>"
+        );
+    }
+
+    #[test]
+    fn test_number() {
+        assert_eq!(number("1.0", 1.0), "1.0");
+        assert_eq!(number("1.070", 1.07), "1.070");
+        assert_eq!(
+            number("0.333333333333333333", 0.3333333333333333),
+            "0.333333333333333333"
+        );
+        assert_eq!(number("1e-16", 1e-16), "0.0000000000000001");
+        assert_eq!(number("1E2", 100.0), "100.0");
+    }
+
+    #[test]
+    fn test_round_trip_expressions() {
+        let s = r#"GET http://localhost:8000/{{path}}
+HTTP 200
+[Asserts]
+jsonpath "$.a" == "{{a}}"
+jsonpath "$.b" == {{b}}
+jsonpath "$.c" == "{{c}}-{{d}}"
+variable "e" == "e"
+"#;
+        assert_eq!(round_trip(s), s);
+    }
+
+    #[test]
+    fn test_round_trip_json_body() {
+        let s = r#"POST http://localhost:8000/users
+{
+    "name": "{{name}}",
+    "age": {{age}},
+    "ratio": 1.070,
+    "ids": [
+        {{id}},
+        "{{id}}"
+    ],
+    "a/b~c": {
+        "d": {{d}}
+    }
+}
+HTTP 200
+[Asserts]
+jsonpath "$.ratio" == 1.070
+"#;
+        assert_eq!(round_trip(s), s);
+
+        let s = r#"POST http://localhost:8000/g-clef
+{
+    "g_clef": "\uD834\uDD1E"
+}
+"#;
+        assert_eq!(
+            round_trip(s),
+            r#"POST http://localhost:8000/g-clef
+{
+    "g_clef": "𝄞"
+}
+"#
+        );
+    }
+
+    /// Checks that the JSON export of the integration tests Hurl files is unchanged after an
+    /// import.
+    #[test]
+    fn test_round_trip_integration_files() {
+        let dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../../integration");
+        let mut count = 0;
+        for tests in ["tests_ok", "tests_failed"] {
+            for entry in std::fs::read_dir(dir.join(tests)).unwrap() {
+                let path = entry.unwrap().path();
+                if path.extension().and_then(|e| e.to_str()) != Some("hurl") {
+                    continue;
+                }
+                let Ok(content) = std::fs::read_to_string(&path) else {
+                    continue;
+                };
+                let Ok(hurl_file) = parser::parse_hurl_file(&content) else {
+                    continue;
+                };
+                let json = format::format_json(&hurl_file);
+                let imported = parse(&json).unwrap_or_else(|e| panic!("{}: {e}", path.display()));
+                let hurl_file = parser::parse_hurl_file(&imported)
+                    .unwrap_or_else(|_| panic!("{}: invalid import\n{imported}", path.display()));
+                assert_eq!(format::format_json(&hurl_file), json, "{}", path.display());
+                count += 1;
+            }
+        }
+        assert!(count > 0);
+    }
+}
@@ -15,7 +15,15 @@
  * limitations under the License.
  *
  */
+mod builder;
 pub mod cli;
 pub mod curl;
+mod escape;
 pub mod format;
+pub mod har;
+pub mod http;
+pub mod json;
 pub mod linter;
+pub mod openapi;
+pub mod postman;
+pub mod refactor;
@@ -0,0 +1,143 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2023 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+//! Linter configuration, read from a `.hurlfmt.toml` file:
+//!
+//! ```toml
+//! [rules]
+//! unused-capture = "off"
+//! no-assert = "error"
+//! ```
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::linter::core::{LinterError, RULES};
+
+pub const CONFIG_FILENAME: &str = ".hurlfmt.toml";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Severity {
+    Off,
+    Warning,
+    Error,
+}
+
+impl Severity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Severity::Off => "off",
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        }
+    }
+}
+
+/// Severity of each rule, rules not configured being warnings.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Config {
+    rules: HashMap<String, Severity>,
+}
+
+impl Config {
+    /// Parses a linter configuration from a TOML content `s`.
+    pub fn parse(s: &str) -> Result<Config, String> {
+        let table = s
+            .parse::<toml::Table>()
+            .map_err(|e| e.message().to_string())?;
+        let mut rules = HashMap::new();
+        for (key, value) in &table {
+            if key != "rules" {
+                return Err(format!("unknown section <{key}>"));
+            }
+            let Some(value) = value.as_table() else {
+                return Err("<rules> must be a table".to_string());
+            };
+            for (rule, severity) in value {
+                if !RULES.contains(&rule.as_str()) {
+                    return Err(format!("unknown rule <{rule}>"));
+                }
+                let severity = match severity.as_str() {
+                    Some("off") => Severity::Off,
+                    Some("warning") => Severity::Warning,
+                    Some("error") => Severity::Error,
+                    _ => {
+                        return Err(format!(
+                            "invalid severity for rule <{rule}>, expecting off, warning or error"
+                        ))
+                    }
+                };
+                rules.insert(rule.clone(), severity);
+            }
+        }
+        Ok(Config { rules })
+    }
+
+    /// Returns the severity of this linter `error`.
+    pub fn severity(&self, error: &LinterError) -> Severity {
+        self.rules
+            .get(error.rule())
+            .copied()
+            .unwrap_or(Severity::Warning)
+    }
+}
+
+/// Returns the path of the configuration file of `dir`, looking into `dir` and its ancestors.
+pub fn find(dir: &Path) -> Option<PathBuf> {
+    dir.ancestors()
+        .map(|dir| dir.join(CONFIG_FILENAME))
+        .find(|path| path.is_file())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let config = Config::parse(
+            r#"
+[rules]
+unused-capture = "off"
+no-assert = "error"
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            config.severity(&LinterError::UnusedCapture("id".to_string())),
+            Severity::Off
+        );
+        assert_eq!(config.severity(&LinterError::NoAssert), Severity::Error);
+        assert_eq!(config.severity(&LinterError::OneSpace), Severity::Warning);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Config::parse("[rules]\nfoo = \"off\"\n").unwrap_err(),
+            "unknown rule <foo>"
+        );
+        assert_eq!(
+            Config::parse("[rules]\none-space = \"fatal\"\n").unwrap_err(),
+            "invalid severity for rule <one-space>, expecting off, warning or error"
+        );
+        assert_eq!(
+            Config::parse("[format]\n").unwrap_err(),
+            "unknown section <format>"
+        );
+    }
+}
@@ -28,4 +28,43 @@ pub enum LinterError {
     UnnecessarySpace,
     UnnecessaryJsonEncoding,
     OneSpace,
+    UndefinedVariable(String),
+    UnusedCapture(String),
+    DuplicateCapture(String),
+    NoAssert,
+    JsonpathOnNonJson(String),
+    OptionWithoutEffect { name: String, required: String },
+    ConflictingOptions { name: String, other: String },
+}
+
+/// Identifiers of the linter rules, as used in the configuration file and suppression comments.
+pub const RULES: [&str; 10] = [
+    "unnecessary-space",
+    "unnecessary-json-encoding",
+    "one-space",
+    "undefined-variable",
+    "unused-capture",
+    "duplicate-capture",
+    "no-assert",
+    "jsonpath-on-non-json",
+    "option-without-effect",
+    "conflicting-options",
+];
+
+impl LinterError {
+    /// Returns the identifier of the rule raising this error.
+    pub fn rule(&self) -> &'static str {
+        match self {
+            LinterError::UnnecessarySpace => "unnecessary-space",
+            LinterError::UnnecessaryJsonEncoding => "unnecessary-json-encoding",
+            LinterError::OneSpace => "one-space",
+            LinterError::UndefinedVariable(_) => "undefined-variable",
+            LinterError::UnusedCapture(_) => "unused-capture",
+            LinterError::DuplicateCapture(_) => "duplicate-capture",
+            LinterError::NoAssert => "no-assert",
+            LinterError::JsonpathOnNonJson(_) => "jsonpath-on-non-json",
+            LinterError::OptionWithoutEffect { .. } => "option-without-effect",
+            LinterError::ConflictingOptions { .. } => "conflicting-options",
+        }
+    }
 }
@@ -31,18 +31,46 @@ impl Error for linter::Error {
     }
 
     fn description(&self) -> String {
-        match self.inner {
+        match &self.inner {
             LinterError::UnnecessarySpace => "Unnecessary space".to_string(),
             LinterError::UnnecessaryJsonEncoding => "Unnecessary json encoding".to_string(),
             LinterError::OneSpace => "One space ".to_string(),
+            LinterError::UndefinedVariable(name) => format!("Undefined variable <{name}>"),
+            LinterError::UnusedCapture(name) => format!("Unused capture <{name}>"),
+            LinterError::DuplicateCapture(name) => format!("Duplicate capture <{name}>"),
+            LinterError::NoAssert => "Entry without assert".to_string(),
+            LinterError::JsonpathOnNonJson(content_type) => {
+                format!("Jsonpath query on a non-JSON response <{content_type}>")
+            }
+            LinterError::OptionWithoutEffect { name, required } => {
+                format!("Option <{name}> has no effect without option <{required}>")
+            }
+            LinterError::ConflictingOptions { name, other } => {
+                format!("Option <{name}> conflicts with option <{other}>")
+            }
         }
     }
 
     fn fixme(&self) -> String {
-        match self.inner {
+        match &self.inner {
             LinterError::UnnecessarySpace => "Remove space".to_string(),
             LinterError::UnnecessaryJsonEncoding => "Use Simple String".to_string(),
             LinterError::OneSpace => "Use only one space".to_string(),
+            LinterError::UndefinedVariable(_) => {
+                "Capture it or define it with a variable option".to_string()
+            }
+            LinterError::UnusedCapture(_) => "Use the captured value or remove it".to_string(),
+            LinterError::DuplicateCapture(_) => {
+                "Previous capture is never used, rename or remove it".to_string()
+            }
+            LinterError::NoAssert => "Add a response status or asserts".to_string(),
+            LinterError::JsonpathOnNonJson(_) => {
+                "Use a JSON content type or another query".to_string()
+            }
+            LinterError::OptionWithoutEffect { required, .. } => {
+                format!("Enable option <{required}> or remove this one")
+            }
+            LinterError::ConflictingOptions { .. } => "Remove one of the options".to_string(),
         }
     }
 }
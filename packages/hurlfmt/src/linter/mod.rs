@@ -15,9 +15,12 @@
  * limitations under the License.
  *
  */
-pub use rules::{check_hurl_file, lint_hurl_file};
+pub use rules::{check_hurl_file, check_hurl_file_with_config, lint_hurl_file};
 
-pub use self::core::{Error, LinterError};
+pub use self::config::{Config, Severity, CONFIG_FILENAME};
+pub use self::core::{Error, LinterError, RULES};
+pub mod config;
 mod core;
 mod error;
 mod rules;
+mod semantic;
@@ -15,542 +15,323 @@
  * limitations under the License.
  *
  */
+use std::collections::HashMap;
+
+use hurl_core::ast::visit::{self, Visitor};
+use hurl_core::ast::visit_mut::{self, VisitorMut};
 use hurl_core::ast::*;
 
+use crate::linter::config::{Config, Severity};
 use crate::linter::core::{Error, LinterError};
+use crate::linter::semantic;
+
+/// Prefix of the comments suppressing lint errors, for instance `# hurlfmt: ignore unused-capture`.
+const IGNORE_COMMENT: &str = "hurlfmt: ignore";
 
-/// Returns lint errors for the `hurl_file`.
+/// Returns lint errors for the `hurl_file`, sorted by position.
 pub fn check_hurl_file(hurl_file: &HurlFile) -> Vec<Error> {
-    hurl_file.entries.iter().flat_map(check_entry).collect()
+    let mut checker = Checker { errors: vec![] };
+    checker.visit_hurl_file(hurl_file);
+    let mut errors = checker.errors;
+    errors.append(&mut semantic::check_hurl_file(hurl_file));
+    errors.sort_by_key(|e| (e.source_info.start.line, e.source_info.start.column));
+    errors
 }
 
-/// Returns a new linted instance from this `hurl_file`.
-pub fn lint_hurl_file(hurl_file: &HurlFile) -> HurlFile {
-    HurlFile {
-        entries: hurl_file.entries.iter().map(lint_entry).collect(),
-        line_terminators: hurl_file.line_terminators.clone(),
-    }
+/// Returns lint errors for the `hurl_file` whose rules are enabled by `config`.
+///
+/// Errors can also be suppressed with a `# hurlfmt: ignore` comment, on the line of the error or
+/// on the line before. The comment can be followed by a list of rules, separated by commas or
+/// spaces, to suppress only these rules.
+pub fn check_hurl_file_with_config(hurl_file: &HurlFile, config: &Config) -> Vec<Error> {
+    let mut ignores = Ignores::default();
+    ignores.visit_hurl_file(hurl_file);
+    check_hurl_file(hurl_file)
+        .into_iter()
+        .filter(|e| config.severity(&e.inner) != Severity::Off)
+        .filter(|e| !ignores.is_ignored(e))
+        .collect()
 }
 
-fn check_entry(entry: &Entry) -> Vec<Error> {
-    let mut errors = vec![];
-    errors.append(&mut check_request(&entry.request));
-    match &entry.response {
-        Some(r) => errors.append(&mut check_response(r)),
-        None => {}
-    };
-    errors
+/// Returns a new linted instance from this `hurl_file`.
+pub fn lint_hurl_file(hurl_file: &HurlFile) -> HurlFile {
+    let mut hurl_file = hurl_file.clone();
+    Linter.visit_hurl_file(&mut hurl_file);
+    hurl_file
 }
 
-fn lint_entry(entry: &Entry) -> Entry {
-    let request = lint_request(&entry.request);
-    let response = entry.response.as_ref().map(lint_response);
-    Entry { request, response }
+/// Collects lint errors, without modifying the AST.
+struct Checker {
+    errors: Vec<Error>,
 }
 
-fn check_request(request: &Request) -> Vec<Error> {
-    let mut errors = vec![];
-    if !request.space0.value.is_empty() {
-        errors.push(Error {
-            source_info: request.space0.source_info.clone(),
-            inner: LinterError::UnnecessarySpace,
-        });
-    }
-    if request.space1.value != " " {
-        errors.push(Error {
-            source_info: request.space1.source_info.clone(),
-            inner: LinterError::OneSpace,
+impl Checker {
+    fn push(&mut self, whitespace: &Whitespace, inner: LinterError) {
+        self.errors.push(Error {
+            source_info: whitespace.source_info.clone(),
+            inner,
         });
     }
-    for error in check_line_terminator(&request.line_terminator0) {
-        errors.push(error);
+
+    fn check_line_terminator(&mut self, line_terminator: &LineTerminator) {
+        if line_terminator.comment.is_none() && !line_terminator.space0.value.is_empty() {
+            self.push(&line_terminator.space0, LinterError::UnnecessarySpace);
+        }
     }
-    errors
 }
 
-fn lint_request(request: &Request) -> Request {
-    let line_terminators = request.line_terminators.clone();
-    let space0 = empty_whitespace();
-    let method = request.method.clone();
-    let space1 = one_whitespace();
-
-    let url = request.url.clone();
-    let line_terminator0 = lint_line_terminator(&request.line_terminator0);
-    let headers = request.headers.iter().map(lint_key_value).collect();
-    let body = request.body.as_ref().map(lint_body);
-    let mut sections: Vec<Section> = request.sections.iter().map(lint_section).collect();
-    sections.sort_by_key(|k| section_value_index(k.value.clone()));
-
-    let source_info = SourceInfo::new(0, 0, 0, 0);
-    Request {
-        line_terminators,
-        space0,
-        method,
-        space1,
-        url,
-        line_terminator0,
-        headers,
-        sections,
-        body,
-        source_info,
+impl Visitor for Checker {
+    fn visit_request(&mut self, request: &Request) {
+        if !request.space0.value.is_empty() {
+            self.push(&request.space0, LinterError::UnnecessarySpace);
+        }
+        if request.space1.value != " " {
+            self.push(&request.space1, LinterError::OneSpace);
+        }
+        self.check_line_terminator(&request.line_terminator0);
+        visit::walk_request(self, request);
     }
-}
 
-fn check_response(response: &Response) -> Vec<Error> {
-    let mut errors = vec![];
-    if !response.space0.value.is_empty() {
-        errors.push(Error {
-            source_info: response.space0.source_info.clone(),
-            inner: LinterError::UnnecessarySpace,
-        });
+    fn visit_response(&mut self, response: &Response) {
+        if !response.space0.value.is_empty() {
+            self.push(&response.space0, LinterError::UnnecessarySpace);
+        }
+        visit::walk_response(self, response);
     }
-    errors
 }
 
-fn lint_response(response: &Response) -> Response {
-    let line_terminators = response.line_terminators.clone();
-    let space0 = empty_whitespace();
-    let version = response.version.clone();
-    let space1 = response.space1.clone();
-    let status = response.status.clone();
-    let line_terminator0 = response.line_terminator0.clone();
-    let headers = response.headers.iter().map(lint_key_value).collect();
-    let mut sections: Vec<Section> = response.sections.iter().map(lint_section).collect();
-    sections.sort_by_key(|k| section_value_index(k.value.clone()));
-    let body = response.body.clone();
-
-    Response {
-        line_terminators,
-        space0,
-        version,
-        space1,
-        status,
-        line_terminator0,
-        headers,
-        sections,
-        body,
-        source_info: SourceInfo::new(0, 0, 0, 0),
-    }
+/// Collects `# hurlfmt: ignore` comments: rules ignored by line, an empty list of rules meaning
+/// all rules.
+#[derive(Default)]
+struct Ignores {
+    lines: HashMap<usize, Vec<String>>,
 }
 
-fn lint_section(section: &Section) -> Section {
-    let line_terminators = section.line_terminators.clone();
-    let line_terminator0 = section.line_terminator0.clone();
-    let value = lint_section_value(&section.value);
-    Section {
-        line_terminators,
-        space0: empty_whitespace(),
-        value,
-        line_terminator0,
-        source_info: SourceInfo::new(0, 0, 0, 0),
+impl Ignores {
+    fn is_ignored(&self, error: &Error) -> bool {
+        let line = error.source_info.start.line;
+        [line, line.saturating_sub(1)].iter().any(|line| {
+            self.lines.get(line).is_some_and(|rules| {
+                rules.is_empty() || rules.iter().any(|rule| rule == error.inner.rule())
+            })
+        })
     }
 }
 
-fn lint_section_value(section_value: &SectionValue) -> SectionValue {
-    match section_value {
-        SectionValue::QueryParams(params) => {
-            SectionValue::QueryParams(params.iter().map(lint_key_value).collect())
-        }
-        SectionValue::BasicAuth(param) => {
-            SectionValue::BasicAuth(param.as_ref().map(lint_key_value))
-        }
-        SectionValue::Captures(captures) => {
-            SectionValue::Captures(captures.iter().map(lint_capture).collect())
-        }
-        SectionValue::Asserts(asserts) => {
-            SectionValue::Asserts(asserts.iter().map(lint_assert).collect())
-        }
-        SectionValue::FormParams(params) => {
-            SectionValue::FormParams(params.iter().map(lint_key_value).collect())
-        }
-        SectionValue::MultipartFormData(params) => {
-            SectionValue::MultipartFormData(params.iter().map(lint_multipart_param).collect())
-        }
-        SectionValue::Cookies(cookies) => {
-            SectionValue::Cookies(cookies.iter().map(lint_cookie).collect())
-        }
-        SectionValue::Options(options) => {
-            SectionValue::Options(options.iter().map(lint_entry_option).collect())
+impl Visitor for Ignores {
+    fn visit_line_terminator(&mut self, line_terminator: &LineTerminator) {
+        let Some(comment) = &line_terminator.comment else {
+            return;
+        };
+        if let Some(rules) = comment.value.trim().strip_prefix(IGNORE_COMMENT) {
+            let rules = rules
+                .split(|c: char| c == ',' || c.is_whitespace())
+                .filter(|rule| !rule.is_empty())
+                .map(|rule| rule.to_string())
+                .collect();
+            let line = line_terminator.space0.source_info.start.line;
+            self.lines.insert(line, rules);
         }
     }
 }
 
-fn section_value_index(section_value: SectionValue) -> u32 {
-    match section_value {
-        // Request sections
-        SectionValue::QueryParams(_) => 0,
-        SectionValue::BasicAuth(_) => 1,
-        SectionValue::FormParams(_) => 2,
-        SectionValue::MultipartFormData(_) => 3,
-        SectionValue::Cookies(_) => 4,
-        SectionValue::Options(_) => 5,
-        // Response sections
-        SectionValue::Captures(_) => 0,
-        SectionValue::Asserts(_) => 1,
+/// Rewrites the AST in place with normalized whitespaces and sections order.
+struct Linter;
+
+impl VisitorMut for Linter {
+    fn visit_request(&mut self, request: &mut Request) {
+        request.space0 = empty_whitespace();
+        request.space1 = one_whitespace();
+        lint_line_terminator(&mut request.line_terminator0);
+        visit_mut::walk_request(self, request);
+        request
+            .sections
+            .sort_by_key(|section| section_value_index(&section.value));
     }
-}
 
-fn lint_assert(assert: &Assert) -> Assert {
-    let filters = assert
-        .filters
-        .iter()
-        .map(|(_, f)| (one_whitespace(), lint_filter(f)))
-        .collect();
-    Assert {
-        line_terminators: assert.line_terminators.clone(),
-        space0: empty_whitespace(),
-        query: lint_query(&assert.query),
-        filters,
-        space1: one_whitespace(),
-        predicate: lint_predicate(&assert.predicate),
-        line_terminator0: assert.line_terminator0.clone(),
+    fn visit_response(&mut self, response: &mut Response) {
+        // Response bodies are kept as is.
+        response.space0 = empty_whitespace();
+        for header in &mut response.headers {
+            self.visit_key_value(header);
+        }
+        for section in &mut response.sections {
+            self.visit_section(section);
+        }
+        response
+            .sections
+            .sort_by_key(|section| section_value_index(&section.value));
     }
-}
 
-fn lint_capture(capture: &Capture) -> Capture {
-    let filters = capture
-        .filters
-        .iter()
-        .map(|(_, f)| (one_whitespace(), lint_filter(f)))
-        .collect();
-    Capture {
-        line_terminators: capture.line_terminators.clone(),
-        space0: empty_whitespace(),
-        name: capture.name.clone(),
-        space1: empty_whitespace(),
-        space2: one_whitespace(),
-        query: lint_query(&capture.query),
-        filters,
-        line_terminator0: lint_line_terminator(&capture.line_terminator0),
+    fn visit_section(&mut self, section: &mut Section) {
+        section.space0 = empty_whitespace();
+        visit_mut::walk_section(self, section);
     }
-}
 
-fn lint_query(query: &Query) -> Query {
-    Query {
-        source_info: SourceInfo::new(0, 0, 0, 0),
-        value: lint_query_value(&query.value),
+    fn visit_key_value(&mut self, key_value: &mut KeyValue) {
+        key_value.space0 = empty_whitespace();
+        key_value.space1 = empty_whitespace();
+        key_value.space2 = if key_value.value.elements.is_empty() {
+            empty_whitespace()
+        } else {
+            one_whitespace()
+        };
+        visit_mut::walk_key_value(self, key_value);
     }
-}
 
-fn lint_query_value(query_value: &QueryValue) -> QueryValue {
-    match query_value {
-        QueryValue::Status => QueryValue::Status,
-        QueryValue::Url => QueryValue::Url,
-        QueryValue::Header { name, .. } => QueryValue::Header {
-            name: name.clone(),
-            space0: one_whitespace(),
-        },
-        QueryValue::Cookie {
-            expr: CookiePath { name, attribute },
-            ..
-        } => {
-            let attribute = attribute.as_ref().map(lint_cookie_attribute);
-            QueryValue::Cookie {
-                space0: one_whitespace(),
-                expr: CookiePath {
-                    name: name.clone(),
-                    attribute,
-                },
-            }
+    fn visit_capture(&mut self, capture: &mut Capture) {
+        capture.space0 = empty_whitespace();
+        capture.space1 = empty_whitespace();
+        capture.space2 = one_whitespace();
+        for (space, _) in &mut capture.filters {
+            *space = one_whitespace();
         }
-        QueryValue::Body => QueryValue::Body,
-        QueryValue::Xpath { expr, .. } => QueryValue::Xpath {
-            expr: expr.clone(),
-            space0: one_whitespace(),
-        },
-        QueryValue::Jsonpath { expr, .. } => QueryValue::Jsonpath {
-            expr: expr.clone(),
-            space0: one_whitespace(),
-        },
-        QueryValue::Regex { value, .. } => QueryValue::Regex {
-            value: lint_regex_value(value),
-            space0: one_whitespace(),
-        },
-        QueryValue::Variable { name, .. } => QueryValue::Variable {
-            name: name.clone(),
-            space0: one_whitespace(),
-        },
-        QueryValue::Duration => QueryValue::Duration,
-        QueryValue::Bytes => QueryValue::Bytes,
-        QueryValue::Sha256 => QueryValue::Sha256,
-        QueryValue::Md5 => QueryValue::Md5,
-        QueryValue::Certificate {
-            attribute_name: field,
-            ..
-        } => QueryValue::Certificate {
-            attribute_name: field.clone(),
-            space0: one_whitespace(),
-        },
+        lint_line_terminator(&mut capture.line_terminator0);
+        visit_mut::walk_capture(self, capture);
     }
-}
 
-fn lint_regex_value(regex_value: &RegexValue) -> RegexValue {
-    match regex_value {
-        RegexValue::Template(template) => RegexValue::Template(lint_template(template)),
-        RegexValue::Regex(regex) => RegexValue::Regex(regex.clone()),
+    fn visit_capture_default(&mut self, default: &mut CaptureDefault) {
+        default.space0 = one_whitespace();
+        default.space1 = one_whitespace();
+        visit_mut::walk_capture_default(self, default);
     }
-}
 
-fn lint_cookie_attribute(cookie_attribute: &CookieAttribute) -> CookieAttribute {
-    let space0 = empty_whitespace();
-    let name = lint_cookie_attribute_name(&cookie_attribute.name);
-    let space1 = empty_whitespace();
-    CookieAttribute {
-        space0,
-        name,
-        space1,
+    fn visit_assert(&mut self, assert: &mut Assert) {
+        assert.space0 = empty_whitespace();
+        for (space, _) in &mut assert.filters {
+            *space = one_whitespace();
+        }
+        assert.space1 = one_whitespace();
+        visit_mut::walk_assert(self, assert);
+    }
+
+    fn visit_query_value(&mut self, query_value: &mut QueryValue) {
+        match query_value {
+            QueryValue::Header { space0, .. }
+            | QueryValue::Cookie { space0, .. }
+            | QueryValue::Xpath { space0, .. }
+            | QueryValue::Jsonpath { space0, .. }
+            | QueryValue::Regex { space0, .. }
+            | QueryValue::Variable { space0, .. }
+            | QueryValue::Certificate { space0, .. } => *space0 = one_whitespace(),
+            _ => {}
+        }
+        visit_mut::walk_query_value(self, query_value);
     }
-}
 
-fn lint_cookie_attribute_name(cookie_attribute_name: &CookieAttributeName) -> CookieAttributeName {
-    match cookie_attribute_name {
-        CookieAttributeName::Value(_) => CookieAttributeName::Value("Value".to_string()),
-        CookieAttributeName::Expires(_) => CookieAttributeName::Expires("Expires".to_string()),
-        CookieAttributeName::MaxAge(_) => CookieAttributeName::MaxAge("Max-Age".to_string()),
-        CookieAttributeName::Domain(_) => CookieAttributeName::Domain("Domain".to_string()),
-        CookieAttributeName::Path(_) => CookieAttributeName::Path("Path".to_string()),
-        CookieAttributeName::Secure(_) => CookieAttributeName::Secure("Secure".to_string()),
-        CookieAttributeName::HttpOnly(_) => CookieAttributeName::HttpOnly("HttpOnly".to_string()),
-        CookieAttributeName::SameSite(_) => CookieAttributeName::SameSite("SameSite".to_string()),
+    fn visit_cookie_attribute(&mut self, cookie_attribute: &mut CookieAttribute) {
+        cookie_attribute.space0 = empty_whitespace();
+        cookie_attribute.name = lint_cookie_attribute_name(&cookie_attribute.name);
+        cookie_attribute.space1 = empty_whitespace();
+        visit_mut::walk_cookie_attribute(self, cookie_attribute);
     }
-}
 
-fn lint_predicate(predicate: &Predicate) -> Predicate {
-    Predicate {
-        not: predicate.not,
-        space0: if predicate.not {
+    fn visit_predicate(&mut self, predicate: &mut Predicate) {
+        predicate.space0 = if predicate.not {
             one_whitespace()
         } else {
             empty_whitespace()
-        },
-        predicate_func: lint_predicate_func(&predicate.predicate_func),
-    }
-}
-
-fn lint_predicate_func(predicate_func: &PredicateFunc) -> PredicateFunc {
-    PredicateFunc {
-        source_info: SourceInfo::new(0, 0, 0, 0),
-        value: lint_predicate_func_value(&predicate_func.value),
+        };
+        visit_mut::walk_predicate(self, predicate);
     }
-}
 
-fn lint_predicate_func_value(predicate_func_value: &PredicateFuncValue) -> PredicateFuncValue {
-    match predicate_func_value {
-        PredicateFuncValue::Equal { value, .. } => PredicateFuncValue::Equal {
-            space0: one_whitespace(),
-            value: lint_predicate_value(value),
-            operator: true,
-        },
-        PredicateFuncValue::NotEqual { value, .. } => PredicateFuncValue::NotEqual {
-            space0: one_whitespace(),
-            value: lint_predicate_value(value),
-            operator: true,
-        },
-        PredicateFuncValue::GreaterThan { value, .. } => PredicateFuncValue::GreaterThan {
-            space0: one_whitespace(),
-            value: lint_predicate_value(value),
-            operator: true,
-        },
-        PredicateFuncValue::GreaterThanOrEqual { value, .. } => {
-            PredicateFuncValue::GreaterThanOrEqual {
-                space0: one_whitespace(),
-                value: lint_predicate_value(value),
-                operator: true,
+    fn visit_predicate_func_value(&mut self, predicate_func_value: &mut PredicateFuncValue) {
+        match predicate_func_value {
+            PredicateFuncValue::Equal {
+                space0, operator, ..
             }
+            | PredicateFuncValue::NotEqual {
+                space0, operator, ..
+            }
+            | PredicateFuncValue::GreaterThan {
+                space0, operator, ..
+            }
+            | PredicateFuncValue::GreaterThanOrEqual {
+                space0, operator, ..
+            }
+            | PredicateFuncValue::LessThan {
+                space0, operator, ..
+            }
+            | PredicateFuncValue::LessThanOrEqual {
+                space0, operator, ..
+            } => {
+                *space0 = one_whitespace();
+                *operator = true;
+            }
+            PredicateFuncValue::StartWith { space0, .. }
+            | PredicateFuncValue::EndWith { space0, .. }
+            | PredicateFuncValue::Contain { space0, .. }
+            | PredicateFuncValue::Include { space0, .. }
+            | PredicateFuncValue::Match { space0, .. } => *space0 = one_whitespace(),
+            _ => {}
         }
-        PredicateFuncValue::LessThan { value, .. } => PredicateFuncValue::LessThan {
-            space0: one_whitespace(),
-            value: lint_predicate_value(value),
-            operator: true,
-        },
-        PredicateFuncValue::LessThanOrEqual { value, .. } => PredicateFuncValue::LessThanOrEqual {
-            space0: one_whitespace(),
-            value: lint_predicate_value(value),
-            operator: true,
-        },
-        PredicateFuncValue::Contain { value, .. } => PredicateFuncValue::Contain {
-            space0: one_whitespace(),
-            value: lint_predicate_value(value),
-        },
-
-        PredicateFuncValue::Include { value, .. } => PredicateFuncValue::Include {
-            space0: one_whitespace(),
-            value: lint_predicate_value(value),
-        },
-
-        PredicateFuncValue::Match { value, .. } => PredicateFuncValue::Match {
-            space0: one_whitespace(),
-            value: lint_predicate_value(value),
-        },
-        PredicateFuncValue::StartWith { value, .. } => PredicateFuncValue::StartWith {
-            space0: one_whitespace(),
-            value: lint_predicate_value(value),
-        },
-        PredicateFuncValue::EndWith { value, .. } => PredicateFuncValue::EndWith {
-            space0: one_whitespace(),
-            value: lint_predicate_value(value),
-        },
-        PredicateFuncValue::IsInteger => PredicateFuncValue::IsInteger,
-        PredicateFuncValue::IsFloat => PredicateFuncValue::IsFloat,
-        PredicateFuncValue::IsBoolean => PredicateFuncValue::IsBoolean,
-        PredicateFuncValue::IsString => PredicateFuncValue::IsString,
-        PredicateFuncValue::IsCollection => PredicateFuncValue::IsCollection,
-        PredicateFuncValue::IsDate => PredicateFuncValue::IsDate,
-        PredicateFuncValue::Exist => PredicateFuncValue::Exist,
-        PredicateFuncValue::IsEmpty => PredicateFuncValue::IsEmpty,
-    }
-}
-
-fn lint_predicate_value(predicate_value: &PredicateValue) -> PredicateValue {
-    match predicate_value {
-        PredicateValue::String(value) => PredicateValue::String(lint_template(value)),
-        PredicateValue::MultilineString(value) => {
-            PredicateValue::MultilineString(lint_multiline_string(value))
-        }
-        PredicateValue::Integer(value) => PredicateValue::Integer(*value),
-        PredicateValue::Float(value) => PredicateValue::Float(value.clone()),
-        PredicateValue::Bool(value) => PredicateValue::Bool(*value),
-        PredicateValue::Null => PredicateValue::Null,
-        PredicateValue::Hex(value) => PredicateValue::Hex(lint_hex(value)),
-        PredicateValue::Base64(value) => PredicateValue::Base64(lint_base64(value)),
-        PredicateValue::Expression(value) => PredicateValue::Expression(value.clone()),
-        PredicateValue::Regex(value) => PredicateValue::Regex(value.clone()),
+        visit_mut::walk_predicate_func_value(self, predicate_func_value);
     }
-}
 
-fn lint_multiline_string(multiline_string: &MultilineString) -> MultilineString {
-    match multiline_string {
-        MultilineString::OneLineText(value) => MultilineString::OneLineText(lint_template(value)),
-        MultilineString::Text(value) => MultilineString::Text(lint_text(value)),
-        MultilineString::Json(value) => MultilineString::Json(lint_text(value)),
-        MultilineString::Xml(value) => MultilineString::Xml(lint_text(value)),
-        MultilineString::GraphQl(value) => MultilineString::GraphQl(lint_graphql(value)),
+    fn visit_body(&mut self, body: &mut Body) {
+        body.space0 = empty_whitespace();
+        visit_mut::walk_body(self, body);
     }
-}
 
-fn lint_text(text: &Text) -> Text {
-    let space = empty_whitespace();
-    let newline = text.newline.clone();
-    let value = lint_template(&text.value);
-    Text {
-        space,
-        newline,
-        value,
+    fn visit_text(&mut self, text: &mut Text) {
+        text.space = empty_whitespace();
+        visit_mut::walk_text(self, text);
     }
-}
 
-fn lint_graphql(graphql: &GraphQl) -> GraphQl {
-    let space = empty_whitespace();
-    let newline = graphql.newline.clone();
-    let value = lint_template(&graphql.value);
-    let variables = graphql.variables.clone();
-    GraphQl {
-        space,
-        newline,
-        value,
-        variables,
+    fn visit_graphql(&mut self, graphql: &mut GraphQl) {
+        graphql.space = empty_whitespace();
+        visit_mut::walk_graphql(self, graphql);
     }
-}
-
-fn lint_cookie(cookie: &Cookie) -> Cookie {
-    cookie.clone()
-}
 
-fn lint_body(body: &Body) -> Body {
-    let line_terminators = body.line_terminators.clone();
-    let space0 = empty_whitespace();
-    let value = lint_bytes(&body.value);
-    let line_terminator0 = body.line_terminator0.clone();
-    Body {
-        line_terminators,
-        space0,
-        value,
-        line_terminator0,
+    fn visit_base64(&mut self, base64: &mut Base64) {
+        base64.space0 = empty_whitespace();
+        base64.space1 = empty_whitespace();
     }
-}
 
-fn lint_bytes(bytes: &Bytes) -> Bytes {
-    match bytes {
-        Bytes::File(value) => Bytes::File(lint_file(value)),
-        Bytes::Base64(value) => Bytes::Base64(lint_base64(value)),
-        Bytes::Hex(value) => Bytes::Hex(lint_hex(value)),
-        Bytes::Json(value) => Bytes::Json(value.clone()),
-        Bytes::OnelineString(value) => Bytes::OnelineString(lint_template(value)),
-        Bytes::MultilineString(value) => Bytes::MultilineString(lint_multiline_string(value)),
-        Bytes::Xml(value) => Bytes::Xml(value.clone()),
+    fn visit_hex(&mut self, hex: &mut Hex) {
+        hex.space0 = empty_whitespace();
+        hex.space1 = empty_whitespace();
     }
-}
 
-fn lint_base64(base64: &Base64) -> Base64 {
-    Base64 {
-        space0: empty_whitespace(),
-        value: base64.value.clone(),
-        encoded: base64.encoded.clone(),
-        space1: empty_whitespace(),
+    fn visit_file(&mut self, file: &mut File) {
+        file.space0 = empty_whitespace();
+        file.space1 = empty_whitespace();
+        visit_mut::walk_file(self, file);
     }
-}
 
-fn lint_hex(hex: &Hex) -> Hex {
-    Hex {
-        space0: empty_whitespace(),
-        value: hex.value.clone(),
-        encoded: hex.encoded.clone(),
-        space1: empty_whitespace(),
-    }
-}
-
-fn lint_file(file: &File) -> File {
-    File {
-        space0: empty_whitespace(),
-        filename: Filename {
-            value: file.filename.value.clone(),
-            source_info: SourceInfo::new(0, 0, 0, 0),
-        },
-        space1: empty_whitespace(),
-    }
-}
-
-fn lint_key_value(key_value: &KeyValue) -> KeyValue {
-    KeyValue {
-        line_terminators: key_value.line_terminators.clone(),
-        space0: empty_whitespace(),
-        key: key_value.key.clone(),
-        space1: empty_whitespace(),
-        space2: if key_value.value.elements.is_empty() {
-            empty_whitespace()
-        } else {
-            one_whitespace()
-        },
-        value: key_value.value.clone(),
-        line_terminator0: key_value.line_terminator0.clone(),
+    fn visit_filter_value(&mut self, filter_value: &mut FilterValue) {
+        if let FilterValue::Regex { space0, .. } = filter_value {
+            *space0 = one_whitespace();
+        }
+        visit_mut::walk_filter_value(self, filter_value);
     }
 }
 
-fn lint_multipart_param(multipart_param: &MultipartParam) -> MultipartParam {
-    match multipart_param {
-        MultipartParam::Param(param) => MultipartParam::Param(lint_key_value(param)),
-        MultipartParam::FileParam(file_param) => {
-            MultipartParam::FileParam(lint_file_param(file_param))
-        }
+fn section_value_index(section_value: &SectionValue) -> u32 {
+    match section_value {
+        // Request sections
+        SectionValue::QueryParams(_) => 0,
+        SectionValue::BasicAuth(_) => 1,
+        SectionValue::FormParams(_) => 2,
+        SectionValue::MultipartFormData(_) => 3,
+        SectionValue::Cookies(_) => 4,
+        SectionValue::Options(_) => 5,
+        // Response sections
+        SectionValue::Captures(_) => 0,
+        SectionValue::Asserts(_) => 1,
     }
 }
 
-fn lint_file_param(file_param: &FileParam) -> FileParam {
-    let line_terminators = file_param.line_terminators.clone();
-    let space0 = file_param.space0.clone();
-    let key = file_param.key.clone();
-    let space1 = file_param.space1.clone();
-    let space2 = file_param.space2.clone();
-    let value = file_param.value.clone();
-    let line_terminator0 = file_param.line_terminator0.clone();
-    FileParam {
-        line_terminators,
-        space0,
-        key,
-        space1,
-        space2,
-        value,
-        line_terminator0,
+fn lint_cookie_attribute_name(cookie_attribute_name: &CookieAttributeName) -> CookieAttributeName {
+    match cookie_attribute_name {
+        CookieAttributeName::Value(_) => CookieAttributeName::Value("Value".to_string()),
+        CookieAttributeName::Expires(_) => CookieAttributeName::Expires("Expires".to_string()),
+        CookieAttributeName::MaxAge(_) => CookieAttributeName::MaxAge("Max-Age".to_string()),
+        CookieAttributeName::Domain(_) => CookieAttributeName::Domain("Domain".to_string()),
+        CookieAttributeName::Path(_) => CookieAttributeName::Path("Path".to_string()),
+        CookieAttributeName::Secure(_) => CookieAttributeName::Secure("Secure".to_string()),
+        CookieAttributeName::HttpOnly(_) => CookieAttributeName::HttpOnly("HttpOnly".to_string()),
+        CookieAttributeName::SameSite(_) => CookieAttributeName::SameSite("SameSite".to_string()),
     }
 }
 
@@ -568,78 +349,17 @@ fn one_whitespace() -> Whitespace {
     }
 }
 
-fn check_line_terminator(line_terminator: &LineTerminator) -> Vec<Error> {
-    let mut errors = vec![];
-    match &line_terminator.comment {
-        Some(_) => {}
-        None => {
-            if !line_terminator.space0.value.is_empty() {
-                errors.push(Error {
-                    source_info: line_terminator.space0.source_info.clone(),
-                    inner: LinterError::UnnecessarySpace,
-                });
+fn lint_line_terminator(line_terminator: &mut LineTerminator) {
+    match &mut line_terminator.comment {
+        None => line_terminator.space0 = empty_whitespace(),
+        Some(comment) => {
+            if !comment.value.starts_with(' ') {
+                comment.value = format!(" {}", comment.value);
             }
         }
     }
-    errors
-}
-
-fn lint_line_terminator(line_terminator: &LineTerminator) -> LineTerminator {
-    let space0 = match line_terminator.comment {
-        None => empty_whitespace(),
-        Some(_) => Whitespace {
-            value: line_terminator.clone().space0.value,
-            source_info: SourceInfo::new(0, 0, 0, 0),
-        },
-    };
-    let comment = line_terminator.comment.as_ref().map(lint_comment);
-    let newline = Whitespace {
-        value: if line_terminator.newline.value.is_empty() {
-            String::new()
-        } else {
-            "\n".to_string()
-        },
-        source_info: SourceInfo::new(0, 0, 0, 0),
-    };
-    LineTerminator {
-        space0,
-        comment,
-        newline,
-    }
-}
-
-fn lint_comment(comment: &Comment) -> Comment {
-    Comment {
-        value: if comment.value.starts_with(' ') {
-            comment.value.clone()
-        } else {
-            format!(" {}", comment.value)
-        },
-    }
-}
-
-fn lint_template(template: &Template) -> Template {
-    template.clone()
-}
-
-fn lint_entry_option(entry_option: &EntryOption) -> EntryOption {
-    entry_option.clone()
-}
-
-fn lint_filter(filter: &Filter) -> Filter {
-    Filter {
-        source_info: SourceInfo::new(0, 0, 0, 0),
-        value: lint_filter_value(&filter.value),
-    }
-}
-
-fn lint_filter_value(filter_value: &FilterValue) -> FilterValue {
-    match filter_value {
-        FilterValue::Regex { value, .. } => FilterValue::Regex {
-            space0: one_whitespace(),
-            value: lint_regex_value(value),
-        },
-        f => f.clone(),
+    if !line_terminator.newline.value.is_empty() {
+        line_terminator.newline.value = "\n".to_string();
     }
 }
 
@@ -674,4 +394,57 @@ mod tests {
         assert_eq!(check_hurl_file(&entry), vec![]);
         assert_eq!(lint_hurl_file(&entry), entry_linted);
     }
+
+    #[test]
+    fn test_lint_request() {
+        let hurl_file = hurl_core::parser::parse_hurl_file(
+            "  GET   http://localhost  \nHTTP 200\n[Asserts]\nbody == \"a\"\n",
+        )
+        .unwrap();
+        let errors = check_hurl_file(&hurl_file);
+        assert_eq!(
+            errors.iter().map(|e| e.inner.clone()).collect::<Vec<_>>(),
+            vec![
+                LinterError::UnnecessarySpace,
+                LinterError::OneSpace,
+                LinterError::UnnecessarySpace,
+            ]
+        );
+
+        let hurl_file = lint_hurl_file(&hurl_file);
+        let request = &hurl_file.entries[0].request;
+        assert_eq!(request.space0.value, "");
+        assert_eq!(request.space1.value, " ");
+        assert_eq!(request.line_terminator0.space0.value, "");
+        assert!(check_hurl_file(&hurl_file).is_empty());
+    }
+
+    #[test]
+    fn test_check_with_config() {
+        let hurl_file = hurl_core::parser::parse_hurl_file(
+            r#"GET http://localhost/{{a}}
+HTTP 200
+[Captures]
+id: body # hurlfmt: ignore unused-capture
+
+# hurlfmt: ignore
+GET  http://localhost/{{b}}
+
+GET http://localhost
+"#,
+        )
+        .unwrap();
+        let rules = |config: &Config| {
+            check_hurl_file_with_config(&hurl_file, config)
+                .iter()
+                .map(|e| (e.source_info.start.line, e.inner.rule()))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            rules(&Config::default()),
+            vec![(1, "undefined-variable"), (9, "no-assert")]
+        );
+        let config = Config::parse("[rules]\nno-assert = \"off\"\n").unwrap();
+        assert_eq!(rules(&config), vec![(1, "undefined-variable")]);
+    }
 }
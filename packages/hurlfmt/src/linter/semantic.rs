@@ -0,0 +1,369 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2023 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+//! Semantic rules, checking how entries, variables and options relate to each other (as opposed
+//! to the layout rules of the `rules` module).
+use std::collections::{HashMap, HashSet};
+
+use hurl_core::ast::visit::{self, Visitor};
+use hurl_core::ast::*;
+
+use crate::linter::core::{Error, LinterError};
+
+/// Options that select an HTTP version: only one of them can be enabled per entry.
+const HTTP_VERSION_OPTIONS: [&str; 5] = [
+    "http1.0",
+    "http1.1",
+    "http2",
+    "http2-prior-knowledge",
+    "http3",
+];
+
+/// Returns semantic lint errors for the `hurl_file`.
+pub fn check_hurl_file(hurl_file: &HurlFile) -> Vec<Error> {
+    let mut checker = Checker::default();
+    checker.visit_hurl_file(hurl_file);
+    let mut unused = checker
+        .captures
+        .into_iter()
+        .filter(|(_, capture)| !capture.used)
+        .map(|(name, capture)| Error {
+            source_info: capture.source_info,
+            inner: LinterError::UnusedCapture(name),
+        })
+        .collect::<Vec<_>>();
+    checker.errors.append(&mut unused);
+    checker.errors
+}
+
+/// A captured variable, and whether its value has been used since it has been captured.
+struct CaptureState {
+    source_info: SourceInfo,
+    used: bool,
+}
+
+/// Tracks variables in the order they're defined and used, walking the file in source order.
+#[derive(Default)]
+struct Checker {
+    errors: Vec<Error>,
+    captures: HashMap<String, CaptureState>,
+    defined: HashSet<String>,
+    undefined: HashSet<String>,
+    /// Content type of the current response, if it's not JSON.
+    non_json_content_type: Option<String>,
+}
+
+impl Checker {
+    fn use_variable(&mut self, name: &str, source_info: &SourceInfo) {
+        if let Some(capture) = self.captures.get_mut(name) {
+            capture.used = true;
+        } else if !self.defined.contains(name) && self.undefined.insert(name.to_string()) {
+            self.errors.push(Error {
+                source_info: source_info.clone(),
+                inner: LinterError::UndefinedVariable(name.to_string()),
+            });
+        }
+    }
+
+    fn check_options(&mut self, options: &[EntryOption]) {
+        let enabled = |name: &str| {
+            options.iter().any(|option| match option.kind {
+                OptionKind::FollowLocation(value) => name == "location" && value,
+                OptionKind::Retry(value) => name == "retry" && value != Retry::None,
+                _ => false,
+            })
+        };
+        let mut http_version: Option<&str> = None;
+        for option in options {
+            let required = match option.kind {
+                OptionKind::MaxRedirect(_) => Some("location"),
+                OptionKind::RetryInterval(_) => Some("retry"),
+                _ => None,
+            };
+            if let Some(required) = required {
+                if !enabled(required) {
+                    self.errors.push(Error {
+                        source_info: option_name_source_info(option),
+                        inner: LinterError::OptionWithoutEffect {
+                            name: option.kind.name().to_string(),
+                            required: required.to_string(),
+                        },
+                    });
+                }
+            }
+
+            let name = option.kind.name();
+            if HTTP_VERSION_OPTIONS.contains(&name) && option.kind.value_as_str() == "true" {
+                match http_version {
+                    Some(other) if other != name => self.errors.push(Error {
+                        source_info: option_name_source_info(option),
+                        inner: LinterError::ConflictingOptions {
+                            name: name.to_string(),
+                            other: other.to_string(),
+                        },
+                    }),
+                    _ => http_version = Some(name),
+                }
+            }
+        }
+    }
+}
+
+impl Visitor for Checker {
+    fn visit_entry(&mut self, entry: &Entry) {
+        if !has_assert(entry.response.as_ref()) {
+            let request = &entry.request;
+            self.errors.push(Error {
+                source_info: SourceInfo {
+                    start: request.space0.source_info.end.clone(),
+                    end: request.url.source_info.end.clone(),
+                },
+                inner: LinterError::NoAssert,
+            });
+        }
+        visit::walk_entry(self, entry);
+    }
+
+    fn visit_request(&mut self, request: &Request) {
+        // Variables options are evaluated before the request is built.
+        let options = request.options();
+        for option in &options {
+            if let OptionKind::Variable(VariableDefinition { name, .. }) = &option.kind {
+                self.defined.insert(name.clone());
+            }
+        }
+        self.check_options(&options);
+        visit::walk_request(self, request);
+    }
+
+    fn visit_response(&mut self, response: &Response) {
+        self.non_json_content_type = content_type(response).filter(|c| !c.contains("json"));
+        visit::walk_response(self, response);
+        self.non_json_content_type = None;
+    }
+
+    fn visit_capture(&mut self, capture: &Capture) {
+        visit::walk_capture(self, capture);
+        let name = &capture.name.value;
+        if matches!(self.captures.get(name), Some(previous) if !previous.used) {
+            self.errors.push(Error {
+                source_info: capture.name.source_info.clone(),
+                inner: LinterError::DuplicateCapture(name.clone()),
+            });
+        }
+        self.defined.insert(name.clone());
+        self.captures.insert(
+            name.clone(),
+            CaptureState {
+                source_info: capture.name.source_info.clone(),
+                used: false,
+            },
+        );
+    }
+
+    fn visit_query(&mut self, query: &Query) {
+        match &query.value {
+            QueryValue::Jsonpath { .. } => {
+                if let Some(content_type) = self.non_json_content_type.clone() {
+                    self.errors.push(Error {
+                        source_info: query.source_info.clone(),
+                        inner: LinterError::JsonpathOnNonJson(content_type),
+                    });
+                }
+            }
+            QueryValue::Variable { name, .. } => {
+                if let [TemplateElement::String { value, .. }] = name.elements.as_slice() {
+                    self.use_variable(value, &name.source_info);
+                }
+            }
+            _ => {}
+        }
+        visit::walk_query(self, query);
+    }
+
+    fn visit_variable(&mut self, variable: &Variable) {
+        self.use_variable(&variable.name, &variable.source_info);
+    }
+}
+
+/// Returns true if this `response` asserts anything: a specific status, headers, a body or
+/// explicit asserts.
+fn has_assert(response: Option<&Response>) -> bool {
+    match response {
+        None => false,
+        Some(response) => {
+            response.status.value != StatusValue::Any
+                || !response.headers.is_empty()
+                || response.body.is_some()
+                || !response.asserts().is_empty()
+        }
+    }
+}
+
+/// Returns the `Content-Type` expected by this `response`, either from its implicit headers or
+/// from an explicit `header "Content-Type"` assert.
+fn content_type(response: &Response) -> Option<String> {
+    let header = response
+        .headers
+        .iter()
+        .find(|h| h.key.value.eq_ignore_ascii_case("Content-Type"))
+        .map(|h| h.value.to_string());
+    if header.is_some() {
+        return header;
+    }
+    response.asserts().iter().find_map(|assert| {
+        let QueryValue::Header { name, .. } = &assert.query.value else {
+            return None;
+        };
+        if !assert.filters.is_empty()
+            || assert.predicate.not
+            || !name.to_string().eq_ignore_ascii_case("Content-Type")
+        {
+            return None;
+        }
+        match &assert.predicate.predicate_func.value {
+            PredicateFuncValue::Equal {
+                value: PredicateValue::String(value),
+                ..
+            }
+            | PredicateFuncValue::StartWith {
+                value: PredicateValue::String(value),
+                ..
+            } => Some(value.to_string()),
+            _ => None,
+        }
+    })
+}
+
+/// Returns the source info of the name of this `option`.
+fn option_name_source_info(option: &EntryOption) -> SourceInfo {
+    SourceInfo {
+        start: option.space0.source_info.end.clone(),
+        end: option.space1.source_info.start.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::parser;
+
+    use super::*;
+
+    fn errors(content: &str) -> Vec<(usize, LinterError)> {
+        let hurl_file = parser::parse_hurl_file(content).unwrap();
+        let mut errors = check_hurl_file(&hurl_file)
+            .into_iter()
+            .map(|e| (e.source_info.start.line, e.inner))
+            .collect::<Vec<_>>();
+        errors.sort_by_key(|(line, _)| *line);
+        errors
+    }
+
+    #[test]
+    fn test_variables() {
+        assert_eq!(
+            errors(
+                r#"GET http://localhost/{{id}}
+[Options]
+variable: id=1
+HTTP 200
+[Captures]
+token: header "token"
+csrf: header "csrf"
+
+GET http://localhost/{{user}}?token={{token}}
+HTTP 200
+[Captures]
+csrf: header "csrf"
+[Asserts]
+variable "csrf" exists
+"#
+            ),
+            vec![
+                (9, LinterError::UndefinedVariable("user".to_string())),
+                (12, LinterError::DuplicateCapture("csrf".to_string())),
+            ]
+        );
+        assert_eq!(
+            errors("GET http://localhost\nHTTP 200\n[Captures]\nid: body\n"),
+            vec![(4, LinterError::UnusedCapture("id".to_string()))]
+        );
+    }
+
+    #[test]
+    fn test_no_assert() {
+        assert_eq!(
+            errors("GET http://localhost\n\nGET http://localhost\nHTTP *\n\nGET http://localhost\nHTTP 200\n"),
+            vec![(1, LinterError::NoAssert), (3, LinterError::NoAssert)]
+        );
+    }
+
+    #[test]
+    fn test_jsonpath_on_non_json() {
+        assert_eq!(
+            errors(
+                r#"GET http://localhost
+HTTP 200
+Content-Type: text/html
+[Asserts]
+jsonpath "$.id" == 1
+
+GET http://localhost
+HTTP 200
+[Asserts]
+header "Content-Type" == "application/json"
+jsonpath "$.id" == 1
+"#
+            ),
+            vec![(5, LinterError::JsonpathOnNonJson("text/html".to_string()))]
+        );
+    }
+
+    #[test]
+    fn test_options() {
+        assert_eq!(
+            errors(
+                r#"GET http://localhost
+[Options]
+max-redirs: 5
+retry: 2
+retry-interval: 100
+http1.1: true
+http2: true
+HTTP 200
+"#
+            ),
+            vec![
+                (
+                    3,
+                    LinterError::OptionWithoutEffect {
+                        name: "max-redirs".to_string(),
+                        required: "location".to_string(),
+                    }
+                ),
+                (
+                    7,
+                    LinterError::ConflictingOptions {
+                        name: "http2".to_string(),
+                        other: "http1.1".to_string(),
+                    }
+                ),
+            ]
+        );
+    }
+}
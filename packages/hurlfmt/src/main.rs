@@ -19,9 +19,13 @@ use std::io::{self, Write};
 use std::path::{Path, PathBuf};
 use std::process;
 
+use hurl_core::cst::Cst;
+use hurl_core::format::HtmlOptions;
 use hurl_core::parser;
-use hurlfmt::cli::options::{InputFormat, OptionsError, OutputFormat};
-use hurlfmt::{cli, curl, format, linter};
+use hurlfmt::cli::options::{CheckFormat, InputFormat, Options, OptionsError, OutputFormat};
+use hurlfmt::cli::{FormatReport, LinterReport};
+use hurlfmt::linter::{Config, Severity};
+use hurlfmt::{cli, curl, format, har, http, json, linter, openapi, postman, refactor};
 
 #[cfg(target_family = "unix")]
 pub fn init_colored() {
@@ -51,7 +55,21 @@ fn main() {
     init_colored();
 
     let log_error_message = cli::make_logger_error_message(opts.color);
+    let config = if opts.check {
+        match linter_config(opts.config_file.as_deref()) {
+            Ok(config) => config,
+            Err(message) => {
+                log_error_message(false, &message);
+                process::exit(2);
+            }
+        }
+    } else {
+        Config::default()
+    };
+    let mut reports = vec![];
+    let mut format_reports = vec![];
     let mut output_all = String::new();
+    let mut variables = vec![];
     for input_file in &opts.input_files {
         match cli::read_to_string(input_file) {
             Ok(contents) => {
@@ -65,8 +83,59 @@ fn main() {
                             process::exit(2);
                         }
                     },
+                    InputFormat::Har => match har::parse(&contents) {
+                        Ok(s) => s,
+                        Err(e) => {
+                            eprintln!("{}", e);
+                            process::exit(2);
+                        }
+                    },
+                    InputFormat::Http => match http::parse(&contents) {
+                        Ok(s) => s,
+                        Err(e) => {
+                            eprintln!("{}", e);
+                            process::exit(2);
+                        }
+                    },
+                    InputFormat::Json => match json::parse(&contents) {
+                        Ok(s) => s,
+                        Err(e) => {
+                            eprintln!("{}", e);
+                            process::exit(2);
+                        }
+                    },
+                    InputFormat::OpenApi => match openapi::parse(&contents) {
+                        Ok(s) => s,
+                        Err(e) => {
+                            eprintln!("{}", e);
+                            process::exit(2);
+                        }
+                    },
+                    InputFormat::Postman => match postman::parse(&contents) {
+                        Ok(import) => {
+                            for warning in &import.warnings {
+                                log_error_message(true, warning);
+                            }
+                            variables.extend(import.variables);
+                            // Environments have only variables.
+                            if import.content.is_empty() {
+                                continue;
+                            }
+                            import.content
+                        }
+                        Err(e) => {
+                            eprintln!("{}", e);
+                            process::exit(2);
+                        }
+                    },
                 };
                 let input_path = Path::new(input_file).to_path_buf();
+                // Refactorings are rejected with --check: files are checked as they are.
+                let input = if opts.check {
+                    input
+                } else {
+                    refactor_input(input, &input_path, &opts, &log_error_message)
+                };
                 let lines: Vec<&str> = regex::Regex::new(r"\n|\r\n")
                     .unwrap()
                     .split(&input)
@@ -80,26 +149,71 @@ fn main() {
                 let log_linter_error =
                     cli::make_logger_linter_error(lines, opts.color, Some(input_path));
 
-                match parser::parse_hurl_file(&input) {
-                    Err(e) => {
-                        log_parser_error(&e, false);
+                match parser::parse_hurl_file_with_recovery(&input) {
+                    (_, errors) if !errors.is_empty() => {
+                        for e in &errors {
+                            log_parser_error(e, false);
+                        }
                         process::exit(2);
                     }
-                    Ok(hurl_file) => {
+                    (hurl_file, _) => {
                         if opts.check {
-                            for e in linter::check_hurl_file(&hurl_file).iter() {
-                                log_linter_error(e, true);
+                            for error in linter::check_hurl_file_with_config(&hurl_file, &config) {
+                                let severity = config.severity(&error.inner);
+                                if opts.check_format == CheckFormat::Text {
+                                    log_linter_error(&error, severity == Severity::Warning);
+                                }
+                                reports.push(LinterReport {
+                                    filename: input_file.to_string(),
+                                    error,
+                                    severity,
+                                });
+                            }
+                            let formatted =
+                                format::format_text(linter::lint_hurl_file(&hurl_file), false);
+                            let color = opts.color && opts.check_format == CheckFormat::Text;
+                            if let Some(diff) =
+                                cli::unified_diff(input_file, &input, &formatted, color)
+                            {
+                                if opts.check_format == CheckFormat::Text {
+                                    print!("{diff}");
+                                }
+                                let line = input
+                                    .lines()
+                                    .zip(formatted.lines())
+                                    .take_while(|(old, new)| old == new)
+                                    .count()
+                                    + 1;
+                                format_reports.push(FormatReport {
+                                    filename: input_file.to_string(),
+                                    line,
+                                    diff,
+                                });
                             }
-                            process::exit(1);
                         } else {
                             let output = match opts.output_format {
                                 OutputFormat::Hurl => {
-                                    let hurl_file = linter::lint_hurl_file(&hurl_file);
+                                    let mut hurl_file = linter::lint_hurl_file(&hurl_file);
+                                    if opts.format_bodies {
+                                        hurl_file =
+                                            format::format_bodies(&hurl_file, opts.sort_json_keys);
+                                    }
                                     format::format_text(hurl_file, opts.color)
                                 }
+                                OutputFormat::Curl => format::format_curl(&hurl_file),
                                 OutputFormat::Json => format::format_json(&hurl_file),
                                 OutputFormat::Html => {
-                                    hurl_core::format::format_html(&hurl_file, opts.standalone)
+                                    let options = HtmlOptions {
+                                        standalone: opts.standalone,
+                                        line_numbers: opts.line_numbers,
+                                        links: opts.links,
+                                        theme: opts.theme,
+                                    };
+                                    hurl_core::format::format_html(&hurl_file, &options)
+                                }
+                                OutputFormat::Http => format::format_http(&hurl_file),
+                                OutputFormat::Markdown => {
+                                    hurl_core::format::format_markdown(&hurl_file)
                                 }
                             };
                             if opts.in_place {
@@ -121,9 +235,121 @@ fn main() {
             }
         }
     }
+    if opts.check {
+        if opts.check_format == CheckFormat::Json {
+            let json = cli::format_linter_reports_json(&reports, &format_reports);
+            write_output(&json, None);
+        }
+        // Warnings are reported but don't fail the check, only errors and formatting do.
+        let has_errors = reports.iter().any(|r| r.severity == Severity::Error);
+        let success = !has_errors && format_reports.is_empty();
+        process::exit(if success { 0 } else { 1 });
+    }
     if !opts.in_place {
         write_output(&output_all, opts.output_file);
     }
+    if let Some(variables_file) = opts.variables_file {
+        let content = variables
+            .iter()
+            .map(|(name, value)| format!("{name}={value}\n"))
+            .collect::<String>();
+        write_output(&content, Some(variables_file));
+    }
+    if let Some(css_file) = opts.css_file {
+        write_output(&hurl_core::format::hurl_css(opts.theme), Some(css_file));
+    }
+}
+
+/// Applies the refactorings of the options `opts` to the Hurl `input` of the file `input_path`.
+///
+/// The input is returned unchanged if it can't be parsed: parsing errors are reported later.
+fn refactor_input(
+    input: String,
+    input_path: &Path,
+    opts: &Options,
+    log_error_message: &impl Fn(bool, &str),
+) -> String {
+    let mut input = input;
+    let dir = input_path.parent().unwrap_or(Path::new(""));
+    for (old, new) in &opts.rename_variables {
+        let Ok(cst) = Cst::parse(&input) else {
+            return input;
+        };
+        input = refactor::rename_variable(&cst, old, new);
+    }
+    for (old, new) in &opts.replace_url_prefixes {
+        let Ok(cst) = Cst::parse(&input) else {
+            return input;
+        };
+        input = refactor::replace_url_prefix(&cst, old, new);
+    }
+    if opts.extract_json_bodies {
+        let Ok(cst) = Cst::parse(&input) else {
+            return input;
+        };
+        let stem = input_path
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let (text, files, warnings) = refactor::extract_json_bodies(&cst, &stem);
+        for warning in &warnings {
+            log_error_message(true, warning);
+        }
+        // Existing files are never overwritten: nothing is written if one of them exists.
+        for file in &files {
+            let path = dir.join(&file.filename);
+            if path.exists() {
+                let message = format!("File {} already exists", path.display());
+                log_error_message(false, &message);
+                process::exit(1);
+            }
+        }
+        // Bodies are written as they are: a trailing newline would be sent with the request.
+        for file in files {
+            let path = dir.join(file.filename);
+            if let Err(why) = std::fs::write(&path, file.content) {
+                eprintln!("Issue writing to {}: {:?}", path.display(), why);
+                process::exit(1);
+            }
+        }
+        input = text;
+    }
+    if opts.inline_json_bodies {
+        let Ok(cst) = Cst::parse(&input) else {
+            return input;
+        };
+        let read_file =
+            |filename: &str| std::fs::read_to_string(dir.join(filename)).map_err(|e| e.to_string());
+        let (text, warnings) = refactor::inline_json_bodies(&cst, read_file);
+        for warning in &warnings {
+            log_error_message(true, warning);
+        }
+        input = text;
+    }
+    input
+}
+
+/// Returns the linter configuration, read from `config_file` or from the `.hurlfmt.toml` file of
+/// the current directory (or of one of its ancestors).
+fn linter_config(config_file: Option<&Path>) -> Result<Config, String> {
+    let config_file = match config_file {
+        Some(config_file) => config_file.to_path_buf(),
+        None => {
+            let dir = std::env::current_dir().map_err(|e| e.to_string())?;
+            match linter::config::find(&dir) {
+                Some(config_file) => config_file,
+                None => return Ok(Config::default()),
+            }
+        }
+    };
+    let content = std::fs::read_to_string(&config_file).map_err(|e| {
+        format!(
+            "Config file {} can not be read - {e}",
+            config_file.display()
+        )
+    })?;
+    Config::parse(&content)
+        .map_err(|e| format!("Invalid config file {} - {e}", config_file.display()))
 }
 
 fn write_output(content: &str, filename: Option<PathBuf>) {
@@ -0,0 +1,663 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2023 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+//! Import of [OpenAPI](https://spec.openapis.org/oas/latest.html) 3.0/3.1 specifications.
+//!
+//! Each operation of the specification is converted to a Hurl entry, with path parameters as
+//! variables, an example request body and baseline asserts on the response. The generated file
+//! is meant to bootstrap tests of a service.
+//!
+//! The entries are built as a Hurl AST, which is then formatted as text.
+use hurl_core::ast::*;
+use serde_yaml::{Mapping, Value};
+
+use crate::builder::{self, push_section, whitespace};
+use crate::escape::{escape_value, scalar_to_string, variable_name};
+use crate::format;
+
+const METHODS: [&str; 8] = [
+    "get", "put", "post", "delete", "options", "head", "patch", "trace",
+];
+
+/// Maximum depth of nested schemas used to build examples (schemas can be recursive).
+const MAX_DEPTH: usize = 8;
+
+/// Converts an OpenAPI specification `s` (YAML or JSON) to a Hurl file content.
+pub fn parse(s: &str) -> Result<String, String> {
+    let doc: Value =
+        serde_yaml::from_str(s).map_err(|e| format!("Can not parse OpenAPI file: {e}"))?;
+    match doc.get("openapi").and_then(Value::as_str) {
+        Some(version) if version.starts_with("3.") => {}
+        Some(version) => {
+            return Err(format!(
+                "Can not parse OpenAPI file: version {version} is not supported"
+            ))
+        }
+        None => return Err("Can not parse OpenAPI file: missing openapi version".to_string()),
+    }
+    let base_url = base_url(&doc);
+    let paths = match doc.get("paths") {
+        Some(Value::Mapping(paths)) => paths,
+        _ => return Err("Can not parse OpenAPI file: missing paths".to_string()),
+    };
+
+    let mut entries = vec![];
+    for (path, path_item) in paths {
+        let path = match path.as_str() {
+            Some(path) => path,
+            None => continue,
+        };
+        let path_item = resolve(&doc, path_item);
+        for (method, operation) in path_item.as_mapping().into_iter().flatten() {
+            let method = match method.as_str() {
+                Some(m) if METHODS.contains(&m) => m,
+                _ => continue,
+            };
+            let entry = parse_operation(&doc, &base_url, path, path_item, method, operation)
+                .map_err(|message| {
+                    format!(
+                        "Can not parse OpenAPI operation {} {path}: {message}",
+                        method.to_uppercase()
+                    )
+                })?;
+            entries.push(entry);
+        }
+    }
+    Ok(format::format_text(builder::hurl_file(entries), false))
+}
+
+fn parse_operation(
+    doc: &Value,
+    base_url: &str,
+    path: &str,
+    path_item: &Value,
+    method: &str,
+    operation: &Value,
+) -> Result<Entry, String> {
+    let url = format!("{base_url}{}", template_path(path));
+    let url = builder::template(&url, str::to_string, None);
+    let mut request = builder::request(&method.to_uppercase(), url);
+    let title = operation
+        .get("summary")
+        .or_else(|| operation.get("operationId"))
+        .and_then(Value::as_str);
+    if let Some(title) = title {
+        let title = title.lines().next().unwrap_or_default();
+        request.line_terminators.push(builder::comment(title));
+    }
+
+    // Operation parameters override path item parameters with the same name and location.
+    let mut params: Vec<&Value> = vec![];
+    for param in parameters(doc, operation)
+        .into_iter()
+        .chain(parameters(doc, path_item))
+    {
+        let exists = params
+            .iter()
+            .any(|p| p.get("name") == param.get("name") && p.get("in") == param.get("in"));
+        if !exists {
+            params.push(param);
+        }
+    }
+    let mut query_params = vec![];
+    let mut cookies = vec![];
+    for param in params {
+        let required = param.get("required").and_then(Value::as_bool) == Some(true);
+        let name = match param.get("name").and_then(Value::as_str) {
+            Some(name) => name,
+            None => return Err("missing parameter name".to_string()),
+        };
+        if !required {
+            continue;
+        }
+        let value = param_value(doc, name, param);
+        match param.get("in").and_then(Value::as_str) {
+            Some("query") => query_params.push(builder::key_value(name, value)),
+            Some("header") => request.headers.push(builder::key_value(name, value)),
+            Some("cookie") => cookies.push(builder::cookie(name, value)),
+            _ => {}
+        }
+    }
+
+    let sections = &mut request.sections;
+    push_section(sections, SectionValue::QueryParams(query_params));
+    push_section(sections, SectionValue::Cookies(cookies));
+    if let Some(request_body) = operation.get("requestBody") {
+        let request_body = resolve(doc, request_body);
+        if let Some((content_type, media)) = first_media(request_body) {
+            match request_body_to_hurl(doc, content_type, media) {
+                RequestBody::Section(section) => push_section(sections, *section),
+                RequestBody::Raw(bytes) => {
+                    if !content_type.contains("json") {
+                        let value = builder::template(content_type, escape_value, None);
+                        let header = builder::key_value("Content-Type", value);
+                        request.headers.push(header);
+                    }
+                    request.body = Some(builder::body(*bytes));
+                }
+                RequestBody::None => {}
+            }
+        }
+    }
+    let response = response_to_hurl(doc, operation);
+    Ok(Entry {
+        request,
+        response: Some(response),
+    })
+}
+
+/// A request body converted to Hurl: either a section (form params, multipart) or a raw body.
+enum RequestBody {
+    Section(Box<SectionValue>),
+    Raw(Box<Bytes>),
+    None,
+}
+
+fn request_body_to_hurl(doc: &Value, content_type: &str, media: &Value) -> RequestBody {
+    let example = media_example(doc, media);
+    if content_type.starts_with("application/x-www-form-urlencoded")
+        || content_type.starts_with("multipart/form-data")
+    {
+        let multipart = content_type.starts_with("multipart");
+        let schema = media.get("schema").map(|schema| resolve(doc, schema));
+        let mut params = vec![];
+        for (name, value) in example
+            .as_ref()
+            .and_then(Value::as_mapping)
+            .into_iter()
+            .flatten()
+        {
+            let name = match name.as_str() {
+                Some(name) => name,
+                None => continue,
+            };
+            let property = schema
+                .and_then(|schema| schema.get("properties"))
+                .and_then(|p| p.get(name))
+                .map(|p| resolve(doc, p));
+            let is_file = property
+                .and_then(|p| p.get("format"))
+                .and_then(Value::as_str)
+                == Some("binary");
+            if multipart && is_file {
+                let filename = format!("{name}.bin");
+                params.push(builder::file_param(name, &filename, None));
+            } else {
+                let param = builder::key_value(name, scalar_to_hurl(value));
+                params.push(MultipartParam::Param(param));
+            }
+        }
+        if multipart {
+            return RequestBody::Section(Box::new(SectionValue::MultipartFormData(params)));
+        }
+        let params = params
+            .into_iter()
+            .filter_map(|param| match param {
+                MultipartParam::Param(param) => Some(param),
+                MultipartParam::FileParam(_) => None,
+            })
+            .collect();
+        return RequestBody::Section(Box::new(SectionValue::FormParams(params)));
+    }
+    match example {
+        None => RequestBody::None,
+        Some(example) if content_type.contains("json") => {
+            RequestBody::Raw(Box::new(Bytes::Json(builder::json(&example, 0))))
+        }
+        Some(Value::String(text)) if !text.contains("```") => {
+            let bytes = builder::multiline(&format!("{text}\n"), "");
+            RequestBody::Raw(Box::new(bytes))
+        }
+        Some(_) => RequestBody::None,
+    }
+}
+
+/// Returns the status line and the baseline asserts of an `operation`.
+fn response_to_hurl(doc: &Value, operation: &Value) -> Response {
+    let any_status = || builder::response(VersionValue::VersionAny, StatusValue::Any);
+    let responses = match operation.get("responses").and_then(Value::as_mapping) {
+        Some(responses) => responses,
+        None => return any_status(),
+    };
+    let mut success = responses
+        .iter()
+        .filter_map(|(code, response)| {
+            let code = match code {
+                Value::Number(n) => n.as_u64()?,
+                Value::String(s) => s.parse::<u64>().ok()?,
+                _ => return None,
+            };
+            (200..300).contains(&code).then_some((code, response))
+        })
+        .collect::<Vec<_>>();
+    success.sort_by_key(|(code, _)| *code);
+    let (code, response) = match success.first() {
+        Some((code, response)) => (*code, resolve(doc, response)),
+        None => return any_status(),
+    };
+    let mut hurl_response =
+        builder::response(VersionValue::VersionAny, StatusValue::Specific(code));
+    let content_type = first_media(response)
+        .map(|(content_type, _)| content_type)
+        .filter(|content_type| !content_type.contains('*'));
+    if let Some(content_type) = content_type {
+        let asserts = vec![content_type_assert(content_type)];
+        push_section(&mut hurl_response.sections, SectionValue::Asserts(asserts));
+    }
+    hurl_response
+}
+
+/// Returns the assert `header "Content-Type" contains "content_type"`.
+fn content_type_assert(content_type: &str) -> Assert {
+    let query = Query {
+        source_info: builder::source_info(),
+        value: QueryValue::Header {
+            space0: whitespace(" "),
+            name: builder::quoted("Content-Type"),
+        },
+    };
+    let predicate = Predicate {
+        not: false,
+        space0: whitespace(""),
+        predicate_func: PredicateFunc {
+            source_info: builder::source_info(),
+            value: PredicateFuncValue::Contain {
+                space0: whitespace(" "),
+                value: PredicateValue::String(builder::quoted(content_type)),
+            },
+        },
+    };
+    Assert {
+        line_terminators: vec![],
+        space0: whitespace(""),
+        query,
+        filters: vec![],
+        space1: whitespace(" "),
+        predicate,
+        line_terminator0: builder::newline(),
+    }
+}
+
+/// Returns the base url of the API, from the first server of `doc`.
+///
+/// Server variables are replaced by their default values, and a `{{base_url}}` variable is used
+/// when the servers are missing or relative.
+fn base_url(doc: &Value) -> String {
+    let server = match doc.get("servers").and_then(|s| s.get(0)) {
+        Some(server) => server,
+        None => return "{{base_url}}".to_string(),
+    };
+    let mut url = server
+        .get("url")
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_string();
+    for (name, variable) in server
+        .get("variables")
+        .and_then(Value::as_mapping)
+        .into_iter()
+        .flatten()
+    {
+        if let (Some(name), Some(default)) = (name.as_str(), variable.get("default")) {
+            url = url.replace(&format!("{{{name}}}"), &scalar_to_string(default));
+        }
+    }
+    let url = url.trim_end_matches('/');
+    if url.starts_with("http://") || url.starts_with("https://") {
+        url.to_string()
+    } else {
+        format!("{{{{base_url}}}}{url}")
+    }
+}
+
+/// Converts the templated `path` of an operation (`/pets/{petId}`) to a Hurl templated path
+/// (`/pets/{{petId}}`).
+fn template_path(path: &str) -> String {
+    let mut s = String::new();
+    let mut rest = path;
+    while let Some(start) = rest.find('{') {
+        match rest[start..].find('}') {
+            Some(end) => {
+                let name = &rest[start + 1..start + end];
+                s.push_str(&rest[..start]);
+                s.push_str(format!("{{{{{}}}}}", variable_name(name)).as_str());
+                rest = &rest[start + end + 1..];
+            }
+            None => break,
+        }
+    }
+    s.push_str(rest);
+    s
+}
+
+/// Returns the value of a parameter: its example if any, or a variable.
+fn param_value(doc: &Value, name: &str, param: &Value) -> Template {
+    let example = param.get("example").cloned().or_else(|| {
+        param
+            .get("schema")
+            .and_then(|schema| schema_example(doc, schema, 0))
+    });
+    match example {
+        Some(value) if !value.is_mapping() && !value.is_sequence() => scalar_to_hurl(&value),
+        _ => builder::template(
+            &format!("{{{{{}}}}}", variable_name(name)),
+            escape_value,
+            None,
+        ),
+    }
+}
+
+/// Returns the resolved parameters of an operation or a path item.
+fn parameters<'a>(doc: &'a Value, value: &'a Value) -> Vec<&'a Value> {
+    value
+        .get("parameters")
+        .and_then(Value::as_sequence)
+        .into_iter()
+        .flatten()
+        .map(|p| resolve(doc, p))
+        .collect()
+}
+
+/// Returns the first media type of a request body or a response.
+fn first_media(value: &Value) -> Option<(&str, &Value)> {
+    let content: &Mapping = value.get("content")?.as_mapping()?;
+    let (content_type, media) = content.iter().next()?;
+    Some((content_type.as_str()?, media))
+}
+
+/// Returns an example of a media type, from its examples or its schema.
+fn media_example(doc: &Value, media: &Value) -> Option<Value> {
+    if let Some(example) = media.get("example") {
+        return Some(example.clone());
+    }
+    if let Some((_, example)) = media
+        .get("examples")
+        .and_then(Value::as_mapping)
+        .and_then(|examples| examples.iter().next())
+    {
+        if let Some(value) = resolve(doc, example).get("value") {
+            return Some(value.clone());
+        }
+    }
+    schema_example(doc, media.get("schema")?, 0)
+}
+
+/// Builds an example value from a JSON `schema`.
+fn schema_example(doc: &Value, schema: &Value, depth: usize) -> Option<Value> {
+    if depth > MAX_DEPTH {
+        return None;
+    }
+    let schema = resolve(doc, schema);
+    if let Some(example) = schema.get("example").or_else(|| schema.get("default")) {
+        return Some(example.clone());
+    }
+    if let Some(first) = ["examples", "enum"]
+        .iter()
+        .find_map(|key| schema.get(key).and_then(|v| v.get(0)))
+    {
+        return Some(first.clone());
+    }
+    if let Some(first) = ["oneOf", "anyOf"]
+        .iter()
+        .find_map(|key| schema.get(key).and_then(|v| v.get(0)))
+    {
+        return schema_example(doc, first, depth + 1);
+    }
+    if let Some(all_of) = schema.get("allOf").and_then(Value::as_sequence) {
+        let mut object = Mapping::new();
+        for schema in all_of {
+            match schema_example(doc, schema, depth + 1) {
+                Some(Value::Mapping(m)) => object.extend(m),
+                Some(value) => return Some(value),
+                None => {}
+            }
+        }
+        return Some(Value::Mapping(object));
+    }
+
+    // OpenAPI 3.1 allows a list of types (`[string, "null"]`).
+    let schema_type = match schema.get("type") {
+        Some(Value::Sequence(types)) => types
+            .iter()
+            .filter_map(Value::as_str)
+            .find(|t| *t != "null"),
+        Some(t) => t.as_str(),
+        None if schema.get("properties").is_some() => Some("object"),
+        None => None,
+    };
+    let value = match schema_type? {
+        "object" => {
+            let mut object = Mapping::new();
+            for (name, property) in schema
+                .get("properties")
+                .and_then(Value::as_mapping)
+                .into_iter()
+                .flatten()
+            {
+                if let Some(value) = schema_example(doc, property, depth + 1) {
+                    object.insert(name.clone(), value);
+                }
+            }
+            Value::Mapping(object)
+        }
+        "array" => {
+            let items = schema
+                .get("items")
+                .and_then(|items| schema_example(doc, items, depth + 1));
+            Value::Sequence(items.into_iter().collect())
+        }
+        "string" => {
+            let example = match schema.get("format").and_then(Value::as_str) {
+                Some("date") => "2023-01-01",
+                Some("date-time") => "2023-01-01T00:00:00Z",
+                Some("email") => "user@example.com",
+                Some("uuid") => "3fa85f64-5717-4562-b3fc-2c963f66afa6",
+                Some("uri") => "https://example.com",
+                _ => "string",
+            };
+            Value::String(example.to_string())
+        }
+        "integer" | "number" => Value::Number(0.into()),
+        "boolean" => Value::Bool(true),
+        _ => return None,
+    };
+    Some(value)
+}
+
+/// Resolves a local reference (`$ref: '#/components/schemas/Pet'`) of `value`.
+///
+/// If `value` is not a reference, or can't be resolved, `value` is returned.
+fn resolve<'a>(doc: &'a Value, value: &'a Value) -> &'a Value {
+    let mut value = value;
+    // References can point to other references.
+    for _ in 0..MAX_DEPTH {
+        let pointer = match value.get("$ref").and_then(Value::as_str) {
+            Some(r) => match r.strip_prefix("#/") {
+                Some(pointer) => pointer,
+                None => return value,
+            },
+            None => return value,
+        };
+        let mut target = doc;
+        for token in pointer.split('/') {
+            let token = token.replace("~1", "/").replace("~0", "~");
+            target = match target.get(token.as_str()) {
+                Some(v) => v,
+                None => return value,
+            };
+        }
+        value = target;
+    }
+    value
+}
+
+/// Converts a scalar `value` to a Hurl value (used in headers, params etc...).
+fn scalar_to_hurl(value: &Value) -> Template {
+    builder::template(&scalar_to_string(value), escape_value, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::escape::to_json;
+
+    fn yaml(s: &str) -> Value {
+        serde_yaml::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn test_parse_json() {
+        let spec = r#"{
+  "openapi": "3.1.0",
+  "paths": {
+    "/users/{user.id}/avatar": {
+      "put": {
+        "requestBody": {
+          "content": {
+            "multipart/form-data": {
+              "schema": {
+                "type": "object",
+                "properties": {
+                  "name": {"type": ["string", "null"]},
+                  "file": {"type": "string", "format": "binary"}
+                }
+              }
+            }
+          }
+        },
+        "responses": {"default": {"description": "error"}}
+      }
+    }
+  }
+}"#;
+        assert_eq!(
+            parse(spec).unwrap(),
+            r#"PUT {{base_url}}/users/{{user_id}}/avatar
+[MultipartFormData]
+name: string
+file: file,file.bin;
+HTTP *
+"#
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            parse("swagger: '2.0'").unwrap_err(),
+            "Can not parse OpenAPI file: missing openapi version"
+        );
+        assert_eq!(
+            parse("openapi: 2.0.0").unwrap_err(),
+            "Can not parse OpenAPI file: version 2.0.0 is not supported"
+        );
+        assert_eq!(
+            parse("openapi: 3.0.0").unwrap_err(),
+            "Can not parse OpenAPI file: missing paths"
+        );
+    }
+
+    #[test]
+    fn test_base_url() {
+        assert_eq!(base_url(&yaml("openapi: 3.0.0")), "{{base_url}}");
+        assert_eq!(
+            base_url(&yaml("servers: [{url: /api/v1/}]")),
+            "{{base_url}}/api/v1"
+        );
+        assert_eq!(
+            base_url(&yaml(
+                "servers: [{url: 'https://{env}.example.com', variables: {env: {default: dev}}}]"
+            )),
+            "https://dev.example.com"
+        );
+    }
+
+    #[test]
+    fn test_template_path() {
+        assert_eq!(template_path("/pets"), "/pets");
+        assert_eq!(
+            template_path("/pets/{petId}/photos/{photo id}"),
+            "/pets/{{petId}}/photos/{{photo_id}}"
+        );
+        assert_eq!(template_path("/pets/{petId"), "/pets/{petId");
+    }
+
+    #[test]
+    fn test_schema_example() {
+        let doc = yaml(
+            r#"
+components:
+  schemas:
+    Node:
+      type: object
+      properties:
+        name:
+          type: string
+          enum: [a, b]
+        children:
+          type: array
+          items:
+            $ref: '#/components/schemas/Node'
+    Named:
+      allOf:
+        - $ref: '#/components/schemas/Id'
+        - properties:
+            name: {type: string, default: Bob}
+    Id:
+      properties:
+        id: {type: integer}
+"#,
+        );
+        let schema = yaml("$ref: '#/components/schemas/Named'");
+        assert_eq!(
+            to_json(&schema_example(&doc, &schema, 0).unwrap(), 0),
+            "{\n    \"id\": 0,\n    \"name\": \"Bob\"\n}"
+        );
+
+        // Recursive schemas are cut at a maximum depth.
+        let schema = yaml("$ref: '#/components/schemas/Node'");
+        let example = schema_example(&doc, &schema, 0).unwrap();
+        assert_eq!(example["name"], yaml("a"));
+        assert_eq!(example["children"][0]["name"], yaml("a"));
+
+        assert_eq!(schema_example(&doc, &yaml("{}"), 0), None);
+    }
+
+    #[test]
+    fn test_resolve() {
+        let doc = yaml(
+            r#"
+components:
+  schemas:
+    A: {$ref: '#/components/schemas/B'}
+    B: {type: string}
+    a/b: {type: integer}
+"#,
+        );
+        assert_eq!(
+            resolve(&doc, &yaml("$ref: '#/components/schemas/A'")),
+            &yaml("type: string")
+        );
+        assert_eq!(
+            resolve(&doc, &yaml("$ref: '#/components/schemas/a~1b'")),
+            &yaml("type: integer")
+        );
+        let external = yaml("$ref: 'pet.yaml#/Pet'");
+        assert_eq!(resolve(&doc, &external), &external);
+    }
+}
@@ -0,0 +1,579 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2023 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+//! Import of [Postman](https://www.postman.com) v2.1 collections and environments.
+//!
+//! Requests of a collection are converted to sequential Hurl entries (folders are flattened),
+//! and collection variables are kept as `{{var}}` templates. As Hurl has no scripting, pre-request
+//! and test scripts are kept as comments.
+use hurl_core::ast::*;
+use serde_json::Value;
+
+use crate::builder::{self, push_section};
+use crate::escape::{
+    escape_body_braces, escape_url_braces, escape_value, escape_value_braces, url_decode,
+    variable_name,
+};
+use crate::format;
+
+/// The result of a Postman import.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PostmanImport {
+    /// The Hurl file content, empty for an environment.
+    pub content: String,
+    /// The collection (or environment) variables, as name/value pairs.
+    pub variables: Vec<(String, String)>,
+    /// The unsupported features found during the import.
+    pub warnings: Vec<String>,
+}
+
+/// Converts a Postman collection (or environment) `s` to a Hurl file content.
+pub fn parse(s: &str) -> Result<PostmanImport, String> {
+    let doc: Value =
+        serde_json::from_str(s).map_err(|e| format!("Can not parse Postman file: {e}"))?;
+    let mut ctx = Context::default();
+
+    // Environments have only variables, under a `values` key.
+    if doc.get("item").is_none() {
+        if let Some(values) = doc.get("values") {
+            let variables = variables(values, &mut ctx);
+            return Ok(PostmanImport {
+                content: String::new(),
+                variables,
+                warnings: ctx.warnings,
+            });
+        }
+        return Err("Can not parse Postman file: missing collection items".to_string());
+    }
+    let schema = doc["info"]["schema"].as_str().unwrap_or_default();
+    if !schema.contains("v2.1") && !schema.contains("v2.0") {
+        return Err(format!(
+            "Can not parse Postman file: schema <{schema}> is not supported (v2.1 expected)"
+        ));
+    }
+
+    ctx.variables = variables(&doc["variable"], &mut ctx);
+    let mut entries = vec![];
+    scripts(&doc, "collection", &mut ctx);
+    let auth = doc.get("auth");
+    items(&doc["item"], &[], auth, &mut entries, &mut ctx)?;
+    let mut hurl_file = builder::hurl_file(entries);
+    // Scripts of the last folders are kept at the end of the file.
+    if ctx.comments.pop().is_some() {
+        if !hurl_file.entries.is_empty() {
+            ctx.comments.insert(0, builder::newline());
+        }
+        hurl_file.line_terminators = ctx.comments;
+    }
+    Ok(PostmanImport {
+        content: format::format_text(hurl_file, false),
+        variables: ctx.variables,
+        warnings: ctx.warnings,
+    })
+}
+
+/// Converts recursively the `items` of a folder (or a collection) to Hurl entries.
+fn items(
+    items: &Value,
+    path: &[&str],
+    auth: Option<&Value>,
+    entries: &mut Vec<Entry>,
+    ctx: &mut Context,
+) -> Result<(), String> {
+    for item in items.as_array().into_iter().flatten() {
+        let name = item["name"].as_str().unwrap_or_default();
+        let mut path = path.to_vec();
+        path.push(name);
+        let title = path.join(" / ");
+        // Authentication is inherited from the parent folder if not defined.
+        let auth = item.get("auth").or(auth);
+
+        if item.get("item").is_some() {
+            scripts(item, &format!("folder {title}"), ctx);
+            self::items(&item["item"], &path, auth, entries, ctx)?;
+        } else {
+            let request = request(item, &title, auth, ctx)
+                .map_err(|message| format!("Can not parse Postman request {title}: {message}"))?;
+            entries.push(Entry {
+                request,
+                response: None,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Converts a request `item` to a Hurl request.
+fn request(
+    item: &Value,
+    title: &str,
+    auth: Option<&Value>,
+    ctx: &mut Context,
+) -> Result<Request, String> {
+    let request = &item["request"];
+    let auth = request.get("auth").or(auth);
+    // The scripts of the collection and folders are written before the request title, the
+    // scripts of the request after.
+    let mut line_terminators = std::mem::take(&mut ctx.comments);
+    line_terminators.push(builder::comment(title));
+    scripts(item, &format!("request {title}"), ctx);
+    if ctx.comments.pop().is_some() {
+        line_terminators.append(&mut ctx.comments);
+    }
+
+    // A request can be a simple url.
+    let (method, url) = match request {
+        Value::String(url) => ("GET", url.as_str()),
+        _ => {
+            let method = request["method"].as_str().unwrap_or("GET");
+            let url = match &request["url"] {
+                Value::String(url) => url.as_str(),
+                url => url["raw"].as_str().unwrap_or_default(),
+            };
+            (method, url)
+        }
+    };
+    if url.is_empty() {
+        return Err("missing url".to_string());
+    }
+    // Query params are described in a section if they are available.
+    let query = enabled(&request["url"]["query"], "key");
+    let url = if query.is_empty() {
+        url
+    } else {
+        url.split_once('?').map_or(url, |(url, _)| url)
+    };
+    let url = template(url, str::to_string, escape_url_braces, ctx);
+    let mut hurl_request = builder::request(method, url);
+    hurl_request.line_terminators = line_terminators;
+
+    hurl_request.headers = enabled(&request["header"], "key")
+        .iter()
+        .map(|(name, value)| param(name, value, ctx))
+        .collect();
+
+    let query = query
+        .iter()
+        .map(|(name, value)| param(&url_decode(name), &url_decode(value), ctx))
+        .collect();
+    let sections = &mut hurl_request.sections;
+    push_section(sections, SectionValue::QueryParams(query));
+    let basic_auth = auth.and_then(|auth| basic_auth(auth, ctx));
+    push_section(sections, SectionValue::BasicAuth(basic_auth));
+    hurl_request.body = body(&request["body"], sections, ctx).map(builder::body);
+    Ok(hurl_request)
+}
+
+/// Returns the `[BasicAuth]` user and password of an `auth` object.
+///
+/// Only basic authentication is supported, other authentication types produce a warning.
+fn basic_auth(auth: &Value, ctx: &mut Context) -> Option<KeyValue> {
+    match auth["type"].as_str() {
+        Some("basic") => {
+            let attributes = enabled(&auth["basic"], "key");
+            let get = |key: &str| {
+                attributes
+                    .iter()
+                    .find(|(k, _)| k == key)
+                    .map(|(_, v)| v.as_str())
+                    .unwrap_or_default()
+            };
+            Some(param(get("username"), get("password"), ctx))
+        }
+        Some("noauth") | None => None,
+        Some(auth_type) => {
+            ctx.warn(format!("authentication <{auth_type}> is not supported"));
+            None
+        }
+    }
+}
+
+/// Converts a request `body`, either to a section added to `sections` or to a Hurl body.
+fn body(body: &Value, sections: &mut Vec<Section>, ctx: &mut Context) -> Option<Bytes> {
+    match body["mode"].as_str()? {
+        "raw" => {
+            let raw = body["raw"].as_str().unwrap_or_default();
+            if raw.is_empty() {
+                return None;
+            }
+            let json = body["options"]["raw"]["language"].as_str() == Some("json");
+            let (bytes, dynamics) = builder::import_body(raw, json);
+            ctx.warn_dynamics(dynamics);
+            Some(bytes)
+        }
+        "urlencoded" => {
+            let params = enabled(&body["urlencoded"], "key")
+                .iter()
+                .map(|(name, value)| param(name, value, ctx))
+                .collect();
+            push_section(sections, SectionValue::FormParams(params));
+            None
+        }
+        "formdata" => {
+            let mut params = vec![];
+            for param in body["formdata"].as_array().into_iter().flatten() {
+                if param["disabled"].as_bool() == Some(true) {
+                    continue;
+                }
+                let name = param["key"].as_str().unwrap_or_default();
+                if param["type"].as_str() == Some("file") {
+                    let src = match &param["src"] {
+                        Value::Array(files) => files.first().and_then(Value::as_str),
+                        src => src.as_str(),
+                    };
+                    let name = key(name, ctx);
+                    let filename = src.unwrap_or_default();
+                    let content_type = param["contentType"].as_str();
+                    params.push(builder::file_param(&name, filename, content_type));
+                } else {
+                    let value = param["value"].as_str().unwrap_or_default();
+                    params.push(MultipartParam::Param(self::param(name, value, ctx)));
+                }
+            }
+            push_section(sections, SectionValue::MultipartFormData(params));
+            None
+        }
+        "file" => {
+            let src = body["file"]["src"].as_str()?;
+            Some(builder::file(src))
+        }
+        "graphql" => {
+            let query = body["graphql"]["query"].as_str().unwrap_or_default();
+            let template = template(
+                &format!("{query}\n"),
+                str::to_string,
+                escape_body_braces,
+                ctx,
+            );
+            let variables = body["graphql"]["variables"]
+                .as_str()
+                .map(str::trim)
+                .filter(|v| !v.is_empty() && *v != "{}");
+            let variables = match variables {
+                Some(variables) => match builder::parse_json(variables) {
+                    Some(variables) => Some(variables),
+                    None => {
+                        ctx.warn("invalid GraphQL body".to_string());
+                        return None;
+                    }
+                },
+                None => None,
+            };
+            if query.contains("```") {
+                ctx.warn("invalid GraphQL body".to_string());
+                return None;
+            }
+            Some(builder::graphql(template, variables))
+        }
+        mode => {
+            ctx.warn(format!("body mode <{mode}> is not supported"));
+            None
+        }
+    }
+}
+
+/// Adds the pre-request and test scripts of an `item` to the pending comments, followed by an
+/// empty line.
+fn scripts(item: &Value, description: &str, ctx: &mut Context) {
+    let mut comments = vec![];
+    for event in item["event"].as_array().into_iter().flatten() {
+        let lines = match &event["script"]["exec"] {
+            Value::Array(lines) => lines.iter().filter_map(Value::as_str).collect::<Vec<_>>(),
+            Value::String(exec) => exec.lines().collect(),
+            _ => vec![],
+        };
+        if lines.iter().all(|l| l.trim().is_empty()) {
+            continue;
+        }
+        let listen = event["listen"].as_str().unwrap_or("script");
+        ctx.warn(format!("{listen} script of {description} is not supported"));
+        comments.push(builder::comment(&format!("Unsupported {listen} script:")));
+        for line in lines {
+            comments.push(builder::comment(line.trim_end()));
+        }
+    }
+    if !comments.is_empty() {
+        ctx.comments.append(&mut comments);
+        ctx.comments.push(builder::newline());
+    }
+}
+
+/// Returns the variables of a collection or an environment.
+fn variables(values: &Value, ctx: &mut Context) -> Vec<(String, String)> {
+    let mut variables = vec![];
+    for variable in values.as_array().into_iter().flatten() {
+        if variable["disabled"].as_bool() == Some(true)
+            || variable["enabled"].as_bool() == Some(false)
+        {
+            continue;
+        }
+        let name = match variable["key"].as_str() {
+            Some(name) => name,
+            None => continue,
+        };
+        let value = match &variable["value"] {
+            Value::String(s) => s.clone(),
+            Value::Null => String::new(),
+            value => value.to_string(),
+        };
+        if value.contains('\n') {
+            ctx.warn(format!(
+                "multiline value of variable <{name}> is not supported"
+            ));
+            continue;
+        }
+        variables.push((variable_name(name), value));
+    }
+    variables
+}
+
+/// Returns the enabled key/value pairs of a Postman list (headers, query params etc...).
+fn enabled(values: &Value, key: &str) -> Vec<(String, String)> {
+    values
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter(|v| v["disabled"].as_bool() != Some(true))
+        .filter_map(|v| {
+            let name = v[key].as_str()?;
+            let value = v["value"].as_str().unwrap_or_default();
+            Some((name.to_string(), value.to_string()))
+        })
+        .collect()
+}
+
+/// Returns a key/value line from a param `name` and `value`.
+fn param(name: &str, value: &str, ctx: &mut Context) -> KeyValue {
+    let name = key(name, ctx);
+    let value = template(value, escape_value, escape_value_braces, ctx);
+    builder::key_value(&name, value)
+}
+
+/// Returns a template of a Postman string `s` (see [`builder::import_template`]), the dynamic
+/// variables producing a warning.
+fn template(
+    s: &str,
+    escape: fn(&str) -> String,
+    escape_dynamic: fn(&str) -> String,
+    ctx: &mut Context,
+) -> Template {
+    let (template, dynamics) = builder::import_template(s, escape, escape_dynamic);
+    ctx.warn_dynamics(dynamics);
+    template
+}
+
+/// Returns a Hurl key from a Postman `s` string.
+///
+/// As Hurl keys can't be templated, templates are replaced by the value of the collection
+/// variables.
+fn key(s: &str, ctx: &mut Context) -> String {
+    let mut result = String::new();
+    let mut rest = s;
+    while let Some(start) = rest.find("{{") {
+        let end = match rest[start..].find("}}") {
+            Some(end) => start + end,
+            None => break,
+        };
+        let name = variable_name(rest[start + 2..end].trim());
+        result.push_str(&rest[..start]);
+        match ctx.variables.iter().find(|(n, _)| *n == name) {
+            Some((_, value)) => result.push_str(value),
+            None => {
+                ctx.warn(format!("variable <{name}> can not be used in key <{s}>"));
+                result.push_str(&name);
+            }
+        }
+        rest = &rest[end + 2..];
+    }
+    result.push_str(rest);
+    result
+}
+
+/// The state of an import: the collection variables, the comments to write before the next
+/// request and the warnings raised so far.
+#[derive(Default)]
+struct Context {
+    variables: Vec<(String, String)>,
+    comments: Vec<LineTerminator>,
+    warnings: Vec<String>,
+}
+
+impl Context {
+    fn warn(&mut self, warning: String) {
+        if !self.warnings.contains(&warning) {
+            self.warnings.push(warning);
+        }
+    }
+
+    fn warn_dynamics(&mut self, dynamics: Vec<String>) {
+        for name in dynamics {
+            self.warn(format!("dynamic variable <{name}> is not supported"));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_environment() {
+        let environment = r#"{
+  "name": "dev",
+  "values": [
+    {"key": "host", "value": "localhost", "enabled": true},
+    {"key": "api key", "value": "secret", "enabled": true},
+    {"key": "old", "value": "x", "enabled": false}
+  ]
+}"#;
+        assert_eq!(
+            parse(environment).unwrap(),
+            PostmanImport {
+                content: String::new(),
+                variables: vec![
+                    ("host".to_string(), "localhost".to_string()),
+                    ("api_key".to_string(), "secret".to_string()),
+                ],
+                warnings: vec![],
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            parse("{}").unwrap_err(),
+            "Can not parse Postman file: missing collection items"
+        );
+        assert_eq!(
+            parse(r#"{"info": {"schema": "https://schema.getpostman.com/json/collection/v1.0.0/collection.json"}, "item": []}"#)
+                .unwrap_err(),
+            "Can not parse Postman file: schema <https://schema.getpostman.com/json/collection/v1.0.0/collection.json> is not supported (v2.1 expected)"
+        );
+    }
+
+    #[test]
+    fn test_parse_collection() {
+        let collection = r#"{
+  "info": {"schema": "https://schema.getpostman.com/json/collection/v2.1.0/collection.json"},
+  "auth": {"type": "bearer", "bearer": [{"key": "token", "value": "xxx"}]},
+  "item": [
+    {
+      "name": "Users",
+      "auth": {"type": "noauth"},
+      "item": [
+        {"name": "Get user", "request": "https://{{host}}/users/{{$randomInt}}"}
+      ]
+    },
+    {
+      "name": "Search",
+      "request": {
+        "method": "POST",
+        "url": "https://{{host}}/graphql",
+        "body": {
+          "mode": "graphql",
+          "graphql": {"query": "query { users { id } }", "variables": ""}
+        }
+      }
+    }
+  ]
+}"#;
+        let import = parse(collection).unwrap();
+        assert_eq!(
+            import.content,
+            r#"# Users / Get user
+GET https://{{host}}/users/%7B%7B$randomInt%7D%7D
+
+# Search
+POST https://{{host}}/graphql
+```graphql
+query { users { id } }
+```
+"#
+        );
+        assert_eq!(
+            import.warnings,
+            vec![
+                "dynamic variable <$randomInt> is not supported".to_string(),
+                "authentication <bearer> is not supported".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_raw_body() {
+        let collection = |body: serde_json::Value| {
+            let item = serde_json::json!({
+                "name": "Data",
+                "request": {"method": "POST", "url": "http://localhost:8000/data", "body": body}
+            });
+            let collection = serde_json::json!({
+                "info": {"schema": "https://schema.getpostman.com/json/collection/v2.1.0/collection.json"},
+                "item": [item]
+            });
+            parse(&collection.to_string()).unwrap()
+        };
+        let import = collection(serde_json::json!({
+            "mode": "raw",
+            "raw": "{\"id\": \"{{id}}\"}",
+            "options": {"raw": {"language": "json"}}
+        }));
+        assert_eq!(
+            import.content,
+            "# Data\nPOST http://localhost:8000/data\n{\"id\": \"{{id}}\"}\n"
+        );
+
+        // Bodies that are not valid Hurl JSON are kept as multiline strings.
+        let import = collection(serde_json::json!({
+            "mode": "raw",
+            "raw": "{\"id\": {{id}}}",
+            "options": {"raw": {"language": "json"}}
+        }));
+        assert_eq!(
+            import.content,
+            "# Data\nPOST http://localhost:8000/data\n```\n{\"id\": {{id}}}\n```\n"
+        );
+
+        let import = collection(serde_json::json!({"mode": "raw", "raw": "Hello {{name}}"}));
+        assert_eq!(
+            import.content,
+            "# Data\nPOST http://localhost:8000/data\n```\nHello {{name}}\n```\n"
+        );
+
+        let import = collection(serde_json::json!({"mode": "raw", "raw": "```"}));
+        assert_eq!(
+            import.content,
+            "# Data\nPOST http://localhost:8000/data\nbase64,YGBg;\n"
+        );
+        assert!(import.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_key() {
+        let mut ctx = Context {
+            variables: vec![("user".to_string(), "bob@email.com".to_string())],
+            ..Context::default()
+        };
+        assert_eq!(key("{{user}}", &mut ctx), "bob@email.com");
+        assert_eq!(key("x {{id}}", &mut ctx), "x id");
+        assert_eq!(
+            ctx.warnings,
+            vec!["variable <id> can not be used in key <x {{id}}>".to_string()]
+        );
+    }
+}
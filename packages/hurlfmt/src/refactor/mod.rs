@@ -0,0 +1,402 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2023 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+//! Refactorings of Hurl files.
+//!
+//! Refactorings are done on the concrete syntax tree of a file: only the edited nodes are
+//! rewritten, comments and whitespaces are kept as is.
+use hurl_core::ast::{Bytes, QueryValue};
+use hurl_core::cst::{Cst, SyntaxKind, SyntaxNode};
+use hurl_core::parser;
+
+/// A file to create, when moving a body out of a Hurl file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BodyFile {
+    /// Path of the file, relative to the Hurl file.
+    pub filename: String,
+    pub content: String,
+}
+
+/// Renames the variable `old` to `new`, where it's defined (`variable` option), captured, queried
+/// (`variable "name"`) and used.
+///
+/// Captured and queried names are compared once decoded, so escaped names are renamed too.
+pub fn rename_variable(cst: &Cst, old: &str, new: &str) -> String {
+    let mut editor = cst.editor();
+    for node in cst.root().descendants() {
+        match node.kind() {
+            SyntaxKind::Variable | SyntaxKind::VariableName if cst.node_text(node) == old => {
+                editor.replace(node, new);
+            }
+            SyntaxKind::Capture => {
+                let Some(name) = child(node, SyntaxKind::EncodedString) else {
+                    continue;
+                };
+                if capture_name(cst.node_text(name)).as_deref() == Some(old) {
+                    editor.replace(name, new);
+                }
+            }
+            SyntaxKind::Query if cst.node_text(node).starts_with("variable") => {
+                let Some(name) = child(node, SyntaxKind::Template) else {
+                    continue;
+                };
+                if query_variable_name(cst.node_text(name)).as_deref() == Some(old) {
+                    editor.replace(name, &format!("\"{new}\""));
+                }
+            }
+            _ => {}
+        }
+    }
+    editor.finish()
+}
+
+/// Replaces the prefix `old` of the request URLs by `new`.
+///
+/// URLs are compared as written in the file, templates included: `{{host}}/api` is a valid prefix.
+pub fn replace_url_prefix(cst: &Cst, old: &str, new: &str) -> String {
+    let mut editor = cst.editor();
+    let urls = cst
+        .root()
+        .descendants()
+        .filter(|n| n.kind() == SyntaxKind::Request)
+        .filter_map(|n| child(n, SyntaxKind::Template));
+    for url in urls {
+        if let Some(suffix) = cst.node_text(url).strip_prefix(old) {
+            editor.replace(url, &format!("{new}{suffix}"));
+        }
+    }
+    editor.finish()
+}
+
+/// Moves the inline JSON bodies to files, and references them with `file,` bodies.
+///
+/// Files are named from the `stem` of the Hurl file, the entry index and the request or response:
+/// `<stem>_<index>_request.json`. Bodies with templates are kept inline, as templates are not
+/// rendered in files. Returns the new text, the files to create and the warnings for the bodies
+/// that have been kept.
+pub fn extract_json_bodies(cst: &Cst, stem: &str) -> (String, Vec<BodyFile>, Vec<String>) {
+    let mut editor = cst.editor();
+    let mut files = vec![];
+    let mut warnings = vec![];
+    let entries = cst
+        .root()
+        .children()
+        .iter()
+        .filter(|n| n.kind() == SyntaxKind::Entry);
+    for (index, entry) in entries.enumerate() {
+        for (kind, name) in [
+            (SyntaxKind::Request, "request"),
+            (SyntaxKind::Response, "response"),
+        ] {
+            let Some(bytes) = child(entry, kind).and_then(body_bytes) else {
+                continue;
+            };
+            let content = cst.node_text(bytes);
+            if !content.starts_with(['{', '[']) {
+                continue;
+            }
+            if bytes.descendants().any(|n| n.kind() == SyntaxKind::Expr) {
+                warnings.push(format!(
+                    "JSON {name} body of entry {} has templates and is kept inline",
+                    index + 1
+                ));
+                continue;
+            }
+            let filename = format!("{stem}_{}_{name}.json", index + 1);
+            editor.replace(bytes, &format!("file,{};", escape_filename(&filename)));
+            files.push(BodyFile {
+                filename,
+                content: content.to_string(),
+            });
+        }
+    }
+    (editor.finish(), files, warnings)
+}
+
+/// Inlines the JSON bodies referenced by `file,` bodies, the content of a file being returned by
+/// `read_file`.
+///
+/// Only files with a `.json` extension, and whose content is a valid Hurl JSON body, are
+/// inlined. Returns the new text and the warnings for the files that have been kept.
+pub fn inline_json_bodies<F>(cst: &Cst, read_file: F) -> (String, Vec<String>)
+where
+    F: Fn(&str) -> Result<String, String>,
+{
+    let mut editor = cst.editor();
+    let mut warnings = vec![];
+    let bodies = cst
+        .root()
+        .descendants()
+        .filter(|n| n.kind() == SyntaxKind::Body)
+        .filter_map(|n| child(n, SyntaxKind::Bytes));
+    for bytes in bodies {
+        let Some(filename) = child(bytes, SyntaxKind::Filename) else {
+            continue;
+        };
+        let filename = unescape_filename(cst.node_text(filename));
+        if !filename.ends_with(".json") {
+            continue;
+        }
+        let content = match read_file(&filename) {
+            Ok(content) => content,
+            Err(message) => {
+                warnings.push(format!("File {filename} can not be read - {message}"));
+                continue;
+            }
+        };
+        let content = content.trim_end();
+        if !is_json_body(content) {
+            warnings.push(format!("File {filename} is not a valid JSON body"));
+            continue;
+        }
+        editor.replace(bytes, content);
+    }
+    (editor.finish(), warnings)
+}
+
+/// Returns the first child of `node` of this `kind`.
+fn child(node: &SyntaxNode, kind: SyntaxKind) -> Option<&SyntaxNode> {
+    node.children().iter().find(|n| n.kind() == kind)
+}
+
+/// Returns the value of the body of a request or response `node`.
+fn body_bytes(node: &SyntaxNode) -> Option<&SyntaxNode> {
+    child(node, SyntaxKind::Body).and_then(|body| child(body, SyntaxKind::Bytes))
+}
+
+/// Returns the decoded name of a capture, from its `encoded` text.
+fn capture_name(encoded: &str) -> Option<String> {
+    let text = format!("GET http://localhost\nHTTP *\n[Captures]\n{encoded}: status\n");
+    let hurl_file = parser::parse_hurl_file(&text).ok()?;
+    let response = hurl_file.entries.first()?.response.as_ref()?;
+    let capture = response.captures().into_iter().next()?;
+    Some(capture.name.value)
+}
+
+/// Returns the decoded name of a `variable` query, from its `encoded` quoted text.
+fn query_variable_name(encoded: &str) -> Option<String> {
+    let text = format!("GET http://localhost\nHTTP *\n[Asserts]\nvariable {encoded} exists\n");
+    let hurl_file = parser::parse_hurl_file(&text).ok()?;
+    let response = hurl_file.entries.first()?.response.as_ref()?;
+    let assert = response.asserts().into_iter().next()?;
+    match assert.query.value {
+        QueryValue::Variable { name, .. } => Some(name.to_string()),
+        _ => None,
+    }
+}
+
+/// Returns true if `content` can be used as a JSON body in a Hurl file.
+fn is_json_body(content: &str) -> bool {
+    let Ok(hurl_file) = parser::parse_hurl_file(&format!("GET http://localhost\n{content}\n"))
+    else {
+        return false;
+    };
+    matches!(
+        hurl_file.entries.as_slice(),
+        [entry] if matches!(entry.request.body.as_ref().map(|b| &b.value), Some(Bytes::Json(_)))
+    )
+}
+
+fn escape_filename(filename: &str) -> String {
+    let mut s = String::new();
+    for c in filename.chars() {
+        if !(c.is_alphanumeric() || ['.', '/', '_', '-'].contains(&c)) {
+            s.push('\\');
+        }
+        s.push(c);
+    }
+    s
+}
+
+fn unescape_filename(filename: &str) -> String {
+    let mut s = String::new();
+    let mut chars = filename.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => s.extend(chars.next()),
+            c => s.push(c),
+        }
+    }
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rename_variable() {
+        let text = r#"GET {{host}}/users/{{id}}   # get user {{id}}
+[Options]
+variable: id=1
+variable: ids=2
+HTTP 200
+[Captures]
+id: jsonpath "$.id"
+[Asserts]
+jsonpath "$.id" == {{ id }}
+variable "id" == 1
+variable "ids" == 2
+
+POST {{host}}/users
+{"id": {{id}}, "name": "{{id}}-{{ids}}"}
+"#;
+        let cst = Cst::parse(text).unwrap();
+        assert_eq!(
+            rename_variable(&cst, "id", "user_id"),
+            r#"GET {{host}}/users/{{user_id}}   # get user {{id}}
+[Options]
+variable: user_id=1
+variable: ids=2
+HTTP 200
+[Captures]
+user_id: jsonpath "$.id"
+[Asserts]
+jsonpath "$.id" == {{ user_id }}
+variable "user_id" == 1
+variable "ids" == 2
+
+POST {{host}}/users
+{"id": {{user_id}}, "name": "{{user_id}}-{{ids}}"}
+"#
+        );
+        assert_eq!(rename_variable(&cst, "unknown", "foo"), text);
+    }
+
+    #[test]
+    fn test_rename_escaped_variable() {
+        let text = r#"GET http://localhost/users
+HTTP 200
+[Captures]
+i\u{64}: jsonpath "$.id"
+[Asserts]
+variable "\u{69}d" == 1
+variable "i\u{64}s" == 2
+"#;
+        let cst = Cst::parse(text).unwrap();
+        assert_eq!(
+            rename_variable(&cst, "id", "user_id"),
+            r#"GET http://localhost/users
+HTTP 200
+[Captures]
+user_id: jsonpath "$.id"
+[Asserts]
+variable "user_id" == 1
+variable "i\u{64}s" == 2
+"#
+        );
+    }
+
+    #[test]
+    fn test_replace_url_prefix() {
+        let text = "GET http://localhost:8000/api/users\nLocation: http://localhost:8000/api\n\nGET {{host}}/api/users\n\nGET http://localhost:8000/health\n";
+        let cst = Cst::parse(text).unwrap();
+        assert_eq!(
+            replace_url_prefix(&cst, "http://localhost:8000/api", "{{host}}/api/v2"),
+            "GET {{host}}/api/v2/users\nLocation: http://localhost:8000/api\n\nGET {{host}}/api/users\n\nGET http://localhost:8000/health\n"
+        );
+    }
+
+    #[test]
+    fn test_extract_json_bodies() {
+        let text = r#"POST http://localhost/users
+{
+  "name": "Bob"
+}
+HTTP 201
+[1, 2]
+
+POST http://localhost/users
+{"name": "{{name}}"}
+
+POST http://localhost/login
+`user=bob`
+HTTP 200
+"#;
+        let cst = Cst::parse(text).unwrap();
+        let (text, files, warnings) = extract_json_bodies(&cst, "my users");
+        assert_eq!(
+            text,
+            r#"POST http://localhost/users
+file,my\ users_1_request.json;
+HTTP 201
+file,my\ users_1_response.json;
+
+POST http://localhost/users
+{"name": "{{name}}"}
+
+POST http://localhost/login
+`user=bob`
+HTTP 200
+"#
+        );
+        assert_eq!(
+            files,
+            vec![
+                BodyFile {
+                    filename: "my users_1_request.json".to_string(),
+                    content: "{\n  \"name\": \"Bob\"\n}".to_string(),
+                },
+                BodyFile {
+                    filename: "my users_1_response.json".to_string(),
+                    content: "[1, 2]".to_string(),
+                },
+            ]
+        );
+        assert_eq!(
+            warnings,
+            vec!["JSON request body of entry 2 has templates and is kept inline".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_inline_json_bodies() {
+        let text = r#"POST http://localhost/users
+file,my\ users_1_request.json;
+HTTP 201
+file,data.bin;
+
+POST http://localhost/users
+file,invalid.json;
+
+POST http://localhost/users
+file,missing.json;
+"#;
+        let read_file = |filename: &str| match filename {
+            "my users_1_request.json" => Ok("{\n  \"name\": \"{{name}}\"\n}\n".to_string()),
+            "invalid.json" => Ok("{\"name\": }".to_string()),
+            _ => Err("No such file or directory".to_string()),
+        };
+        let cst = Cst::parse(text).unwrap();
+        let (new_text, warnings) = inline_json_bodies(&cst, read_file);
+        assert_eq!(
+            new_text,
+            text.replace(
+                "file,my\\ users_1_request.json;",
+                "{\n  \"name\": \"{{name}}\"\n}"
+            )
+        );
+        assert_eq!(
+            warnings,
+            vec![
+                "File invalid.json is not a valid JSON body".to_string(),
+                "File missing.json can not be read - No such file or directory".to_string(),
+            ]
+        );
+    }
+}